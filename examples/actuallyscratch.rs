@@ -229,7 +229,7 @@ impl State {
                     let mut msg = Message::<64>::new(self.wl_registry, 0);
                     let new_id = ID_COUNTER.get_new();
                     msg.write_u32(name)
-                        .write_str(&interface)
+                        .write_str(interface)
                         .write_u32(version)
                         .write_u32(new_id)
                         .build();
@@ -251,19 +251,19 @@ impl State {
                 };
                 match interface {
                     "wl_shm" => {
-                        self.wl_shm = bind_reg(name, &interface, version);
+                        self.wl_shm = bind_reg(name, interface, version);
                     }
                     "wl_seat" => {
-                        self.wl_seat = bind_reg(name, &interface, version);
+                        self.wl_seat = bind_reg(name, interface, version);
                     }
                     "xdg_wm_base" => {
-                        self.xdg_wm_base = bind_reg(name, &interface, version);
+                        self.xdg_wm_base = bind_reg(name, interface, version);
                     }
                     "wl_compositor" => {
-                        self.wl_compositor = bind_reg(name, &interface, version);
+                        self.wl_compositor = bind_reg(name, interface, version);
                     }
                     "wp_cursor_shape_manager_v1" => {
-                        self.wl_cursor_mgr = bind_reg(name, &interface, version);
+                        self.wl_cursor_mgr = bind_reg(name, interface, version);
                     }
                     // "wp_single_pixel_buffer_manager_v1" => {
                     //     self.wl_shm_pool = bind_reg(name, &interface, version);
@@ -399,26 +399,23 @@ impl State {
     #[rustfmt::skip]
     fn on_xdgsurface_event(&mut self, socket: &mut UnixStream, event: Event<'_>) {
         let mut parser = EventDataParser::new(event.data);
-        match event.header.opcode {
-            0 => {
-                let serial = parser.get_u32();
-                let mut msg = Message::<12>::new(self.xdg_surface, 4);
-                msg.write_u32(serial).build();
-                unsafe {
-                    libc::send(
-                        socket.as_raw_fd(),
-                        msg.data() as *const [u8] as *const _,
-                        msg.data().len(),
-                        0,
-                    )
-                };
-                if self.debug {
-                    eprintln!("\x1b[32m[DEBUG]\x1b[0m: => xdg_surface#{}.configure(serial: {})", self.xdg_surface, serial);
-                    eprintln!("\x1b[32m[DEBUG]\x1b[0m: xdg_surface#{}.ack_configure(serial: {})", self.xdg_surface, serial);
-                }
-                self.state = AppState::SurfaceAckedConfigure;
+        if event.header.opcode == 0 {
+            let serial = parser.get_u32();
+            let mut msg = Message::<12>::new(self.xdg_surface, 4);
+            msg.write_u32(serial).build();
+            unsafe {
+                libc::send(
+                    socket.as_raw_fd(),
+                    msg.data() as *const [u8] as *const _,
+                    msg.data().len(),
+                    0,
+                )
+            };
+            if self.debug {
+                eprintln!("\x1b[32m[DEBUG]\x1b[0m: => xdg_surface#{}.configure(serial: {})", self.xdg_surface, serial);
+                eprintln!("\x1b[32m[DEBUG]\x1b[0m: xdg_surface#{}.ack_configure(serial: {})", self.xdg_surface, serial);
             }
-            _ => {}
+            self.state = AppState::SurfaceAckedConfigure;
         }
     }
 
@@ -592,7 +589,7 @@ impl State {
             let mut msg = Message::<64>::new(self.xdg_toplevel, 2);
             let title = "YAY first wayland app";
             msg.write_str(title).build();
-            socket.write(msg.data());
+            socket.write_all(msg.data()).unwrap();
             if self.debug {
                 eprintln!(
                     "\x1b[32m[DEBUG]\x1b[0m: Created xdg_toplevel#{}.set_title(title: {})",
@@ -1062,7 +1059,7 @@ fn main() -> io::Result<()> {
         }
 
         let mut event_iter = EventIter::new(&buf[..read]);
-        while let Some(event) = event_iter.next() {
+        for event in event_iter {
             if event.header.id == state.wl_registry {
                 state.on_registry_event(&mut socket, event);
             } else if event.header.id == state.xdg_wm_base {
@@ -6,43 +6,38 @@ use scratchway::wayland::*;
 fn main() -> std::io::Result<()> {
     let conn = Connection::connect()?;
     let display = conn.display();
-    let registry = display.get_registry(conn.writer());
+    display.get_registry(conn.writer());
 
-    let mut app = App { registry };
+    let mut app = App;
 
     conn.roundtrip(&mut app)
 }
 
-struct App {
-    registry: wl_registry::WlRegistry,
+struct App;
+
+impl wl_display::WlDisplayHandler for App {
+    fn error(
+        &mut self, _conn: &Connection, _display: &wl_display::WlDisplay, object_id: u32, code: u32,
+        message: &str,
+    ) {
+        eprintln!("Error on object {object_id}, code {code}: {message}");
+    }
 }
 
-impl App {
-    fn on_wlregistry(&mut self, conn: &Connection, event: scratchway::events::WlEvent<'_>) {
-        match self.registry.parse_event(conn.reader(), event) {
-            wl_registry::Event::Global {
-                interface, version, ..
-            } => match interface {
-                _ => {
-                    println!("Global ==> {}, version: {}", interface, version);
-                }
-            },
-            wl_registry::Event::GlobalRemove { .. } => {}
-        }
+impl wl_registry::WlRegistryHandler for App {
+    fn global(
+        &mut self, _conn: &Connection, _registry: &wl_registry::WlRegistry, _name: u32,
+        interface: &str, version: u32,
+    ) {
+        println!("Global ==> {}, version: {}", interface, version);
     }
 }
 
 impl State for App {
     fn handle_event(&mut self, conn: &Connection, event: scratchway::events::WlEvent<'_>) {
-        let display = conn.display();
-        match event.header.id {
-            1 => {
-                println!("{:?}", display.parse_event(conn.reader(), event));
-            }
-            2 => {
-                self.on_wlregistry(conn, event);
-            }
-            _ => {}
-        }
+        let Some(event) = conn.dispatch::<wl_display::WlDisplay, _>(self, event) else {
+            return;
+        };
+        conn.dispatch::<wl_registry::WlRegistry, _>(self, event);
     }
 }
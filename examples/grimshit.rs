@@ -1,6 +1,7 @@
 #![allow(unused)]
 use std::io::Write;
 
+use scratchway::events::WEnum;
 use scratchway::prelude::*;
 use scratchway::wayland::*;
 
@@ -27,7 +28,7 @@ fn main() -> std::io::Result<()> {
         width: 0,
         height: 0,
         stride: 0,
-        format: 0,
+        format: wl_shm::Format::Xrgb8888,
         outputs: Vec::new(),
         exit: false,
         wl_registry,
@@ -74,7 +75,7 @@ struct Output {
     port: String,
     width: i32,
     height: i32,
-    mode: u32,
+    mode: wl_output::Mode,
     wl_output: wl_output::WlOutput,
     name: u32,
 }
@@ -92,7 +93,7 @@ struct GrimShit {
     width: u32,
     height: u32,
     stride: u32,
-    format: u32,
+    format: wl_shm::Format,
 
     wl_buffer: Option<wl_buffer::WlBuffer>,
     outputs: Vec<Output>,
@@ -126,14 +127,19 @@ impl GrimShit {
 
     fn on_screencopyframe(&mut self, conn: &Connection, event: WlEvent) {
         let frame = self.screencopy_frame.as_ref().expect("fsdfl");
-        match frame.parse_event(conn.reader(), event) {
+        let Some(event) = frame.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             zwlr_screencopy_frame_v1::Event::Buffer {
                 format,
                 width,
                 height,
                 stride,
             } => {
-                self.format = format;
+                if let WEnum::Value(format) = format {
+                    self.format = format;
+                }
                 self.height = height;
                 self.width = width;
                 self.stride = stride;
@@ -189,7 +195,7 @@ impl GrimShit {
                     self.width as i32,
                     self.height as i32,
                     self.stride as i32,
-                    1,
+                    self.format,
                 );
                 wl_shm_pool.destroy(conn.writer());
 
@@ -254,7 +260,10 @@ impl GrimShit {
             .iter_mut()
             .find(|o| event.header.id == o.wl_output.id())
             .expect("Couldn't get output for recieved output event?");
-        match output.wl_output.parse_event(conn.reader(), event) {
+        let Some(event) = output.wl_output.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_output::Event::Mode {
                 flags,
                 width,
@@ -275,18 +284,19 @@ impl GrimShit {
 
     fn on_wlshm(&mut self, conn: &Connection, event: WlEvent) {
         let wl_shm = self.wl_shm.as_ref().expect("hfdosdf");
-        match wl_shm.parse_event(conn.reader(), event) {
-            _ => {}
-        }
+        wl_shm.parse_event(conn.reader(), event);
     }
 
     #[rustfmt::skip]
     fn on_wlregistry(&mut self, conn: &Connection, event: WlEvent) {
-        match self.wl_registry.parse_event(conn.reader(), event) {
+        let Some(event) = self.wl_registry.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_registry::Event::Global { name, interface, version } => {
                 match interface {
                     wl_output::WlOutput::INTERFACE => {
-                        let wl_output: wl_output::WlOutput = self.wl_registry.bind(conn.writer(), name, interface, version);
+                        let wl_output: wl_output::WlOutput = self.wl_registry.bind(conn.writer(), name, version);
                         self.add_cb(wl_output.id(), Self::on_wloutput);
                         self.outputs.push(Output {
                             name,
@@ -294,16 +304,16 @@ impl GrimShit {
                             width: 0,
                             wl_output,
                             port: String::new(),
-                            mode: 0
+                            mode: wl_output::Mode::empty()
                         });
 
                     }
                     ZwlrScreencopyManagerV1::INTERFACE => {
-                        let screencopy_mgr = self.wl_registry.bind(conn.writer(), name, interface, version);
+                        let screencopy_mgr = self.wl_registry.bind(conn.writer(), name, version);
                         self.screencopy_mgr = Some(screencopy_mgr)
                     }
                     wl_shm::WlShm::INTERFACE => {
-                        let wl_shm: wl_shm::WlShm = self.wl_registry.bind(conn.writer(), name, interface, version);
+                        let wl_shm: wl_shm::WlShm = self.wl_registry.bind(conn.writer(), name, version);
                         self.add_cb(wl_shm.id(), Self::on_wlshm);
                         self.wl_shm = Some(wl_shm)
                     }
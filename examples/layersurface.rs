@@ -13,7 +13,7 @@ use scr_protocols::{
 
 fn main() -> std::io::Result<()> {
     let conn = Connection::connect()?;
-    let target_output = std::env::args().skip(1).next();
+    let target_output = std::env::args().nth(1);
 
     let wl_display = conn.display();
     let wl_registry = wl_display.get_registry(conn.writer());
@@ -113,26 +113,26 @@ impl WaylandState {
         let wl_buffer = unsafe { self.wl_buffer.as_ref().unwrap_unchecked() };
         let wlr_layer_shell = unsafe { self.wlr_layer_shell.as_ref().unwrap_unchecked() };
 
-        let Some(output) = self
-            .outputs
-            .iter()
-            .filter(|o| {
-                if !o.ready {
-                    false
-                } else if let Some(name) = target.as_ref() {
-                    *name == o.port
-                } else {
-                    true
-                }
-            })
-            .next()
-        else {
+        let Some(output) = self.outputs.iter().find(|o| {
+            if !o.ready {
+                false
+            } else if let Some(name) = target.as_ref() {
+                *name == o.port
+            } else {
+                true
+            }
+        }) else {
             eprintln!("Couldn't find an output");
             std::process::exit(1);
         };
 
-        let layer_surface =
-            wlr_layer_shell.get_layer_surface(conn.writer(), &wl_surface, None, 2, "crosshair");
+        let layer_surface = wlr_layer_shell.get_layer_surface(
+            conn.writer(),
+            &wl_surface,
+            None,
+            zwlr_layer_shell_v1::Layer::Top,
+            "crosshair",
+        );
         self.register_cb(Self::on_layersurface_event, layer_surface.id());
 
         // if let Some(ref viewporter) = self.viewporter {
@@ -141,14 +141,13 @@ impl WaylandState {
         //     self.viewport = Some(viewport);
         // }
 
-        // 1 = top, 2 = bottom,  4 = left , 8 = right
-        let anchor = zwlr_layer_surface_v1::ANCHOR_RIGHT
-            | zwlr_layer_surface_v1::ANCHOR_LEFT
-            | zwlr_layer_surface_v1::ANCHOR_TOP;
+        let anchor = zwlr_layer_surface_v1::Anchor::RIGHT
+            | zwlr_layer_surface_v1::Anchor::LEFT
+            | zwlr_layer_surface_v1::Anchor::TOP;
 
         layer_surface.set_keyboard_interactivity(
             conn.writer(),
-            zwlr_layer_surface_v1::KeyboardInteractivity::None as u32,
+            zwlr_layer_surface_v1::KeyboardInteractivity::None,
         );
         layer_surface.set_exclusive_zone(conn.writer(), 30);
         layer_surface.set_anchor(conn.writer(), anchor);
@@ -162,7 +161,10 @@ impl WaylandState {
     }
 
     fn on_wldisplay_event(&mut self, conn: &Connection, event: WlEvent) {
-        match self.wl_display.parse_event(conn.reader(), event) {
+        let Some(event) = self.wl_display.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_display::Event::Error {
                 object_id,
                 code,
@@ -181,28 +183,30 @@ impl WaylandState {
         let Some(wl_registry) = self.wl_registry.as_ref() else {
             return; // this should never be reached
         };
-        match wl_registry.parse_event(conn.reader(), event) {
+        let Some(event) = wl_registry.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_registry::Event::Global {
                 name,
                 interface,
                 version,
             } => match interface {
                 "wl_compositor" => {
-                    let wl_compositor = wl_registry.bind(conn.writer(), name, interface, version);
+                    let wl_compositor = wl_registry.bind(conn.writer(), name, version);
                     self.wl_compositor = Some(wl_compositor);
                 }
                 "wp_viewporter" => {
-                    // let viewporter = wl_registry.bind(&conn.writer(), name, interface, version);
+                    // let viewporter = wl_registry.bind(&conn.writer(), name, version);
                     // self.viewporter = Some(viewporter);
                 }
                 "zwlr_layer_shell_v1" => {
-                    let wlr_layer_shell =
-                        wl_registry.bind(&conn.writer(), name, interface, version);
+                    let wlr_layer_shell = wl_registry.bind(conn.writer(), name, version);
                     self.wlr_layer_shell = Some(wlr_layer_shell);
                 }
                 "wp_single_pixel_buffer_manager_v1" => {
                     let spm: WpSinglePixelBufferManagerV1 =
-                        wl_registry.bind(&conn.writer(), name, interface, version);
+                        wl_registry.bind(conn.writer(), name, version);
                     let wl_buffer = spm.create_u32_rgba_buffer(
                         conn.writer(),
                         (u32::MAX / 255) * 170,
@@ -216,7 +220,7 @@ impl WaylandState {
                 }
                 "wl_output" => {
                     let wl_output: wl_output::WlOutput =
-                        wl_registry.bind(&conn.writer(), name, interface, version);
+                        wl_registry.bind(conn.writer(), name, version);
                     self.register_cb(Self::on_output_event, wl_output.id());
                     self.outputs.push(Output {
                         wl_output,
@@ -237,7 +241,10 @@ impl WaylandState {
         let Some(layer_surface) = self.layer_surface.as_ref() else {
             return;
         };
-        match layer_surface.parse_event(conn.reader(), event) {
+        let Some(event) = layer_surface.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             zwlr_layer_surface_v1::Event::Configure {
                 serial,
                 width,
@@ -269,16 +276,17 @@ impl WaylandState {
         let Some(wl_surface) = self.wl_surface.as_ref() else {
             return;
         };
-        match wl_surface.parse_event(conn.reader(), event) {
-            _ => {}
-        }
+        wl_surface.parse_event(conn.reader(), event);
     }
 
     fn on_wlbuffer_event(&mut self, conn: &Connection, event: WlEvent<'_>) {
         let Some(wl_buffer) = self.wl_buffer.as_ref() else {
             return; // this should never be reached
         };
-        match wl_buffer.parse_event(conn.reader(), event) {
+        let Some(event) = wl_buffer.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_buffer::Event::Release => {}
         }
     }
@@ -291,7 +299,10 @@ impl WaylandState {
         else {
             return;
         };
-        match output.wl_output.parse_event(conn.reader(), event) {
+        let Some(event) = output.wl_output.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_output::Event::Geometry {
                 x,
                 y,
@@ -5,6 +5,7 @@ use std::{
     os::fd::{AsRawFd, RawFd},
 };
 
+use scratchway::events::WEnum;
 use scratchway::log;
 use scratchway::prelude::*;
 use scratchway::wayland::*;
@@ -21,6 +22,8 @@ mod clibs;
 
 fn main() -> std::io::Result<()> {
     let conn = Connection::connect()?;
+    // Lists whatever `App::cleanup` missed when the connection is dropped
+    conn.set_leak_report(true);
 
     let wl_display = conn.display();
     let wl_registry = wl_display.get_registry(conn.writer());
@@ -109,14 +112,17 @@ struct Xkb {
 impl App {
     fn on_wlseat(&mut self, conn: &Connection, event: WlEvent) {
         let wl_seat = unsafe { self.wl_seat.as_ref().unwrap_unchecked() };
-        match wl_seat.parse_event(conn.reader(), event) {
+        let Some(event) = wl_seat.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_seat::Event::Capabilities { capabilities } => {
-                if capabilities & wl_seat::CAPABILITY_POINTER > 0 {
+                if capabilities.contains(wl_seat::Capability::POINTER) {
                     let wl_pointer = wl_seat.get_pointer(conn.writer());
                     self.callbacks.push((wl_pointer.id(), Self::on_wlpointer));
                     self.wl_pointer = Some(wl_pointer);
                 }
-                if capabilities & wl_seat::CAPABILITY_KEYBOARD > 0 {
+                if capabilities.contains(wl_seat::Capability::KEYBOARD) {
                     let wl_keyboard = wl_seat.get_keyboard(conn.writer());
                     self.callbacks.push((wl_keyboard.id(), Self::on_wlkeyboard));
                     self.wl_keyboard = Some(wl_keyboard);
@@ -128,7 +134,10 @@ impl App {
 
     fn on_wlkeyboard(&mut self, conn: &Connection, event: WlEvent) {
         let wl_keyboard = unsafe { self.wl_keyboard.as_ref().unwrap_unchecked() };
-        match wl_keyboard.parse_event(conn.reader(), event) {
+        let Some(event) = wl_keyboard.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_keyboard::Event::Keymap { fd, size, .. } => unsafe {
                 let p_keymap = libc::mmap(
                     core::ptr::null_mut(),
@@ -169,7 +178,7 @@ impl App {
                         //     buf.as_mut_ptr().cast(),
                         //     4,
                         // );
-                        if state == 1 {
+                        if state == WEnum::Value(wl_keyboard::KeyState::Pressed) {
                             let mut name = [0u8; 64];
                             let len = xkbcommon::xkb_keysym_get_name(
                                 keysym,
@@ -182,7 +191,7 @@ impl App {
                                     .unwrap(),
                             );
                         }
-                        xkbcommon::xkb_state_update_key(self.xkb.state, key + 8, state);
+                        xkbcommon::xkb_state_update_key(self.xkb.state, key + 8, state.into());
                         // log!(
                         //     DEBUG,
                         //     "{:?}",
@@ -220,7 +229,10 @@ impl App {
     }
 
     fn on_wldisplay(&mut self, conn: &Connection, event: WlEvent) {
-        match self.wl_display.parse_event(conn.reader(), event) {
+        let Some(event) = self.wl_display.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_display::Event::Error {
                 object_id,
                 code,
@@ -242,39 +254,38 @@ impl App {
         // let Some(wl_registry) = self.wl_registry.as_ref() else {
         //     return; // this should never be reached
         // };
-        match wl_registry.parse_event(conn.reader(), event) {
+        let Some(event) = wl_registry.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_registry::Event::Global {
                 name,
                 interface,
                 version,
             } => match interface {
                 "wp_viewporter" => {
-                    self.viewporter =
-                        Some(wl_registry.bind(conn.writer(), name, interface, version));
+                    self.viewporter = Some(wl_registry.bind(conn.writer(), name, version));
                 }
                 "wl_shm" => {
-                    let wl_shm: wl_shm::WlShm =
-                        wl_registry.bind(conn.writer(), name, interface, version);
+                    let wl_shm: wl_shm::WlShm = wl_registry.bind(conn.writer(), name, version);
                     self.wl_shm = Some(wl_shm);
                     self.init_shm(conn);
                 }
                 "wl_seat" => {
-                    let wl_seat: wl_seat::WlSeat =
-                        wl_registry.bind(conn.writer(), name, interface, version);
+                    let wl_seat: wl_seat::WlSeat = wl_registry.bind(conn.writer(), name, version);
                     self.callbacks.push((wl_seat.id(), Self::on_wlseat));
                     self.wl_seat = Some(wl_seat);
                 }
                 "wl_compositor" => {
                     let wl_compositor: wl_compositor::WlCompositor =
-                        wl_registry.bind(conn.writer(), name, interface, version);
+                        wl_registry.bind(conn.writer(), name, version);
                     let wl_surface = wl_compositor.create_surface(conn.writer());
 
                     self.callbacks.push((wl_surface.id(), Self::on_wlsurface));
                     self.wl_surface = Some(wl_surface);
                 }
                 "xdg_wm_base" => {
-                    let xdg_wm_base: XdgWmBase =
-                        wl_registry.bind(conn.writer(), name, interface, version);
+                    let xdg_wm_base: XdgWmBase = wl_registry.bind(conn.writer(), name, version);
                     self.callbacks.push((xdg_wm_base.id(), Self::on_xdgwmbase));
 
                     self.xdg_wm_base = Some(xdg_wm_base);
@@ -293,7 +304,10 @@ impl App {
         // let Some(xdg_surface) = self.xdg_surface.as_ref() else {
         //     return;
         // };
-        match xdg_surface.parse_event(conn.reader(), event) {
+        let Some(event) = xdg_surface.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             xdg_surface::Event::Configure { serial } => {
                 xdg_surface.ack_configure(conn.writer(), serial);
 
@@ -341,7 +355,10 @@ impl App {
 
     fn on_xdgwmbase(&mut self, conn: &Connection, event: WlEvent<'_>) {
         let xdg_wm_base = unsafe { self.xdg_wm_base.as_ref().unwrap_unchecked() };
-        match xdg_wm_base.parse_event(conn.reader(), event) {
+        let Some(event) = xdg_wm_base.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             xdg_wm_base::Event::Ping { serial } => {
                 xdg_wm_base.pong(conn.writer(), serial);
             }
@@ -350,7 +367,10 @@ impl App {
 
     fn on_xdgtoplevel(&mut self, conn: &Connection, event: WlEvent<'_>) {
         let xdg_toplevel = unsafe { self.xdg_toplevel.as_ref().unwrap_unchecked() };
-        match xdg_toplevel.parse_event(conn.reader(), event) {
+        let Some(event) = xdg_toplevel.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             xdg_toplevel::Event::Configure { width, height, .. } => {
                 if width != 0 && height != 0 {
                     if self.window_width != width || self.window_height != height {
@@ -372,7 +392,10 @@ impl App {
         let Some(wl_buffer) = self.wl_buffer.as_ref() else {
             return;
         };
-        match wl_buffer.parse_event(conn.reader(), event) {
+        let Some(event) = wl_buffer.parse_event(conn.reader(), event) else {
+            return conn.malformed(event);
+        };
+        match event {
             wl_buffer::Event::Release => {}
         }
     }
@@ -452,8 +475,14 @@ impl App {
 
         let wl_shm = self.wl_shm.as_ref().unwrap();
         let wl_shm_pool = wl_shm.create_pool(conn.writer(), self.shm_fd, self.shm_pool_size);
-        let wl_buffer =
-            wl_shm_pool.create_buffer(conn.writer(), 0, self.width, self.height, self.stride, 1);
+        let wl_buffer = wl_shm_pool.create_buffer(
+            conn.writer(),
+            0,
+            self.width,
+            self.height,
+            self.stride,
+            wl_shm::Format::Xrgb8888,
+        );
         // unsafe {
         //     libc::close(self.shm_fd);
         //     self.shm_fd = 0;
@@ -542,7 +571,7 @@ impl App {
         wl_surface.commit(conn.writer());
     }
 
-    fn cleanup(&self, conn: &Connection) {
+    fn cleanup(&mut self, conn: &Connection) {
         unsafe {
             if self.shm_fd != 0 {
                 libc::close(self.shm_fd);
@@ -561,35 +590,43 @@ impl App {
             // }
         }
 
-        if let Some(ref o) = self.wl_pointer {
+        if let Some(o) = self.wl_pointer.take() {
+            o.release(conn.writer());
+        }
+
+        if let Some(o) = self.wl_keyboard.take() {
             o.release(conn.writer());
         }
 
-        if let Some(ref o) = self.wl_buffer {
+        if let Some(o) = self.wl_buffer.take() {
+            o.destroy(conn.writer());
+        }
+
+        if let Some(o) = self.wl_shm_pool.take() {
             o.destroy(conn.writer());
         }
 
-        if let Some(ref o) = self.xdg_toplevel {
+        if let Some(o) = self.xdg_toplevel.take() {
             o.destroy(conn.writer());
         }
 
-        if let Some(ref o) = self.viewport {
+        if let Some(o) = self.viewport.take() {
             o.destroy(conn.writer());
         }
 
-        if let Some(ref o) = self.viewporter {
+        if let Some(o) = self.viewporter.take() {
             o.destroy(conn.writer());
         }
 
-        if let Some(ref o) = self.xdg_surface {
+        if let Some(o) = self.xdg_surface.take() {
             o.destroy(conn.writer());
         }
 
-        if let Some(ref o) = self.xdg_wm_base {
+        if let Some(o) = self.xdg_wm_base.take() {
             o.destroy(conn.writer());
         }
 
-        if let Some(ref o) = self.wl_surface {
+        if let Some(o) = self.wl_surface.take() {
             o.destroy(conn.writer());
         }
     }
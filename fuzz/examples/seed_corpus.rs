@@ -0,0 +1,100 @@
+//! Writes the seed corpus of the fuzz targets to `corpus/`:
+//!
+//! - `decode_events`: every event received in the traces in `traces/`, recorded with
+//!   `SCR_TRACE_FILE`, prefixed with the byte picking the interface of its object.
+//! - `parse_xml`: the bundled protocol files.
+//!
+//! Run it from `fuzz/` with `cargo run --example seed_corpus`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use scratchway::events::{EventDataParser, Header};
+use scratchway::interface::{ArgType, Interface, MessageDesc};
+use scratchway::trace::{self, Direction};
+use scratchway_fuzz::{PARSERS, selector};
+
+fn main() -> io::Result<()> {
+    let events = Path::new("corpus/decode_events");
+    fs::create_dir_all(events)?;
+    for trace in fs::read_dir("traces")? {
+        let path = trace?.path();
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let records = trace::load(&path)?;
+        let mut objects = HashMap::from([(1, "wl_display")]);
+        let mut written = 0;
+        for record in &records {
+            let Some(header) = record.header() else {
+                continue;
+            };
+            let Some(interface) = objects.get(&header.id).and_then(|name| find(name)) else {
+                continue;
+            };
+            let desc = match record.direction {
+                Direction::Sent => interface.request(header.opcode),
+                Direction::Received => interface.event(header.opcode),
+            };
+            let Some(desc) = desc else {
+                continue;
+            };
+            created(desc, &record.data[Header::HEADER_SIZE..], &mut objects);
+            if record.direction == Direction::Received
+                && let Some(selector) = selector(interface.name)
+            {
+                let seed = [&[selector][..], &record.data].concat();
+                fs::write(events.join(format!("{name}-{written:04}")), seed)?;
+                written += 1;
+            }
+        }
+        println!("{}: {written} events", path.display());
+    }
+
+    let xml = Path::new("corpus/parse_xml");
+    fs::create_dir_all(xml)?;
+    for dir in ["../protocols", "../scr_protocols/protocols"] {
+        for file in fs::read_dir(dir)? {
+            let path = file?.path();
+            fs::copy(&path, xml.join(path.file_name().unwrap()))?;
+        }
+    }
+    Ok(())
+}
+
+fn find(name: &str) -> Option<&'static Interface> {
+    PARSERS
+        .iter()
+        .map(|parser| parser.desc)
+        .find(|desc| desc.name == name)
+}
+
+/// Adds the objects created by a message with `args` to `objects`.
+fn created(desc: &MessageDesc, args: &[u8], objects: &mut HashMap<u32, &'static str>) {
+    let args = EventDataParser::new(args);
+    for arg in desc.args {
+        match arg.ty {
+            ArgType::Fd => {}
+            ArgType::String | ArgType::Array => {
+                args.get_bytes();
+            }
+            ArgType::NewId => {
+                // Untyped ids are preceded by the interface name and version
+                let interface = match arg.interface {
+                    Some(desc) => Some(desc.name),
+                    None => {
+                        let name = args.get_string();
+                        args.get_u32();
+                        name.and_then(find).map(|desc| desc.name)
+                    }
+                };
+                if let (Some(id), Some(interface)) = (args.get_u32(), interface) {
+                    objects.insert(id, interface);
+                }
+            }
+            _ => {
+                args.get_u32();
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Arbitrary bytes through `EventIter`, `EventDataParser` and the generated `parse_event`,
+//! the first byte picks the interface, see `scratchway_fuzz::decode_events`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    scratchway_fuzz::decode_events(data);
+});
@@ -0,0 +1,12 @@
+//! Arbitrary protocol xml through the scanner's parser.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scr_scanner::parser::Parser;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(xml) = std::str::from_utf8(data) {
+        let _ = Parser::new(xml).get_grotocol();
+    }
+});
@@ -0,0 +1,69 @@
+//! What the fuzz targets share: every interface scratchway generates code for, and decoding
+//! byte streams as the events of one of them.
+
+use std::hint::black_box;
+use std::os::unix::net::UnixStream;
+use std::sync::LazyLock;
+
+use scratchway::connection::{Connection, Reader, WaylandBuffer};
+use scratchway::events::{EventIter, WlEvent};
+use scratchway::interface::{ArgType, Parser};
+
+/// The parsers of the core protocol and everything in `scr_protocols`.
+pub static PARSERS: LazyLock<Vec<Parser>> = LazyLock::new(|| {
+    [
+        scratchway::wayland::PARSERS,
+        &scr_protocols::ALL_PARSERS.concat(),
+    ]
+    .concat()
+});
+
+fn decode(parser: &Parser, reader: &WaylandBuffer<Reader>, event: WlEvent<'_>) {
+    // Unknown opcodes are a bug of the caller and fds come with the ancillary data, which the
+    // fuzzer doesn't control, so those are left out like a well-behaved compositor would
+    let Some(desc) = parser.desc.event(event.header.opcode) else {
+        return;
+    };
+    if desc.args.iter().any(|arg| arg.ty == ArgType::Fd) {
+        return;
+    }
+    black_box((parser.parse)(reader, event));
+}
+
+/// Decodes `data` after its first byte as events of the interface that byte picks.
+pub fn decode_events(data: &[u8]) {
+    let Some((&selector, data)) = data.split_first() else {
+        return;
+    };
+    let entry = &PARSERS[selector as usize % PARSERS.len()];
+    // A fresh connection every time so objects created by earlier inputs don't linger
+    let (socket, _peer) = UnixStream::pair().unwrap();
+    let conn = Connection::from_fd(socket.into());
+    for event in EventIter::new(data) {
+        decode(entry, conn.reader(), event);
+    }
+}
+
+/// The byte [`decode_events`] needs to pick `interface`.
+pub fn selector(interface: &str) -> Option<u8> {
+    PARSERS
+        .iter()
+        .position(|entry| entry.desc.name == interface)
+        .map(|i| i as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inputs that crashed `decode_events`.
+    #[test]
+    fn regressions() {
+        let display = selector("wl_display").unwrap();
+        // A `wl_display.error` header without the body
+        let mut input = vec![display];
+        input.extend_from_slice(&1u32.to_ne_bytes());
+        input.extend_from_slice(&(8u32 << 16).to_ne_bytes());
+        decode_events(&input);
+    }
+}
@@ -0,0 +1,31 @@
+use scr_scanner::{Generator, Side};
+
+/// (protocol file, generated module), the protocols implemented beyond the core one.
+const PROTOCOLS: &[(&str, &str)] = &[
+    ("../scr_protocols/protocols/xdg-shell.xml", "xdg_shell"),
+    (
+        "../scr_protocols/protocols/wlr-layer-shell-unstable-v1.xml",
+        "wlr_layer_shell_unstable_v1",
+    ),
+    (
+        "../scr_protocols/protocols/single-pixel-buffer-v1.xml",
+        "single_pixel_buffer_v1",
+    ),
+    (
+        "../scr_protocols/protocols/wlr-screencopy-unstable-v1.xml",
+        "wlr_screencopy_unstable_v1",
+    ),
+];
+
+fn main() {
+    for (path, module) in PROTOCOLS {
+        let mut generator = Generator::new(path).side(Side::Server);
+        for (other, other_module) in PROTOCOLS.iter().filter(|(other, _)| other != path) {
+            generator =
+                generator.import_protocol(other, format!("crate::protocols::{other_module}"));
+        }
+        if let Err(err) = generator.write_to_out_dir(format!("{module}.rs")) {
+            panic!("{err}");
+        }
+    }
+}
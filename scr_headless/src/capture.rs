@@ -0,0 +1,152 @@
+//! `wp_single_pixel_buffer_manager_v1` and `wlr-screencopy`, captures get the composited output.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use scratchway::server::wayland::wl_buffer::WlBuffer;
+use scratchway::server::wayland::wl_output::WlOutput;
+use scratchway::server::wayland::wl_shm;
+use scratchway::server::{Client, Resource};
+
+use crate::compositor::Headless;
+use crate::image::Buffer;
+use crate::protocols::single_pixel_buffer_v1::wp_single_pixel_buffer_manager_v1::{
+    self, WpSinglePixelBufferManagerV1,
+};
+use crate::protocols::wlr_screencopy_unstable_v1::zwlr_screencopy_frame_v1::{
+    self, ZwlrScreencopyFrameV1,
+};
+use crate::protocols::wlr_screencopy_unstable_v1::zwlr_screencopy_manager_v1::{
+    self, ZwlrScreencopyManagerV1,
+};
+
+/// The part of the output a `zwlr_screencopy_frame_v1` copies.
+#[derive(Debug, Clone, Copy)]
+pub struct Capture {
+    x:      u32,
+    y:      u32,
+    width:  u32,
+    height: u32,
+}
+
+impl wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1Handler for Headless {
+    fn create_u32_rgba_buffer(
+        &mut self, client: &Client, _: &WpSinglePixelBufferManagerV1, id: WlBuffer, r: u32, g: u32,
+        b: u32, _: u32,
+    ) {
+        self.buffers.insert(
+            (client.id(), id.id()),
+            Buffer::SinglePixel {
+                r,
+                g,
+                b,
+            },
+        );
+    }
+}
+
+impl Headless {
+    fn capture(&mut self, client: &Client, frame: ZwlrScreencopyFrameV1, capture: Capture) {
+        let writer = client.writer();
+        let stride = capture.width * 4;
+        frame.buffer(
+            writer,
+            wl_shm::Format::Xrgb8888,
+            capture.width,
+            capture.height,
+            stride,
+        );
+        if client
+            .object_info(frame.id())
+            .is_some_and(|info| info.version >= 3)
+        {
+            frame.buffer_done(writer);
+        }
+        self.captures.insert((client.id(), frame.id()), capture);
+    }
+
+    fn copy(
+        &mut self, client: &Client, frame: &ZwlrScreencopyFrameV1, buffer: &WlBuffer, damage: bool,
+    ) {
+        let writer = client.writer();
+        let Some(capture) = self.captures.remove(&(client.id(), frame.id())) else {
+            client.post_error(
+                frame.id(),
+                zwlr_screencopy_frame_v1::Error::AlreadyUsed.into(),
+                "frame was already copied",
+            );
+            return;
+        };
+        let image = self
+            .composite()
+            .crop(capture.x, capture.y, capture.width, capture.height);
+        match self.buffers.get(&(client.id(), buffer.id())) {
+            Some(target) if target.write(&image) => {}
+            _ => {
+                frame.failed(writer);
+                return;
+            }
+        }
+        frame.flags(writer, zwlr_screencopy_frame_v1::Flags::empty());
+        if damage {
+            frame.damage(writer, 0, 0, capture.width, capture.height);
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let secs = now.as_secs();
+        frame.ready(writer, (secs >> 32) as u32, secs as u32, now.subsec_nanos());
+    }
+}
+
+impl zwlr_screencopy_manager_v1::ZwlrScreencopyManagerV1Handler for Headless {
+    fn capture_output(
+        &mut self, client: &Client, _: &ZwlrScreencopyManagerV1, frame: ZwlrScreencopyFrameV1,
+        _: i32, _: WlOutput,
+    ) {
+        let capture = Capture {
+            x:      0,
+            y:      0,
+            width:  self.config.width,
+            height: self.config.height,
+        };
+        self.capture(client, frame, capture);
+    }
+
+    fn capture_output_region(
+        &mut self, client: &Client, _: &ZwlrScreencopyManagerV1, frame: ZwlrScreencopyFrameV1,
+        _: i32, _: WlOutput, x: i32, y: i32, width: i32, height: i32,
+    ) {
+        // Clamped to the output, which has no transform or scale
+        let x0 = x.clamp(0, self.config.width as i32) as u32;
+        let y0 = y.clamp(0, self.config.height as i32) as u32;
+        let x1 = x.saturating_add(width).clamp(0, self.config.width as i32) as u32;
+        let y1 = y.saturating_add(height).clamp(0, self.config.height as i32) as u32;
+        if x1 <= x0 || y1 <= y0 {
+            frame.failed(client.writer());
+            return;
+        }
+        let capture = Capture {
+            x:      x0,
+            y:      y0,
+            width:  x1 - x0,
+            height: y1 - y0,
+        };
+        self.capture(client, frame, capture);
+    }
+}
+
+impl zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1Handler for Headless {
+    fn copy(&mut self, client: &Client, frame: &ZwlrScreencopyFrameV1, buffer: WlBuffer) {
+        Headless::copy(self, client, frame, &buffer, false);
+    }
+
+    fn copy_with_damage(
+        &mut self, client: &Client, frame: &ZwlrScreencopyFrameV1, buffer: WlBuffer,
+    ) {
+        Headless::copy(self, client, frame, &buffer, true);
+    }
+
+    fn destroy(&mut self, client: &Client, frame: &ZwlrScreencopyFrameV1) {
+        self.captures.remove(&(client.id(), frame.id()));
+    }
+}
@@ -0,0 +1,966 @@
+//! The compositor state and the core protocol.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::os::fd::{IntoRawFd, OwnedFd};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Instant;
+
+use scratchway::events::{WEnum, WlEvent};
+use scratchway::log;
+use scratchway::server::wayland::*;
+use scratchway::server::{Client, ClientId, Display, Resource, ServerState};
+
+use crate::image::{ARGB8888, Buffer, Image, Pool, XRGB8888};
+use crate::protocols::single_pixel_buffer_v1::wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1;
+use crate::protocols::wlr_layer_shell_unstable_v1::zwlr_layer_shell_v1::ZwlrLayerShellV1;
+use crate::protocols::wlr_layer_shell_unstable_v1::zwlr_layer_surface_v1::{
+    self, ZwlrLayerSurfaceV1,
+};
+use crate::protocols::wlr_screencopy_unstable_v1::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1;
+use crate::protocols::wlr_screencopy_unstable_v1::zwlr_screencopy_manager_v1::ZwlrScreencopyManagerV1;
+use crate::protocols::xdg_shell::xdg_popup::XdgPopup;
+use crate::protocols::xdg_shell::xdg_positioner::XdgPositioner;
+use crate::protocols::xdg_shell::xdg_surface::XdgSurface;
+use crate::protocols::xdg_shell::xdg_toplevel::XdgToplevel;
+use crate::protocols::xdg_shell::xdg_wm_base::XdgWmBase;
+use crate::script::{Action, Script};
+
+/// Every global and the highest version implemented, a global's name is its index + 1.
+const GLOBALS: &[(&str, u32)] = &[
+    (wl_compositor::WlCompositor::INTERFACE, 6),
+    (wl_shm::WlShm::INTERFACE, 1),
+    (wl_output::WlOutput::INTERFACE, 4),
+    (wl_seat::WlSeat::INTERFACE, 7),
+    (XdgWmBase::INTERFACE, 5),
+    (ZwlrLayerShellV1::INTERFACE, 4),
+    (WpSinglePixelBufferManagerV1::INTERFACE, 1),
+    (ZwlrScreencopyManagerV1::INTERFACE, 3),
+];
+
+/// Resolved by xkbcommon against the system's xkb data.
+const KEYMAP: &str = "xkb_keymap {
+    xkb_keycodes { include \"evdev+aliases(qwerty)\" };
+    xkb_types { include \"complete\" };
+    xkb_compat { include \"complete\" };
+    xkb_symbols { include \"pc+us+inet(evdev)\" };
+};";
+
+#[derive(Debug)]
+pub struct Config {
+    pub width:      u32,
+    pub height:     u32,
+    pub background: u32,
+    /// Where committed surfaces are written to as PPM.
+    pub dump_dir:   Option<PathBuf>,
+    /// Stop after this many surfaces were dumped.
+    pub max_frames: Option<u32>,
+    pub script:     Option<Script>,
+}
+
+/// Objects are only unique per client.
+pub(crate) type Key = (ClientId, u32);
+
+#[derive(Debug, Default)]
+pub(crate) struct Surface {
+    pub(crate) role:       Role,
+    // `Some(None)` if a null buffer was attached
+    pending_buffer:        Option<Option<u32>>,
+    pending_frames:        Vec<u32>,
+    /// Frame callbacks fired on the next tick.
+    frames:                Vec<u32>,
+    pub(crate) image:      Option<Image>,
+    /// Whether the initial commit was answered with a configure.
+    pub(crate) configured: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) enum Role {
+    #[default]
+    None,
+    Toplevel {
+        xdg_surface: u32,
+        toplevel:    u32,
+    },
+    Layer {
+        layer_surface: u32,
+        width:         u32,
+        height:        u32,
+        anchor:        u32,
+    },
+}
+
+#[derive(Debug)]
+pub struct Headless {
+    pub(crate) config:         Config,
+    pub(crate) exit:           bool,
+    start:                     Instant,
+    serial:                    u32,
+    dumped:                    u32,
+    pub(crate) surfaces:       HashMap<Key, Surface>,
+    pools:                     HashMap<Key, Rc<Pool>>,
+    pub(crate) buffers:        HashMap<Key, Buffer>,
+    /// xdg_surface to wl_surface.
+    pub(crate) xdg_surfaces:   HashMap<Key, u32>,
+    /// zwlr_layer_surface_v1 to wl_surface.
+    pub(crate) layer_surfaces: HashMap<Key, u32>,
+    pub(crate) captures:       HashMap<Key, crate::capture::Capture>,
+    pointers:                  Vec<Key>,
+    keyboards:                 Vec<Key>,
+    /// The surface with keyboard and pointer focus.
+    focus:                     Option<Key>,
+}
+
+impl Headless {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            exit: false,
+            start: Instant::now(),
+            serial: 0,
+            dumped: 0,
+            surfaces: HashMap::new(),
+            pools: HashMap::new(),
+            buffers: HashMap::new(),
+            xdg_surfaces: HashMap::new(),
+            layer_surfaces: HashMap::new(),
+            captures: HashMap::new(),
+            pointers: Vec::new(),
+            keyboards: Vec::new(),
+            focus: None,
+        }
+    }
+
+    pub(crate) fn next_serial(&mut self) -> u32 {
+        self.serial += 1;
+        self.serial
+    }
+
+    pub(crate) fn time(&self) -> u32 {
+        self.start.elapsed().as_millis() as u32
+    }
+
+    pub fn should_exit(&self) -> bool {
+        self.exit
+    }
+
+    /// Fires frame callbacks and runs the input script, called about once per refresh.
+    pub fn tick(&mut self, display: &Display) {
+        let time = self.time();
+        for ((client_id, _), surface) in &mut self.surfaces {
+            let Some(client) = display.client(*client_id) else {
+                continue;
+            };
+            for callback in surface.frames.drain(..) {
+                wl_callback::WlCallback::from_id(callback).done(client.writer(), time);
+            }
+        }
+
+        if let Some((client_id, _)) = self.focus
+            && let Some(client) = display.client(client_id)
+        {
+            let actions = match &mut self.config.script {
+                Some(script) if !script.is_done() => script.poll(Instant::now()),
+                _ => Vec::new(),
+            };
+            for action in actions {
+                self.input(client, action);
+            }
+        }
+
+        for client in display.clients() {
+            // Failures show up in the next dispatch
+            let _ = client.flush();
+        }
+    }
+
+    /// Every mapped surface drawn onto the output, toplevels first.
+    pub(crate) fn composite(&self) -> Image {
+        let (width, height) = (self.config.width, self.config.height);
+        let mut canvas = Image::filled(width, height, self.config.background);
+        let mut surfaces: Vec<_> = self
+            .surfaces
+            .iter()
+            .filter(|(_, s)| s.image.is_some())
+            .collect();
+        // Toplevels below layers, older surfaces below newer ones
+        surfaces.sort_by_key(|((_, id), s)| (matches!(s.role, Role::Layer { .. }), *id));
+        for (_, surface) in surfaces {
+            let image = surface.image.as_ref().unwrap();
+            let (x, y) = match surface.role {
+                Role::Layer {
+                    anchor, ..
+                } => {
+                    let place = |start: u32, end: u32, space: u32, size: u32| -> i32 {
+                        match (anchor & start != 0, anchor & end != 0) {
+                            (true, _) => 0,
+                            (false, true) => space as i32 - size as i32,
+                            (false, false) => (space as i32 - size as i32) / 2,
+                        }
+                    };
+                    let top = zwlr_layer_surface_v1::Anchor::TOP.bits();
+                    let bottom = zwlr_layer_surface_v1::Anchor::BOTTOM.bits();
+                    let left = zwlr_layer_surface_v1::Anchor::LEFT.bits();
+                    let right = zwlr_layer_surface_v1::Anchor::RIGHT.bits();
+                    (
+                        place(left, right, width, image.width),
+                        place(top, bottom, height, image.height),
+                    )
+                }
+                _ => (0, 0),
+            };
+            canvas.draw(image, x, y);
+        }
+        canvas
+    }
+
+    fn dump(&mut self, key: Key) {
+        let Some(dir) = &self.config.dump_dir else {
+            return;
+        };
+        let surface = &self.surfaces[&key];
+        let Some(image) = &surface.image else { return };
+        self.dumped += 1;
+        let role = match surface.role {
+            Role::None => "surface",
+            Role::Toplevel {
+                ..
+            } => "toplevel",
+            Role::Layer {
+                ..
+            } => "layer",
+        };
+        let path = dir.join(format!("{:05}-{role}.ppm", self.dumped));
+        match image.save_ppm(&path) {
+            Ok(()) => log!(INFO, "Saved {}", path.display()),
+            Err(err) => log!(ERR, "Couldn't write {}: {}", path.display(), err),
+        }
+        if self.config.max_frames.is_some_and(|max| self.dumped >= max) {
+            self.exit = true;
+        }
+    }
+
+    /// Gives `key` keyboard and pointer focus if nothing has it yet.
+    fn focus(&mut self, client: &Client, key: Key) {
+        if self.focus.is_some() {
+            return;
+        }
+        self.focus = Some(key);
+        let pointers: Vec<_> = self
+            .pointers
+            .iter()
+            .filter(|p| p.0 == key.0)
+            .copied()
+            .collect();
+        for (_, pointer) in pointers {
+            self.pointer_enter(client, pointer);
+        }
+        let keyboards: Vec<_> = self
+            .keyboards
+            .iter()
+            .filter(|k| k.0 == key.0)
+            .copied()
+            .collect();
+        for (_, keyboard) in keyboards {
+            self.keyboard_enter(client, keyboard);
+        }
+    }
+
+    fn pointer_enter(&mut self, client: &Client, pointer: u32) {
+        let Some((_, surface)) = self.focus else {
+            return;
+        };
+        let serial = self.next_serial();
+        let pointer = wl_pointer::WlPointer::from_id(pointer);
+        pointer.enter(
+            client.writer(),
+            serial,
+            &wl_surface::WlSurface::from_id(surface),
+            0.0,
+            0.0,
+        );
+        pointer_frame(client, &pointer);
+    }
+
+    fn keyboard_enter(&mut self, client: &Client, keyboard: u32) {
+        let Some((_, surface)) = self.focus else {
+            return;
+        };
+        let serial = self.next_serial();
+        wl_keyboard::WlKeyboard::from_id(keyboard).enter(
+            client.writer(),
+            serial,
+            &wl_surface::WlSurface::from_id(surface),
+            &[],
+        );
+    }
+
+    fn input(&mut self, client: &Client, action: Action) {
+        let time = self.time();
+        let writer = client.writer();
+        let keyboards: Vec<_> = self
+            .keyboards
+            .iter()
+            .filter(|k| k.0 == client.id())
+            .map(|k| k.1)
+            .collect();
+        let pointers: Vec<_> = self
+            .pointers
+            .iter()
+            .filter(|p| p.0 == client.id())
+            .map(|p| p.1)
+            .collect();
+        match action {
+            Action::Key {
+                key,
+                pressed,
+            } => {
+                let state = if pressed {
+                    wl_keyboard::KeyState::Pressed
+                } else {
+                    wl_keyboard::KeyState::Released
+                };
+                for keyboard in keyboards {
+                    let serial = self.next_serial();
+                    wl_keyboard::WlKeyboard::from_id(keyboard)
+                        .key(writer, serial, time, key, state);
+                }
+            }
+            Action::Modifiers {
+                depressed,
+                latched,
+                locked,
+                group,
+            } => {
+                for keyboard in keyboards {
+                    let serial = self.next_serial();
+                    let keyboard = wl_keyboard::WlKeyboard::from_id(keyboard);
+                    keyboard.modifiers(writer, serial, depressed, latched, locked, group);
+                }
+            }
+            Action::Motion {
+                x,
+                y,
+            } => {
+                for pointer in pointers {
+                    let pointer = wl_pointer::WlPointer::from_id(pointer);
+                    pointer.motion(writer, time, x, y);
+                    pointer_frame(client, &pointer);
+                }
+            }
+            Action::Button {
+                button,
+                pressed,
+            } => {
+                let state = if pressed {
+                    wl_pointer::ButtonState::Pressed
+                } else {
+                    wl_pointer::ButtonState::Released
+                };
+                for pointer in pointers {
+                    let serial = self.next_serial();
+                    let pointer = wl_pointer::WlPointer::from_id(pointer);
+                    pointer.button(writer, serial, time, button, state);
+                    pointer_frame(client, &pointer);
+                }
+            }
+            Action::Axis {
+                horizontal,
+                value,
+            } => {
+                let axis = if horizontal {
+                    wl_pointer::Axis::HorizontalScroll
+                } else {
+                    wl_pointer::Axis::VerticalScroll
+                };
+                for pointer in pointers {
+                    let pointer = wl_pointer::WlPointer::from_id(pointer);
+                    pointer.axis(writer, time, axis, value);
+                    pointer_frame(client, &pointer);
+                }
+            }
+            Action::Close => {
+                let focus = self.focus.and_then(|key| self.surfaces.get(&key));
+                if let Some(Surface {
+                    role:
+                        Role::Toplevel {
+                            toplevel, ..
+                        },
+                    ..
+                }) = focus
+                {
+                    XdgToplevel::from_id(*toplevel).close(writer);
+                }
+            }
+            Action::Quit => self.exit = true,
+            Action::Wait(_) => unreachable!(),
+        }
+    }
+
+    fn send_globals(&self, client: &Client, registry: &wl_registry::WlRegistry) {
+        for (i, (interface, version)) in GLOBALS.iter().enumerate() {
+            registry.global(client.writer(), i as u32 + 1, interface, *version);
+        }
+    }
+}
+
+/// Groups pointer events, required from version 5 on.
+fn pointer_frame(client: &Client, pointer: &wl_pointer::WlPointer) {
+    if client
+        .object_info(pointer.id())
+        .is_some_and(|info| info.version >= 5)
+    {
+        pointer.frame(client.writer());
+    }
+}
+
+impl ServerState for Headless {
+    fn handle_request(&mut self, client: &Client, request: WlEvent<'_>) {
+        let Some(request) = client.dispatch::<wl_display::WlDisplay, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_registry::WlRegistry, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_compositor::WlCompositor, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_surface::WlSurface, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_region::WlRegion, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_shm::WlShm, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_shm_pool::WlShmPool, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_buffer::WlBuffer, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_output::WlOutput, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_seat::WlSeat, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_pointer::WlPointer, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<wl_keyboard::WlKeyboard, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<XdgWmBase, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<XdgPositioner, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<XdgSurface, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<XdgToplevel, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<XdgPopup, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<ZwlrLayerShellV1, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<ZwlrLayerSurfaceV1, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<WpSinglePixelBufferManagerV1, _>(self, request)
+        else {
+            return;
+        };
+        let Some(request) = client.dispatch::<ZwlrScreencopyManagerV1, _>(self, request) else {
+            return;
+        };
+        let Some(request) = client.dispatch::<ZwlrScreencopyFrameV1, _>(self, request) else {
+            return;
+        };
+        log!(
+            WARNING,
+            "{}: unhandled request {:?}",
+            client.id(),
+            request.header
+        );
+    }
+
+    fn client_connected(&mut self, client: &Client) {
+        log!(INFO, "{} connected", client.id());
+    }
+
+    fn client_disconnected(&mut self, client: &Client) {
+        log!(INFO, "{} disconnected", client.id());
+        let id = client.id();
+        self.surfaces.retain(|key, _| key.0 != id);
+        self.pools.retain(|key, _| key.0 != id);
+        self.buffers.retain(|key, _| key.0 != id);
+        self.xdg_surfaces.retain(|key, _| key.0 != id);
+        self.layer_surfaces.retain(|key, _| key.0 != id);
+        self.captures.retain(|key, _| key.0 != id);
+        self.pointers.retain(|key| key.0 != id);
+        self.keyboards.retain(|key| key.0 != id);
+        if self.focus.is_some_and(|key| key.0 == id) {
+            self.focus = None;
+        }
+    }
+}
+
+impl wl_display::WlDisplayHandler for Headless {
+    fn sync(
+        &mut self, client: &Client, _: &wl_display::WlDisplay, callback: wl_callback::WlCallback,
+    ) {
+        let serial = self.next_serial();
+        callback.done(client.writer(), serial);
+    }
+
+    fn get_registry(
+        &mut self, client: &Client, _: &wl_display::WlDisplay, registry: wl_registry::WlRegistry,
+    ) {
+        self.send_globals(client, &registry);
+    }
+}
+
+impl wl_registry::WlRegistryHandler for Headless {
+    fn bind(
+        &mut self, client: &Client, registry: &wl_registry::WlRegistry, name: u32, interface: &str,
+        version: u32, id: u32,
+    ) {
+        let global = name.checked_sub(1).and_then(|i| GLOBALS.get(i as usize));
+        let Some((global, max_version)) = global.filter(|(global, _)| *global == interface) else {
+            let message = format!("invalid global {interface} ({name})");
+            client.post_error(
+                registry.id(),
+                wl_display::Error::InvalidObject.into(),
+                &message,
+            );
+            return;
+        };
+        if version == 0 || version > *max_version {
+            let message = format!(
+                "invalid version for global {global} ({name}): have {max_version}, wanted {version}"
+            );
+            client.post_error(
+                registry.id(),
+                wl_display::Error::InvalidObject.into(),
+                &message,
+            );
+            return;
+        }
+        if client.reader().check_new_id(id).is_none() {
+            let message = format!("invalid new id {id} for global {global} ({name})");
+            client.post_error(
+                registry.id(),
+                wl_display::Error::InvalidObject.into(),
+                &message,
+            );
+            return;
+        }
+
+        let writer = client.writer();
+        match *global {
+            wl_compositor::WlCompositor::INTERFACE => {
+                writer.new_resource::<wl_compositor::WlCompositor>(id, version);
+            }
+            wl_shm::WlShm::INTERFACE => {
+                let shm: wl_shm::WlShm = writer.new_resource(id, version);
+                shm.format(writer, wl_shm::Format::Argb8888);
+                shm.format(writer, wl_shm::Format::Xrgb8888);
+            }
+            wl_output::WlOutput::INTERFACE => {
+                let output: wl_output::WlOutput = writer.new_resource(id, version);
+                output.geometry(
+                    writer,
+                    0,
+                    0,
+                    0,
+                    0,
+                    wl_output::Subpixel::Unknown,
+                    "scratchway",
+                    "headless",
+                    wl_output::Transform::Normal,
+                );
+                let mode = wl_output::Mode::CURRENT | wl_output::Mode::PREFERRED;
+                output.mode(
+                    writer,
+                    mode,
+                    self.config.width as i32,
+                    self.config.height as i32,
+                    60_000,
+                );
+                if version >= 2 {
+                    output.scale(writer, 1);
+                }
+                if version >= 4 {
+                    output.name(writer, "HEADLESS-1");
+                    output.description(writer, "scr-headless output");
+                }
+                if version >= 2 {
+                    output.done(writer);
+                }
+            }
+            wl_seat::WlSeat::INTERFACE => {
+                let seat: wl_seat::WlSeat = writer.new_resource(id, version);
+                seat.capabilities(
+                    writer,
+                    wl_seat::Capability::POINTER | wl_seat::Capability::KEYBOARD,
+                );
+                if version >= 2 {
+                    seat.name(writer, "seat0");
+                }
+            }
+            XdgWmBase::INTERFACE => {
+                writer.new_resource::<XdgWmBase>(id, version);
+            }
+            ZwlrLayerShellV1::INTERFACE => {
+                writer.new_resource::<ZwlrLayerShellV1>(id, version);
+            }
+            WpSinglePixelBufferManagerV1::INTERFACE => {
+                writer.new_resource::<WpSinglePixelBufferManagerV1>(id, version);
+            }
+            ZwlrScreencopyManagerV1::INTERFACE => {
+                writer.new_resource::<ZwlrScreencopyManagerV1>(id, version);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl wl_compositor::WlCompositorHandler for Headless {
+    fn create_surface(
+        &mut self, client: &Client, _: &wl_compositor::WlCompositor, id: wl_surface::WlSurface,
+    ) {
+        self.surfaces
+            .insert((client.id(), id.id()), Surface::default());
+    }
+}
+
+impl wl_region::WlRegionHandler for Headless {}
+
+impl wl_surface::WlSurfaceHandler for Headless {
+    fn destroy(&mut self, client: &Client, surface: &wl_surface::WlSurface) {
+        let key = (client.id(), surface.id());
+        self.surfaces.remove(&key);
+        if self.focus == Some(key) {
+            self.focus = None;
+        }
+    }
+
+    fn attach(
+        &mut self, client: &Client, surface: &wl_surface::WlSurface,
+        buffer: Option<wl_buffer::WlBuffer>, _: i32, _: i32,
+    ) {
+        if let Some(surface) = self.surfaces.get_mut(&(client.id(), surface.id())) {
+            surface.pending_buffer = Some(buffer.map(|b| b.id()));
+        }
+    }
+
+    fn frame(
+        &mut self, client: &Client, surface: &wl_surface::WlSurface,
+        callback: wl_callback::WlCallback,
+    ) {
+        if let Some(surface) = self.surfaces.get_mut(&(client.id(), surface.id())) {
+            surface.pending_frames.push(callback.id());
+        }
+    }
+
+    fn commit(&mut self, client: &Client, surface: &wl_surface::WlSurface) {
+        let key = (client.id(), surface.id());
+        let Some(state) = self.surfaces.get_mut(&key) else {
+            return;
+        };
+        let frames = std::mem::take(&mut state.pending_frames);
+        state.frames.extend(frames);
+
+        let mut mapped = false;
+        if let Some(buffer) = state.pending_buffer.take() {
+            state.image = None;
+            if let Some(id) = buffer {
+                match self.buffers.get(&(client.id(), id)).map(Buffer::image) {
+                    Some(Some(image)) => {
+                        state.image = Some(image);
+                        mapped = true;
+                    }
+                    Some(None) => {
+                        let message = format!("wl_buffer#{id} doesn't fit in its pool");
+                        client.post_error(id, wl_shm::Error::InvalidStride.into(), &message);
+                        return;
+                    }
+                    None => log!(WARNING, "{}: attached unknown buffer {}", client.id(), id),
+                }
+                // The contents are copied, the client can reuse the buffer right away
+                wl_buffer::WlBuffer::from_id(id).release(client.writer());
+            }
+        }
+
+        if !state.configured {
+            match state.role {
+                Role::None => {}
+                Role::Toplevel {
+                    xdg_surface,
+                    toplevel,
+                } => {
+                    state.configured = true;
+                    XdgToplevel::from_id(toplevel).configure(client.writer(), 0, 0, &[]);
+                    let serial = self.next_serial();
+                    XdgSurface::from_id(xdg_surface).configure(client.writer(), serial);
+                }
+                Role::Layer {
+                    layer_surface,
+                    width,
+                    height,
+                    ..
+                } => {
+                    state.configured = true;
+                    let width = if width == 0 { self.config.width } else { width };
+                    let height = if height == 0 {
+                        self.config.height
+                    } else {
+                        height
+                    };
+                    let serial = self.next_serial();
+                    ZwlrLayerSurfaceV1::from_id(layer_surface).configure(
+                        client.writer(),
+                        serial,
+                        width,
+                        height,
+                    );
+                }
+            }
+        }
+
+        if mapped {
+            self.dump(key);
+            self.focus(client, key);
+        }
+    }
+}
+
+impl wl_shm::WlShmHandler for Headless {
+    fn create_pool(
+        &mut self, client: &Client, _: &wl_shm::WlShm, id: wl_shm_pool::WlShmPool, fd: OwnedFd,
+        size: i32,
+    ) {
+        if size <= 0 {
+            client.post_error(
+                id.id(),
+                wl_shm::Error::InvalidStride.into(),
+                "invalid pool size",
+            );
+            return;
+        }
+        self.pools
+            .insert((client.id(), id.id()), Pool::new(fd, size as usize));
+    }
+}
+
+impl wl_shm_pool::WlShmPoolHandler for Headless {
+    #[allow(clippy::too_many_arguments)]
+    fn create_buffer(
+        &mut self, client: &Client, pool: &wl_shm_pool::WlShmPool, id: wl_buffer::WlBuffer,
+        offset: i32, width: i32, height: i32, stride: i32, format: WEnum<wl_shm::Format>,
+    ) {
+        let format = u32::from(format);
+        if format != ARGB8888 && format != XRGB8888 {
+            let message = format!("unsupported format {format:#x}");
+            client.post_error(pool.id(), wl_shm::Error::InvalidFormat.into(), &message);
+            return;
+        }
+        if offset < 0 || width <= 0 || height <= 0 || stride < width * 4 {
+            let message =
+                format!("invalid buffer {width}x{height}, stride {stride}, offset {offset}");
+            client.post_error(pool.id(), wl_shm::Error::InvalidStride.into(), &message);
+            return;
+        }
+        let Some(pool) = self.pools.get(&(client.id(), pool.id())) else {
+            return;
+        };
+        let buffer = Buffer::Shm {
+            pool: pool.clone(),
+            offset: offset as usize,
+            width: width as u32,
+            height: height as u32,
+            stride: stride as u32,
+            format,
+        };
+        self.buffers.insert((client.id(), id.id()), buffer);
+    }
+
+    fn destroy(&mut self, client: &Client, pool: &wl_shm_pool::WlShmPool) {
+        self.pools.remove(&(client.id(), pool.id()));
+    }
+
+    fn resize(&mut self, client: &Client, pool: &wl_shm_pool::WlShmPool, size: i32) {
+        if let Some(pool) = self.pools.get(&(client.id(), pool.id())) {
+            pool.resize(size.max(0) as usize);
+        }
+    }
+}
+
+impl wl_buffer::WlBufferHandler for Headless {
+    fn destroy(&mut self, client: &Client, buffer: &wl_buffer::WlBuffer) {
+        self.buffers.remove(&(client.id(), buffer.id()));
+    }
+}
+
+impl wl_output::WlOutputHandler for Headless {}
+
+impl wl_seat::WlSeatHandler for Headless {
+    fn get_pointer(&mut self, client: &Client, _: &wl_seat::WlSeat, id: wl_pointer::WlPointer) {
+        self.pointers.push((client.id(), id.id()));
+        if self.focus.is_some_and(|key| key.0 == client.id()) {
+            self.pointer_enter(client, id.id());
+        }
+    }
+
+    fn get_keyboard(&mut self, client: &Client, _: &wl_seat::WlSeat, id: wl_keyboard::WlKeyboard) {
+        self.keyboards.push((client.id(), id.id()));
+        match keymap() {
+            Ok(fd) => id.keymap(
+                client.writer(),
+                wl_keyboard::KeymapFormat::XkbV1,
+                fd.into_raw_fd(),
+                KEYMAP.len() as u32 + 1,
+            ),
+            Err(err) => log!(ERR, "Couldn't create the keymap: {}", err),
+        }
+        if client
+            .object_info(id.id())
+            .is_some_and(|info| info.version >= 4)
+        {
+            id.repeat_info(client.writer(), 25, 600);
+        }
+        if self.focus.is_some_and(|key| key.0 == client.id()) {
+            self.keyboard_enter(client, id.id());
+        }
+    }
+}
+
+impl wl_pointer::WlPointerHandler for Headless {
+    fn release(&mut self, client: &Client, pointer: &wl_pointer::WlPointer) {
+        self.pointers
+            .retain(|key| *key != (client.id(), pointer.id()));
+    }
+}
+
+impl wl_keyboard::WlKeyboardHandler for Headless {
+    fn release(&mut self, client: &Client, keyboard: &wl_keyboard::WlKeyboard) {
+        self.keyboards
+            .retain(|key| *key != (client.id(), keyboard.id()));
+    }
+}
+
+/// The keymap in a sealed memfd, null terminated as xkbcommon wants it.
+fn keymap() -> std::io::Result<OwnedFd> {
+    let fd = unsafe { libc::memfd_create(c"scr-headless-keymap".as_ptr(), libc::MFD_CLOEXEC) };
+    if fd == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let mut file = unsafe { <std::fs::File as std::os::fd::FromRawFd>::from_raw_fd(fd) };
+    file.write_all(KEYMAP.as_bytes())?;
+    file.write_all(&[0])?;
+    Ok(file.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::fd::{AsRawFd, FromRawFd};
+    use std::os::unix::net::UnixStream;
+    use std::time::Duration;
+
+    use scratchway::connection::{Connection, Object, State};
+    use scratchway::wayland::{wl_buffer as client_buffer, wl_callback as client_callback};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct App {
+        released: bool,
+        frames:   u32,
+    }
+
+    impl State for App {
+        fn handle_event(&mut self, conn: &Connection, event: WlEvent<'_>) {
+            let Some(event) = conn.dispatch::<client_buffer::WlBuffer, _>(self, event) else {
+                return;
+            };
+            conn.dispatch::<client_callback::WlCallback, _>(self, event);
+        }
+    }
+
+    impl client_buffer::WlBufferHandler for App {
+        fn release(&mut self, _: &Connection, _: &client_buffer::WlBuffer) {
+            self.released = true;
+        }
+    }
+
+    impl client_callback::WlCallbackHandler for App {
+        fn done(&mut self, _: &Connection, _: &client_callback::WlCallback, _: u32) {
+            self.frames += 1;
+        }
+    }
+
+    fn headless() -> Headless {
+        Headless::new(Config {
+            width:      4,
+            height:     2,
+            background: 0,
+            dump_dir:   None,
+            max_frames: None,
+            script:     None,
+        })
+    }
+
+    #[test]
+    fn commits_are_read_and_released() {
+        use scratchway::wayland::*;
+
+        let (server, client) = UnixStream::pair().unwrap();
+        let mut display = Display::new();
+        display.insert_client(server);
+        let conn = Connection::from_fd(client.into());
+        let mut state = headless();
+        let mut app = App::default();
+
+        let pixels: [u32; 4] = [1, 2, 3, 4];
+        let fd = unsafe { libc::memfd_create(c"test".as_ptr(), libc::MFD_CLOEXEC) };
+        let mut file = unsafe { std::fs::File::from_raw_fd(fd) };
+        file.write_all(&pixels.map(u32::to_ne_bytes).concat())
+            .unwrap();
+
+        let registry = conn.display().get_registry(conn.writer());
+        let compositor: wl_compositor::WlCompositor = registry.bind(conn.writer(), 1, 6);
+        let shm: wl_shm::WlShm = registry.bind(conn.writer(), 2, 1);
+        let surface = compositor.create_surface(conn.writer());
+        let pool = shm.create_pool(conn.writer(), unsafe { libc::dup(file.as_raw_fd()) }, 16);
+        let buffer = pool.create_buffer(conn.writer(), 0, 2, 2, 8, wl_shm::Format::Xrgb8888);
+        surface.attach(conn.writer(), Some(&buffer), 0, 0);
+        surface.frame(conn.writer());
+        surface.commit(conn.writer());
+        conn.flush().unwrap();
+
+        display.dispatch(&mut state, Some(Duration::ZERO)).unwrap();
+        let client_id = display.clients().next().unwrap().id();
+        let image = state.surfaces[&(client_id, surface.id())]
+            .image
+            .as_ref()
+            .unwrap();
+        assert_eq!(image.pixels, pixels);
+
+        // The release is flushed by the dispatch, the frame callback by the tick
+        state.tick(&display);
+        conn.dispatch_events(&mut app).unwrap();
+        assert!(app.released);
+        assert_eq!(app.frames, 1);
+
+        let canvas = state.composite();
+        assert_eq!(canvas.pixels, [1, 2, 0, 0, 3, 4, 0, 0]);
+    }
+}
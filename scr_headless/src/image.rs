@@ -0,0 +1,228 @@
+//! Client buffers and what the compositor makes of them.
+
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::os::fd::{AsRawFd, OwnedFd};
+use std::path::Path;
+use std::rc::Rc;
+
+/// `wl_shm.format` values the compositor advertises.
+pub const ARGB8888: u32 = 0;
+pub const XRGB8888: u32 = 1;
+
+/// A `wl_shm_pool`, buffers keep it alive after the pool object is destroyed.
+#[derive(Debug)]
+pub struct Pool {
+    fd:   OwnedFd,
+    size: Cell<usize>,
+}
+
+impl Pool {
+    pub fn new(fd: OwnedFd, size: usize) -> Rc<Self> {
+        Rc::new(Self {
+            fd,
+            size: Cell::new(size),
+        })
+    }
+
+    /// Pools can only grow.
+    pub fn resize(&self, size: usize) {
+        self.size.set(size.max(self.size.get()));
+    }
+
+    /// Maps the pool for the duration of `f`, `None` if `offset..offset + len` isn't inside it.
+    fn with_map<T>(
+        &self, offset: usize, len: usize, writable: bool, f: impl FnOnce(&mut [u8]) -> T,
+    ) -> Option<T> {
+        let size = self.size.get();
+        if size == 0 || offset.checked_add(len)? > size {
+            return None;
+        }
+        let prot = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+        unsafe {
+            let map = libc::mmap(
+                core::ptr::null_mut(),
+                size,
+                prot,
+                libc::MAP_SHARED,
+                self.fd.as_raw_fd(),
+                0,
+            );
+            if map == libc::MAP_FAILED {
+                return None;
+            }
+            let data = core::slice::from_raw_parts_mut(map.cast::<u8>().add(offset), len);
+            let ret = f(data);
+            libc::munmap(map, size);
+            Some(ret)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Buffer {
+    Shm {
+        pool:   Rc<Pool>,
+        offset: usize,
+        width:  u32,
+        height: u32,
+        stride: u32,
+        format: u32,
+    },
+    /// From `wp_single_pixel_buffer_manager_v1`, channels go from 0 to `u32::MAX`.
+    SinglePixel { r: u32, g: u32, b: u32 },
+}
+
+impl Buffer {
+    /// The buffer's contents, `None` if the pool is too small for it.
+    pub fn image(&self) -> Option<Image> {
+        match self {
+            Self::Shm {
+                pool,
+                offset,
+                width,
+                height,
+                stride,
+                ..
+            } => {
+                let (width, height, stride) = (*width as usize, *height as usize, *stride as usize);
+                pool.with_map(*offset, stride * height, false, |data| {
+                    let mut pixels = Vec::with_capacity(width * height);
+                    for row in data.chunks_exact(stride) {
+                        pixels.extend(
+                            row[..width * 4]
+                                .chunks_exact(4)
+                                .map(|px| u32::from_ne_bytes([px[0], px[1], px[2], px[3]])),
+                        );
+                    }
+                    Image {
+                        width: width as u32,
+                        height: height as u32,
+                        pixels,
+                    }
+                })
+            }
+            Self::SinglePixel {
+                r,
+                g,
+                b,
+            } => Some(Image {
+                width:  1,
+                height: 1,
+                pixels: vec![((r >> 24) << 16) | ((g >> 24) << 8) | (b >> 24)],
+            }),
+        }
+    }
+
+    /// Copies `image` into the buffer, which has to be an xrgb/argb shm buffer of the same size.
+    pub fn write(&self, image: &Image) -> bool {
+        let Self::Shm {
+            pool,
+            offset,
+            width,
+            height,
+            stride,
+            format,
+        } = self
+        else {
+            return false;
+        };
+        if (*width, *height) != (image.width, image.height)
+            || ![ARGB8888, XRGB8888].contains(format)
+        {
+            return false;
+        }
+        let stride = *stride as usize;
+        pool.with_map(*offset, stride * *height as usize, true, |data| {
+            for (row, pixels) in data
+                .chunks_exact_mut(stride)
+                .zip(image.pixels.chunks_exact(image.width as usize))
+            {
+                for (px, pixel) in row.chunks_exact_mut(4).zip(pixels) {
+                    px.copy_from_slice(&(pixel | 0xff00_0000).to_ne_bytes());
+                }
+            }
+        })
+        .is_some()
+    }
+}
+
+/// Pixels as `0xAARRGGBB`, what xrgb8888 and argb8888 buffers hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width:  u32,
+    pub height: u32,
+    pub pixels: Vec<u32>,
+}
+
+impl Image {
+    pub fn filled(width: u32, height: u32, color: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; (width * height) as usize],
+        }
+    }
+
+    /// Draws `other` with its top left corner at `x, y`, alpha is ignored.
+    pub fn draw(&mut self, other: &Image, x: i32, y: i32) {
+        for oy in 0..other.height as i32 {
+            let ty = y + oy;
+            if ty < 0 || ty >= self.height as i32 {
+                continue;
+            }
+            for ox in 0..other.width as i32 {
+                let tx = x + ox;
+                if tx < 0 || tx >= self.width as i32 {
+                    continue;
+                }
+                self.pixels[(ty as u32 * self.width + tx as u32) as usize] =
+                    other.pixels[(oy as u32 * other.width + ox as u32) as usize];
+            }
+        }
+    }
+
+    /// A `width` x `height` part of the image starting at `x, y`.
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Image {
+        let mut out = Image::filled(width, height, 0);
+        out.draw(self, -(x as i32), -(y as i32));
+        out
+    }
+
+    /// Writes the image as a binary PPM.
+    pub fn save_ppm(&self, path: &Path) -> io::Result<()> {
+        let mut file = io::BufWriter::new(std::fs::File::create(path)?);
+        write!(file, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for pixel in &self.pixels {
+            file.write_all(&[(pixel >> 16) as u8, (pixel >> 8) as u8, *pixel as u8])?;
+        }
+        file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_clips_to_the_image() {
+        let mut canvas = Image::filled(3, 2, 0);
+        canvas.draw(&Image::filled(2, 2, 7), 2, -1);
+        assert_eq!(canvas.pixels, [0, 0, 7, 0, 0, 0]);
+        assert_eq!(canvas.crop(2, 0, 2, 1).pixels, [7, 0]);
+    }
+
+    #[test]
+    fn single_pixel_buffers_keep_the_top_bits() {
+        let buffer = Buffer::SinglePixel {
+            r: u32::MAX,
+            g: u32::MAX / 2,
+            b: 0,
+        };
+        assert_eq!(buffer.image().unwrap().pixels, [0x00ff7f00]);
+    }
+}
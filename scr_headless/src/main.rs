@@ -0,0 +1,155 @@
+//! A compositor without any output, for running clients in CI.
+//!
+//! Usage: scr-headless [--socket NAME] [--size WxH] [--dump DIR] [--input FILE] [--frames N] [--once]
+//! [-- command args...]
+//!
+//! Committed surfaces are written to `DIR` as PPM, `--input` runs an input [script](script).
+//! With a command it's spawned with `WAYLAND_DISPLAY` set and its exit status is returned.
+
+mod capture;
+mod compositor;
+mod image;
+mod protocols;
+mod script;
+mod shell;
+
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+use std::time::Duration;
+
+use scratchway::server::{Display, ListeningSocket};
+
+use crate::compositor::{Config, Headless};
+use crate::script::Script;
+
+const USAGE: &str = "Usage: scr-headless [--socket NAME] [--size WxH] [--dump DIR] [--input FILE] [--frames N] [--once] [-- command args...]";
+
+/// How often frame callbacks fire and scripted input is sent.
+const REFRESH: Duration = Duration::from_millis(16);
+
+fn main() -> ExitCode {
+    let mut socket = None;
+    let mut once = false;
+    let mut command = Vec::new();
+    let mut config = Config {
+        width:      1920,
+        height:     1080,
+        background: 0x00_20_20_20,
+        dump_dir:   None,
+        max_frames: None,
+        script:     None,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || match args.next() {
+            Some(value) => Ok(value),
+            None => Err(format!("{arg} requires a value")),
+        };
+        let parsed = match arg.as_str() {
+            "--socket" => value().map(|name| socket = Some(name)),
+            "--size" => value().and_then(|size| {
+                let (w, h) = size
+                    .split_once('x')
+                    .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                    .filter(|&(w, h): &(u32, u32)| w > 0 && h > 0)
+                    .ok_or(format!("invalid size `{size}`, expected WxH"))?;
+                (config.width, config.height) = (w, h);
+                Ok(())
+            }),
+            "--dump" => value().map(|dir| config.dump_dir = Some(PathBuf::from(dir))),
+            "--input" => value().and_then(|path| {
+                let text =
+                    std::fs::read_to_string(&path).map_err(|err| format!("{path}: {err}"))?;
+                config.script = Some(Script::parse(&text).map_err(|err| format!("{path}: {err}"))?);
+                Ok(())
+            }),
+            "--frames" => value().and_then(|n| {
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("invalid frame count `{n}`"))?;
+                config.max_frames = Some(n);
+                Ok(())
+            }),
+            "--once" => {
+                once = true;
+                Ok(())
+            }
+            "--" => {
+                command.extend(args.by_ref());
+                Ok(())
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => Err(format!("unexpected argument: {arg}")),
+        };
+        if let Err(err) = parsed {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(dir) = &config.dump_dir
+        && let Err(err) = std::fs::create_dir_all(dir)
+    {
+        eprintln!("{}: {err}", dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let socket = match socket {
+        Some(name) => ListeningSocket::bind(name),
+        None => ListeningSocket::bind_auto(),
+    };
+    let mut display = match socket {
+        Ok(socket) => Display::with_socket(socket),
+        Err(err) => {
+            eprintln!("couldn't create the socket: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let name = display.socket_name().unwrap().to_owned();
+    println!("WAYLAND_DISPLAY={}", name.to_string_lossy());
+
+    let mut child = match command.split_first() {
+        Some((program, args)) => match Command::new(program)
+            .args(args)
+            .env("WAYLAND_DISPLAY", &name)
+            .spawn()
+        {
+            Ok(child) => Some(child),
+            Err(err) => {
+                eprintln!("couldn't run {program}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let mut state = Headless::new(config);
+    let mut had_clients = false;
+    loop {
+        if let Err(err) = display.dispatch(&mut state, Some(REFRESH)) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+        state.tick(&display);
+
+        if let Some(child) = &mut child
+            && let Ok(Some(status)) = child.try_wait()
+        {
+            return ExitCode::from(status.code().unwrap_or(1).clamp(0, 255) as u8);
+        }
+        had_clients |= display.clients().next().is_some();
+        if state.should_exit() || (once && had_clients && display.clients().next().is_none()) {
+            break;
+        }
+    }
+
+    if let Some(mut child) = child {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,21 @@
+//! Server side code of the protocols implemented beyond the core one, generated by `build.rs`.
+
+macro_rules! protocol {
+    ($module:ident) => {
+        pub mod $module {
+            #![allow(unused, clippy::enum_variant_names)]
+
+            use scratchway::connection::{Reader, WaylandBuffer, Writer};
+            use scratchway::events::*;
+            use scratchway::interface;
+            use scratchway::server::{Client, Dispatch, Resource};
+
+            include!(concat!(env!("OUT_DIR"), "/", stringify!($module), ".rs"));
+        }
+    };
+}
+
+protocol!(xdg_shell);
+protocol!(wlr_layer_shell_unstable_v1);
+protocol!(single_pixel_buffer_v1);
+protocol!(wlr_screencopy_unstable_v1);
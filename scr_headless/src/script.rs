@@ -0,0 +1,185 @@
+//! Scripted input, one action per line:
+//!
+//! ```text
+//! # evdev key and button codes, times in milliseconds
+//! wait 500
+//! key 30 press
+//! key 30 release
+//! modifiers 1 0 0 0
+//! motion 100 40.5
+//! button 272 press
+//! axis vertical 10
+//! close
+//! quit
+//! ```
+//!
+//! The script starts once a surface has keyboard and pointer focus.
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Wait(Duration),
+    Key {
+        key:     u32,
+        pressed: bool,
+    },
+    Modifiers {
+        depressed: u32,
+        latched:   u32,
+        locked:    u32,
+        group:     u32,
+    },
+    Motion {
+        x: f32,
+        y: f32,
+    },
+    Button {
+        button:  u32,
+        pressed: bool,
+    },
+    Axis {
+        horizontal: bool,
+        value:      f32,
+    },
+    /// Asks the focused toplevel to close.
+    Close,
+    /// Stops the compositor.
+    Quit,
+}
+
+#[derive(Debug)]
+pub struct Script {
+    actions: Vec<Action>,
+    next:    usize,
+    due:     Option<Instant>,
+}
+
+impl Script {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut actions = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let action =
+                parse_line(line).map_err(|err| format!("line {}: {err}: `{line}`", n + 1))?;
+            actions.push(action);
+        }
+        Ok(Self {
+            actions,
+            next: 0,
+            due: None,
+        })
+    }
+
+    /// The actions due at `now`, waits are handled here.
+    pub fn poll(&mut self, now: Instant) -> Vec<Action> {
+        let mut due = Vec::new();
+        while let Some(action) = self.actions.get(self.next) {
+            if self.due.is_some_and(|due| now < due) {
+                break;
+            }
+            self.next += 1;
+            match action {
+                Action::Wait(duration) => self.due = Some(now + *duration),
+                action => due.push(action.clone()),
+            }
+        }
+        due
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.next >= self.actions.len()
+    }
+}
+
+fn parse_line(line: &str) -> Result<Action, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let num = |i: usize| -> Result<u32, String> {
+        let word = words.get(i).ok_or("missing argument")?;
+        word.parse().map_err(|_| format!("`{word}` isn't a number"))
+    };
+    let float = |i: usize| -> Result<f32, String> {
+        let word = words.get(i).ok_or("missing argument")?;
+        word.parse().map_err(|_| format!("`{word}` isn't a number"))
+    };
+    let pressed = |i: usize| -> Result<bool, String> {
+        match words.get(i) {
+            Some(&"press") => Ok(true),
+            Some(&"release") => Ok(false),
+            _ => Err("expected `press` or `release`".into()),
+        }
+    };
+    let action = match words[0] {
+        "wait" => Action::Wait(Duration::from_millis(num(1)?.into())),
+        "key" => Action::Key {
+            key:     num(1)?,
+            pressed: pressed(2)?,
+        },
+        "modifiers" => Action::Modifiers {
+            depressed: num(1)?,
+            latched:   num(2)?,
+            locked:    num(3)?,
+            group:     num(4)?,
+        },
+        "motion" => Action::Motion {
+            x: float(1)?,
+            y: float(2)?,
+        },
+        "button" => Action::Button {
+            button:  num(1)?,
+            pressed: pressed(2)?,
+        },
+        "axis" => Action::Axis {
+            horizontal: match words.get(1) {
+                Some(&"vertical") => false,
+                Some(&"horizontal") => true,
+                _ => return Err("expected `vertical` or `horizontal`".into()),
+            },
+            value:      float(2)?,
+        },
+        "close" => Action::Close,
+        "quit" => Action::Quit,
+        other => return Err(format!("unknown action `{other}`")),
+    };
+    Ok(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_delay_the_rest() {
+        let mut script =
+            Script::parse("key 30 press # a\n\nwait 100\nkey 30 release\nquit").unwrap();
+        let start = Instant::now();
+        assert_eq!(
+            script.poll(start),
+            [Action::Key {
+                key:     30,
+                pressed: true,
+            }]
+        );
+        assert_eq!(script.poll(start + Duration::from_millis(50)), []);
+        assert_eq!(
+            script.poll(start + Duration::from_millis(100)),
+            [
+                Action::Key {
+                    key:     30,
+                    pressed: false,
+                },
+                Action::Quit
+            ]
+        );
+        assert!(script.is_done());
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let err = Script::parse("wait 10\nkey 30 down").unwrap_err();
+        assert_eq!(err, "line 2: expected `press` or `release`: `key 30 down`");
+    }
+}
@@ -0,0 +1,166 @@
+//! `xdg_shell` and `wlr-layer-shell`, surfaces get a role and are configured on their
+//! initial commit.
+
+use scratchway::events::WEnum;
+use scratchway::server::wayland::wl_surface::WlSurface;
+use scratchway::server::{Client, Resource};
+
+use crate::compositor::{Headless, Role};
+use crate::protocols::wlr_layer_shell_unstable_v1::zwlr_layer_shell_v1::{self, ZwlrLayerShellV1};
+use crate::protocols::wlr_layer_shell_unstable_v1::zwlr_layer_surface_v1::{
+    self, ZwlrLayerSurfaceV1,
+};
+use crate::protocols::xdg_shell::xdg_popup;
+use crate::protocols::xdg_shell::xdg_positioner;
+use crate::protocols::xdg_shell::xdg_surface::{self, XdgSurface};
+use crate::protocols::xdg_shell::xdg_toplevel::{self, XdgToplevel};
+use crate::protocols::xdg_shell::xdg_wm_base::{self, XdgWmBase};
+
+impl Headless {
+    /// Gives the surface a role, a protocol error if it already has one.
+    fn set_role(
+        &mut self, client: &Client, surface: u32, role: Role, object: u32, code: u32,
+    ) -> bool {
+        let Some(state) = self.surfaces.get_mut(&(client.id(), surface)) else {
+            return false;
+        };
+        if !matches!(state.role, Role::None) {
+            client.post_error(
+                object,
+                code,
+                &format!("wl_surface#{surface} already has a role"),
+            );
+            return false;
+        }
+        state.role = role;
+        true
+    }
+}
+
+impl xdg_wm_base::XdgWmBaseHandler for Headless {
+    fn get_xdg_surface(
+        &mut self, client: &Client, _: &XdgWmBase, id: XdgSurface, surface: WlSurface,
+    ) {
+        self.xdg_surfaces
+            .insert((client.id(), id.id()), surface.id());
+    }
+}
+
+impl xdg_positioner::XdgPositionerHandler for Headless {}
+
+impl xdg_surface::XdgSurfaceHandler for Headless {
+    fn destroy(&mut self, client: &Client, surface: &XdgSurface) {
+        self.xdg_surfaces.remove(&(client.id(), surface.id()));
+    }
+
+    fn get_toplevel(&mut self, client: &Client, xdg_surface: &XdgSurface, id: XdgToplevel) {
+        let Some(&surface) = self.xdg_surfaces.get(&(client.id(), xdg_surface.id())) else {
+            return;
+        };
+        let role = Role::Toplevel {
+            xdg_surface: xdg_surface.id(),
+            toplevel:    id.id(),
+        };
+        self.set_role(
+            client,
+            surface,
+            role,
+            xdg_surface.id(),
+            xdg_wm_base::Error::Role.into(),
+        );
+    }
+}
+
+impl xdg_toplevel::XdgToplevelHandler for Headless {
+    fn destroy(&mut self, client: &Client, toplevel: &XdgToplevel) {
+        for ((client_id, _), surface) in &mut self.surfaces {
+            if *client_id == client.id()
+                && matches!(surface.role, Role::Toplevel { toplevel: id, .. } if id == toplevel.id())
+            {
+                surface.role = Role::None;
+                surface.image = None;
+                surface.configured = false;
+            }
+        }
+    }
+}
+
+impl xdg_popup::XdgPopupHandler for Headless {}
+
+impl zwlr_layer_shell_v1::ZwlrLayerShellV1Handler for Headless {
+    fn get_layer_surface(
+        &mut self, client: &Client, _: &ZwlrLayerShellV1, id: ZwlrLayerSurfaceV1,
+        surface: WlSurface, _: Option<scratchway::server::wayland::wl_output::WlOutput>,
+        _: WEnum<zwlr_layer_shell_v1::Layer>, _: &str,
+    ) {
+        let role = Role::Layer {
+            layer_surface: id.id(),
+            width:         0,
+            height:        0,
+            anchor:        0,
+        };
+        if self.set_role(
+            client,
+            surface.id(),
+            role,
+            id.id(),
+            zwlr_layer_shell_v1::Error::Role.into(),
+        ) {
+            self.layer_surfaces
+                .insert((client.id(), id.id()), surface.id());
+        }
+    }
+}
+
+impl Headless {
+    fn layer_role(
+        &mut self, client: &Client, layer_surface: &ZwlrLayerSurfaceV1,
+    ) -> Option<&mut Role> {
+        let surface = self
+            .layer_surfaces
+            .get(&(client.id(), layer_surface.id()))?;
+        self.surfaces
+            .get_mut(&(client.id(), *surface))
+            .map(|surface| &mut surface.role)
+    }
+}
+
+impl zwlr_layer_surface_v1::ZwlrLayerSurfaceV1Handler for Headless {
+    fn set_size(
+        &mut self, client: &Client, layer_surface: &ZwlrLayerSurfaceV1, new_width: u32,
+        new_height: u32,
+    ) {
+        if let Some(Role::Layer {
+            width,
+            height,
+            ..
+        }) = self.layer_role(client, layer_surface)
+        {
+            (*width, *height) = (new_width, new_height);
+        }
+    }
+
+    fn set_anchor(
+        &mut self, client: &Client, layer_surface: &ZwlrLayerSurfaceV1,
+        new_anchor: zwlr_layer_surface_v1::Anchor,
+    ) {
+        if let Some(Role::Layer {
+            anchor, ..
+        }) = self.layer_role(client, layer_surface)
+        {
+            *anchor = new_anchor.bits();
+        }
+    }
+
+    fn destroy(&mut self, client: &Client, layer_surface: &ZwlrLayerSurfaceV1) {
+        if let Some(surface) = self
+            .layer_surfaces
+            .remove(&(client.id(), layer_surface.id()))
+            && let Some(surface) = self.surfaces.get_mut(&(client.id(), surface))
+        {
+            surface.role = Role::None;
+            surface.image = None;
+            surface.configured = false;
+        }
+    }
+}
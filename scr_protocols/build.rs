@@ -0,0 +1,57 @@
+use scr_scanner::Generator;
+
+/// (protocol file, generated module), every protocol can reference interfaces of the others.
+const PROTOCOLS: &[(&str, &str)] = &[
+    ("./protocols/xdg-shell.xml", "xdg_shell"),
+    ("./protocols/viewporter.xml", "viewporter"),
+    (
+        "./protocols/single-pixel-buffer-v1.xml",
+        "single_pixel_buffer_v1",
+    ),
+    (
+        "./protocols/wlr-layer-shell-unstable-v1.xml",
+        "wlr_layer_shell_unstable_v1",
+    ),
+    (
+        "./protocols/wlr-screencopy-unstable-v1.xml",
+        "wlr_screencopy_unstable_v1",
+    ),
+    ("./protocols/cursor-shape-v1.xml", "cursor_shape_v1"),
+    ("./protocols/tablet-v2.xml", "tablet_v2"),
+    (
+        "./protocols/xdg-decoration-unstable-v1.xml",
+        "xdg_decoration_unstable_v1",
+    ),
+];
+
+fn main() {
+    // `ALL` and `ALL_PARSERS`, so code taking every protocol doesn't list them by hand
+    let tables = |name: &str| -> String {
+        PROTOCOLS
+            .iter()
+            .map(|(_, module)| format!("crate::{module}::{name}, "))
+            .collect()
+    };
+    let all = format!(
+        "/// The `INTERFACES` of every protocol, `scratchway::wayland` has the core protocol.\n\
+         pub static ALL: &[&[&Interface]] = &[{}];\n\
+         /// The `PARSERS` of every protocol, in the order of [`ALL`].\n\
+         pub static ALL_PARSERS: &[&[Parser]] = &[{}];\n",
+        tables("INTERFACES"),
+        tables("PARSERS"),
+    );
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    if let Err(err) = std::fs::write(format!("{out_dir}/all.rs"), all) {
+        panic!("{err}");
+    }
+
+    for (path, module) in PROTOCOLS {
+        let mut generator = Generator::new(path);
+        for (other, other_module) in PROTOCOLS.iter().filter(|(other, _)| other != path) {
+            generator = generator.import_protocol(other, format!("crate::{other_module}"));
+        }
+        if let Err(err) = generator.write_to_out_dir(format!("{module}.rs")) {
+            panic!("{err}");
+        }
+    }
+}
@@ -1,9 +1,7 @@
 #![allow(unused_mut, unused)]
-use scratchway::wayland::*;
 use scratchway::connection::{Reader, WaylandBuffer, Writer};
 use scratchway::events::*;
+use scratchway::interface;
 use scratchway::prelude::*;
-use scratchway::log;
-use crate::tablet_v2::*;
 
-scr_scanner::generate!("./protocols/cursor-shape-v1.xml");
+include!(concat!(env!("OUT_DIR"), "/cursor_shape_v1.rs"));
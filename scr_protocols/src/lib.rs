@@ -6,3 +6,7 @@ pub mod wlr_screencopy_unstable_v1;
 pub mod cursor_shape_v1;
 pub mod tablet_v2;
 pub mod xdg_decoration_unstable_v1;
+
+use scratchway::interface::{Interface, Parser};
+
+include!(concat!(env!("OUT_DIR"), "/all.rs"));
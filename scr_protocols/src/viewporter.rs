@@ -1,7 +1,6 @@
-use scratchway::wayland::*;
 use scratchway::connection::{Reader, WaylandBuffer, Writer};
 use scratchway::events::*;
+use scratchway::interface;
 use scratchway::prelude::*;
-use scratchway::log;
 
-scr_scanner::generate!("./protocols/viewporter.xml");
+include!(concat!(env!("OUT_DIR"), "/viewporter.rs"));
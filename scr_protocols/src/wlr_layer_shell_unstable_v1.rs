@@ -1,9 +1,6 @@
-use scratchway::wayland::*;
 use scratchway::connection::{Reader, WaylandBuffer, Writer};
 use scratchway::events::*;
+use scratchway::interface;
 use scratchway::prelude::*;
-use scratchway::log;
 
-use crate::xdg_shell::*;
-
-scr_scanner::generate!("./protocols/wlr-layer-shell-unstable-v1.xml");
+include!(concat!(env!("OUT_DIR"), "/wlr_layer_shell_unstable_v1.rs"));
@@ -1,9 +1,7 @@
 #![allow(unused_mut, unused)]
-use scratchway::wayland::*;
 use scratchway::connection::{Reader, WaylandBuffer, Writer};
 use scratchway::events::*;
+use scratchway::interface;
 use scratchway::prelude::*;
-use scratchway::log;
-use super::xdg_shell::*;
 
-scr_scanner::generate!("./protocols/xdg-decoration-unstable-v1.xml");
+include!(concat!(env!("OUT_DIR"), "/xdg_decoration_unstable_v1.rs"));
@@ -1,9 +1,8 @@
 #![allow(unused_mut, unused)]
 
-use scratchway::wayland::*;
 use scratchway::connection::{Reader, WaylandBuffer, Writer};
 use scratchway::events::*;
+use scratchway::interface;
 use scratchway::prelude::*;
-use scratchway::log;
 
-scr_scanner::generate!("./protocols/xdg-shell.xml");
+include!(concat!(env!("OUT_DIR"), "/xdg_shell.rs"));
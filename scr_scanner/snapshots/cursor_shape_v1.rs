@@ -0,0 +1,725 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/cursor-shape-v1.xml, do not edit.
+
+pub mod wp_cursor_shape_manager_v1 {
+    use super::*;
+    pub struct WpCursorShapeManagerV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WpCursorShapeManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WpCursorShapeManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        GetPointer {
+            cursor_shape_device: super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1,
+            pointer: scratchway::wayland::wl_pointer::WlPointer,
+        },
+        GetTabletToolV2 {
+            cursor_shape_device: super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1,
+            tablet_tool: crate::tablet_v2::zwp_tablet_tool_v2::ZwpTabletToolV2,
+        },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::GetPointer { .. } => 1u16,
+                Self::GetTabletToolV2 { .. } => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::GetPointer { cursor_shape_device, pointer } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_u32(cursor_shape_device.id());
+                    msg.write_u32(pointer.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::GetTabletToolV2 { cursor_shape_device, tablet_tool } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 2u16);
+                    msg.write_u32(cursor_shape_device.id());
+                    msg.write_u32(tablet_tool.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let cursor_shape_device: super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let pointer = Object::from_id(parser.get_u32()?);
+                        Self::GetPointer {
+                            cursor_shape_device,
+                            pointer,
+                        }
+                    }
+                    2u16 => {
+                        let cursor_shape_device: super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let tablet_tool = Object::from_id(parser.get_u32()?);
+                        Self::GetTabletToolV2 {
+                            cursor_shape_device,
+                            tablet_tool,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wp_cursor_shape_manager_v1",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "get_pointer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "cursor_shape_device",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(
+                            &super::wp_cursor_shape_device_v1::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "pointer",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_pointer::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_tablet_tool_v2",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "cursor_shape_device",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(
+                            &super::wp_cursor_shape_device_v1::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "tablet_tool",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &crate::tablet_v2::zwp_tablet_tool_v2::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl WpCursorShapeManagerV1 {
+        pub const INTERFACE: &'static str = "wp_cursor_shape_manager_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_pointer(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            pointer: &scratchway::wayland::wl_pointer::WlPointer,
+        ) -> super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 {
+            let new_id = writer.new_id();
+            let new_wp_cursor_shape_device_v1: super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetPointer {
+                cursor_shape_device: Object::from_id(new_id),
+                pointer: Object::from_id(pointer.id()),
+            }
+                .encode(self.id, writer);
+            new_wp_cursor_shape_device_v1
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_tablet_tool_v2(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            tablet_tool: &crate::tablet_v2::zwp_tablet_tool_v2::ZwpTabletToolV2,
+        ) -> super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 {
+            let new_id = writer.new_id();
+            let new_wp_cursor_shape_device_v1: super::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetTabletToolV2 {
+                cursor_shape_device: Object::from_id(new_id),
+                tablet_tool: Object::from_id(tablet_tool.id()),
+            }
+                .encode(self.id, writer);
+            new_wp_cursor_shape_device_v1
+        }
+    }
+    /// Receives the events of `wp_cursor_shape_manager_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WpCursorShapeManagerV1Handler {}
+    impl<H: WpCursorShapeManagerV1Handler + ?Sized> Dispatch<H>
+    for WpCursorShapeManagerV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for WpCursorShapeManagerV1 {
+        const INTERFACE: &'static str = "wp_cursor_shape_manager_v1";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod wp_cursor_shape_device_v1 {
+    use super::*;
+    pub struct WpCursorShapeDeviceV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WpCursorShapeDeviceV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WpCursorShapeDeviceV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        SetShape { serial: u32, shape: WEnum<Shape> },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::SetShape { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetShape { serial, shape } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(shape.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let serial = parser.get_u32()?;
+                        let shape = parser.get_u32()?.into();
+                        Self::SetShape { serial, shape }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Shape {
+        Default = 1u32,
+        ContextMenu = 2u32,
+        Help = 3u32,
+        Pointer = 4u32,
+        Progress = 5u32,
+        Wait = 6u32,
+        Cell = 7u32,
+        Crosshair = 8u32,
+        Text = 9u32,
+        VerticalText = 10u32,
+        Alias = 11u32,
+        Copy = 12u32,
+        Move = 13u32,
+        NoDrop = 14u32,
+        NotAllowed = 15u32,
+        Grab = 16u32,
+        Grabbing = 17u32,
+        EResize = 18u32,
+        NResize = 19u32,
+        NeResize = 20u32,
+        NwResize = 21u32,
+        SResize = 22u32,
+        SeResize = 23u32,
+        SwResize = 24u32,
+        WResize = 25u32,
+        EwResize = 26u32,
+        NsResize = 27u32,
+        NeswResize = 28u32,
+        NwseResize = 29u32,
+        ColResize = 30u32,
+        RowResize = 31u32,
+        AllScroll = 32u32,
+        ZoomIn = 33u32,
+        ZoomOut = 34u32,
+        DndAsk = 35u32,
+        AllResize = 36u32,
+    }
+    impl PartialEq<Shape> for u32 {
+        fn eq(&self, other: &Shape) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Shape {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Shape {}
+    impl From<Shape> for u32 {
+        fn from(value: Shape) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Shape {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::Default),
+                2u32 => Ok(Self::ContextMenu),
+                3u32 => Ok(Self::Help),
+                4u32 => Ok(Self::Pointer),
+                5u32 => Ok(Self::Progress),
+                6u32 => Ok(Self::Wait),
+                7u32 => Ok(Self::Cell),
+                8u32 => Ok(Self::Crosshair),
+                9u32 => Ok(Self::Text),
+                10u32 => Ok(Self::VerticalText),
+                11u32 => Ok(Self::Alias),
+                12u32 => Ok(Self::Copy),
+                13u32 => Ok(Self::Move),
+                14u32 => Ok(Self::NoDrop),
+                15u32 => Ok(Self::NotAllowed),
+                16u32 => Ok(Self::Grab),
+                17u32 => Ok(Self::Grabbing),
+                18u32 => Ok(Self::EResize),
+                19u32 => Ok(Self::NResize),
+                20u32 => Ok(Self::NeResize),
+                21u32 => Ok(Self::NwResize),
+                22u32 => Ok(Self::SResize),
+                23u32 => Ok(Self::SeResize),
+                24u32 => Ok(Self::SwResize),
+                25u32 => Ok(Self::WResize),
+                26u32 => Ok(Self::EwResize),
+                27u32 => Ok(Self::NsResize),
+                28u32 => Ok(Self::NeswResize),
+                29u32 => Ok(Self::NwseResize),
+                30u32 => Ok(Self::ColResize),
+                31u32 => Ok(Self::RowResize),
+                32u32 => Ok(Self::AllScroll),
+                33u32 => Ok(Self::ZoomIn),
+                34u32 => Ok(Self::ZoomOut),
+                35u32 => Ok(Self::DndAsk),
+                36u32 => Ok(Self::AllResize),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidShape = 1u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::InvalidShape),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wp_cursor_shape_device_v1",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_shape",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "shape",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("shape"),
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "shape",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "default",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "context_menu",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "help",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pointer",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "progress",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "wait",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "cell",
+                        value: 7u32,
+                    },
+                    interface::EnumEntry {
+                        name: "crosshair",
+                        value: 8u32,
+                    },
+                    interface::EnumEntry {
+                        name: "text",
+                        value: 9u32,
+                    },
+                    interface::EnumEntry {
+                        name: "vertical_text",
+                        value: 10u32,
+                    },
+                    interface::EnumEntry {
+                        name: "alias",
+                        value: 11u32,
+                    },
+                    interface::EnumEntry {
+                        name: "copy",
+                        value: 12u32,
+                    },
+                    interface::EnumEntry {
+                        name: "move",
+                        value: 13u32,
+                    },
+                    interface::EnumEntry {
+                        name: "no_drop",
+                        value: 14u32,
+                    },
+                    interface::EnumEntry {
+                        name: "not_allowed",
+                        value: 15u32,
+                    },
+                    interface::EnumEntry {
+                        name: "grab",
+                        value: 16u32,
+                    },
+                    interface::EnumEntry {
+                        name: "grabbing",
+                        value: 17u32,
+                    },
+                    interface::EnumEntry {
+                        name: "e_resize",
+                        value: 18u32,
+                    },
+                    interface::EnumEntry {
+                        name: "n_resize",
+                        value: 19u32,
+                    },
+                    interface::EnumEntry {
+                        name: "ne_resize",
+                        value: 20u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nw_resize",
+                        value: 21u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s_resize",
+                        value: 22u32,
+                    },
+                    interface::EnumEntry {
+                        name: "se_resize",
+                        value: 23u32,
+                    },
+                    interface::EnumEntry {
+                        name: "sw_resize",
+                        value: 24u32,
+                    },
+                    interface::EnumEntry {
+                        name: "w_resize",
+                        value: 25u32,
+                    },
+                    interface::EnumEntry {
+                        name: "ew_resize",
+                        value: 26u32,
+                    },
+                    interface::EnumEntry {
+                        name: "ns_resize",
+                        value: 27u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nesw_resize",
+                        value: 28u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nwse_resize",
+                        value: 29u32,
+                    },
+                    interface::EnumEntry {
+                        name: "col_resize",
+                        value: 30u32,
+                    },
+                    interface::EnumEntry {
+                        name: "row_resize",
+                        value: 31u32,
+                    },
+                    interface::EnumEntry {
+                        name: "all_scroll",
+                        value: 32u32,
+                    },
+                    interface::EnumEntry {
+                        name: "zoom_in",
+                        value: 33u32,
+                    },
+                    interface::EnumEntry {
+                        name: "zoom_out",
+                        value: 34u32,
+                    },
+                    interface::EnumEntry {
+                        name: "dnd_ask",
+                        value: 35u32,
+                    },
+                    interface::EnumEntry {
+                        name: "all_resize",
+                        value: 36u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_shape",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WpCursorShapeDeviceV1 {
+        pub const INTERFACE: &'static str = "wp_cursor_shape_device_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_shape(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            shape: Shape,
+        ) {
+            Request::SetShape {
+                serial,
+                shape: WEnum::Value(shape),
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `wp_cursor_shape_device_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WpCursorShapeDeviceV1Handler {}
+    impl<H: WpCursorShapeDeviceV1Handler + ?Sized> Dispatch<H>
+    for WpCursorShapeDeviceV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for WpCursorShapeDeviceV1 {
+        const INTERFACE: &'static str = "wp_cursor_shape_device_v1";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &wp_cursor_shape_manager_v1::INTERFACE_DESC,
+    &wp_cursor_shape_device_v1::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &wp_cursor_shape_manager_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wp_cursor_shape_manager_v1::WpCursorShapeManagerV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wp_cursor_shape_device_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wp_cursor_shape_device_v1::WpCursorShapeDeviceV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
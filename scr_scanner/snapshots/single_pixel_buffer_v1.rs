@@ -0,0 +1,232 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/single-pixel-buffer-v1.xml, do not edit.
+
+pub mod wp_single_pixel_buffer_manager_v1 {
+    use super::*;
+    pub struct WpSinglePixelBufferManagerV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WpSinglePixelBufferManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WpSinglePixelBufferManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        CreateU32RgbaBuffer {
+            id: scratchway::wayland::wl_buffer::WlBuffer,
+            r: u32,
+            g: u32,
+            b: u32,
+            a: u32,
+        },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::CreateU32RgbaBuffer { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::CreateU32RgbaBuffer { id, r, g, b, a } => {
+                    let mut msg = Message::<28usize>::new(sender_id, 1u16);
+                    msg.write_u32(id.id());
+                    msg.write_u32(r);
+                    msg.write_u32(g);
+                    msg.write_u32(b);
+                    msg.write_u32(a);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let id: scratchway::wayland::wl_buffer::WlBuffer = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let r = parser.get_u32()?;
+                        let g = parser.get_u32()?;
+                        let b = parser.get_u32()?;
+                        let a = parser.get_u32()?;
+                        Self::CreateU32RgbaBuffer {
+                            id,
+                            r,
+                            g,
+                            b,
+                            a,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wp_single_pixel_buffer_manager_v1",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "create_u32_rgba_buffer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_buffer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "r",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "g",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "b",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "a",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl WpSinglePixelBufferManagerV1 {
+        pub const INTERFACE: &'static str = "wp_single_pixel_buffer_manager_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn create_u32_rgba_buffer(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            r: u32,
+            g: u32,
+            b: u32,
+            a: u32,
+        ) -> scratchway::wayland::wl_buffer::WlBuffer {
+            let new_id = writer.new_id();
+            let new_wl_buffer: scratchway::wayland::wl_buffer::WlBuffer = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::CreateU32RgbaBuffer {
+                id: Object::from_id(new_id),
+                r,
+                g,
+                b,
+                a,
+            }
+                .encode(self.id, writer);
+            new_wl_buffer
+        }
+    }
+    /// Receives the events of `wp_single_pixel_buffer_manager_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WpSinglePixelBufferManagerV1Handler {}
+    impl<H: WpSinglePixelBufferManagerV1Handler + ?Sized> Dispatch<H>
+    for WpSinglePixelBufferManagerV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for WpSinglePixelBufferManagerV1 {
+        const INTERFACE: &'static str = "wp_single_pixel_buffer_manager_v1";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &wp_single_pixel_buffer_manager_v1::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &wp_single_pixel_buffer_manager_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,3430 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/tablet-v2.xml, do not edit.
+
+pub mod zwp_tablet_manager_v2 {
+    use super::*;
+    pub struct ZwpTabletManagerV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletManagerV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletManagerV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        GetTabletSeat {
+            tablet_seat: super::zwp_tablet_seat_v2::ZwpTabletSeatV2,
+            seat: scratchway::wayland::wl_seat::WlSeat,
+        },
+        Destroy,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::GetTabletSeat { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::GetTabletSeat { tablet_seat, seat } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 0u16);
+                    msg.write_u32(tablet_seat.id());
+                    msg.write_u32(seat.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let tablet_seat: super::zwp_tablet_seat_v2::ZwpTabletSeatV2 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let seat = Object::from_id(parser.get_u32()?);
+                        Self::GetTabletSeat {
+                            tablet_seat,
+                            seat,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_manager_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "get_tablet_seat",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "tablet_seat",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_seat_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl ZwpTabletManagerV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_manager_v2";
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_tablet_seat(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            seat: &scratchway::wayland::wl_seat::WlSeat,
+        ) -> super::zwp_tablet_seat_v2::ZwpTabletSeatV2 {
+            let new_id = writer.new_id();
+            let new_zwp_tablet_seat_v2: super::zwp_tablet_seat_v2::ZwpTabletSeatV2 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetTabletSeat {
+                tablet_seat: Object::from_id(new_id),
+                seat: Object::from_id(seat.id()),
+            }
+                .encode(self.id, writer);
+            new_zwp_tablet_seat_v2
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_manager_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletManagerV2Handler {}
+    impl<H: ZwpTabletManagerV2Handler + ?Sized> Dispatch<H> for ZwpTabletManagerV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for ZwpTabletManagerV2 {
+        const INTERFACE: &'static str = "zwp_tablet_manager_v2";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod zwp_tablet_seat_v2 {
+    use super::*;
+    pub struct ZwpTabletSeatV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletSeatV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletSeatV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        TabletAdded { id: super::zwp_tablet_v2::ZwpTabletV2 },
+        ToolAdded { id: super::zwp_tablet_tool_v2::ZwpTabletToolV2 },
+        PadAdded { id: super::zwp_tablet_pad_v2::ZwpTabletPadV2 },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_seat_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "tablet_added",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "tool_added",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_tool_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "pad_added",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_pad_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    impl ZwpTabletSeatV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_seat_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_seat_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletSeatV2Handler {
+        fn tablet_added(
+            &mut self,
+            conn: &Connection,
+            tablet_seat: &ZwpTabletSeatV2,
+            id: super::zwp_tablet_v2::ZwpTabletV2,
+        ) {}
+        fn tool_added(
+            &mut self,
+            conn: &Connection,
+            tablet_seat: &ZwpTabletSeatV2,
+            id: super::zwp_tablet_tool_v2::ZwpTabletToolV2,
+        ) {}
+        fn pad_added(
+            &mut self,
+            conn: &Connection,
+            tablet_seat: &ZwpTabletSeatV2,
+            id: super::zwp_tablet_pad_v2::ZwpTabletPadV2,
+        ) {}
+    }
+    impl<H: ZwpTabletSeatV2Handler + ?Sized> Dispatch<H> for ZwpTabletSeatV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::TabletAdded { id } => handler.tablet_added(conn, self, id),
+                Event::ToolAdded { id } => handler.tool_added(conn, self, id),
+                Event::PadAdded { id } => handler.pad_added(conn, self, id),
+            }
+        }
+    }
+    impl Object for ZwpTabletSeatV2 {
+        const INTERFACE: &'static str = "zwp_tablet_seat_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::TabletAdded { id }
+                    }
+                    1u16 => {
+                        let id = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::ToolAdded { id }
+                    }
+                    2u16 => {
+                        let id = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::PadAdded { id }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_tool_v2 {
+    use super::*;
+    pub struct ZwpTabletToolV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletToolV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletToolV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        SetCursor {
+            serial: u32,
+            surface: Option<scratchway::wayland::wl_surface::WlSurface>,
+            hotspot_x: i32,
+            hotspot_y: i32,
+        },
+        Destroy,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::SetCursor { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::SetCursor { serial, surface, hotspot_x, hotspot_y } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 0u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(surface.map_or(0, |o| o.id()));
+                    msg.write_i32(hotspot_x);
+                    msg.write_i32(hotspot_y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Object::from_id);
+                        let hotspot_x = parser.get_i32()?;
+                        let hotspot_y = parser.get_i32()?;
+                        Self::SetCursor {
+                            serial,
+                            surface,
+                            hotspot_x,
+                            hotspot_y,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Type { tool_type: WEnum<Type> },
+        HardwareSerial { hardware_serial_hi: u32, hardware_serial_lo: u32 },
+        HardwareIdWacom { hardware_id_hi: u32, hardware_id_lo: u32 },
+        Capability { capability: WEnum<Capability> },
+        Done,
+        Removed,
+        ProximityIn {
+            serial: u32,
+            tablet: super::zwp_tablet_v2::ZwpTabletV2,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        },
+        ProximityOut,
+        Down { serial: u32 },
+        Up,
+        Motion { x: f32, y: f32 },
+        Pressure { pressure: u32 },
+        Distance { distance: u32 },
+        Tilt { tilt_x: f32, tilt_y: f32 },
+        Rotation { degrees: f32 },
+        Slider { position: i32 },
+        Wheel { degrees: f32, clicks: i32 },
+        Button { serial: u32, button: u32, state: WEnum<ButtonState> },
+        Frame { time: u32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Type {
+        Pen = 320u32,
+        Eraser = 321u32,
+        Brush = 322u32,
+        Pencil = 323u32,
+        Airbrush = 324u32,
+        Finger = 325u32,
+        Mouse = 326u32,
+        Lens = 327u32,
+    }
+    impl PartialEq<Type> for u32 {
+        fn eq(&self, other: &Type) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Type {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Type {}
+    impl From<Type> for u32 {
+        fn from(value: Type) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Type {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                320u32 => Ok(Self::Pen),
+                321u32 => Ok(Self::Eraser),
+                322u32 => Ok(Self::Brush),
+                323u32 => Ok(Self::Pencil),
+                324u32 => Ok(Self::Airbrush),
+                325u32 => Ok(Self::Finger),
+                326u32 => Ok(Self::Mouse),
+                327u32 => Ok(Self::Lens),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Capability {
+        Tilt = 1u32,
+        Pressure = 2u32,
+        Distance = 3u32,
+        Rotation = 4u32,
+        Slider = 5u32,
+        Wheel = 6u32,
+    }
+    impl PartialEq<Capability> for u32 {
+        fn eq(&self, other: &Capability) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Capability {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Capability {}
+    impl From<Capability> for u32 {
+        fn from(value: Capability) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Capability {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::Tilt),
+                2u32 => Ok(Self::Pressure),
+                3u32 => Ok(Self::Distance),
+                4u32 => Ok(Self::Rotation),
+                5u32 => Ok(Self::Slider),
+                6u32 => Ok(Self::Wheel),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum ButtonState {
+        Released = 0u32,
+        Pressed = 1u32,
+    }
+    impl PartialEq<ButtonState> for u32 {
+        fn eq(&self, other: &ButtonState) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for ButtonState {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for ButtonState {}
+    impl From<ButtonState> for u32 {
+        fn from(value: ButtonState) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for ButtonState {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Released),
+                1u32 => Ok(Self::Pressed),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        Role = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Role),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_tool_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_cursor",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "hotspot_x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "hotspot_y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "type",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "tool_type",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("type"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "hardware_serial",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "hardware_serial_hi",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "hardware_serial_lo",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "hardware_id_wacom",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "hardware_id_hi",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "hardware_id_lo",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "capability",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "capability",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("capability"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "done",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "removed",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "proximity_in",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "tablet",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "proximity_out",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "down",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "up",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "motion",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "pressure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "pressure",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "distance",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "distance",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "tilt",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "tilt_x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "tilt_y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "rotation",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "degrees",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "slider",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "position",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "wheel",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "degrees",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "clicks",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "button",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "button",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "state",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("button_state"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "type",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "pen",
+                        value: 320u32,
+                    },
+                    interface::EnumEntry {
+                        name: "eraser",
+                        value: 321u32,
+                    },
+                    interface::EnumEntry {
+                        name: "brush",
+                        value: 322u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pencil",
+                        value: 323u32,
+                    },
+                    interface::EnumEntry {
+                        name: "airbrush",
+                        value: 324u32,
+                    },
+                    interface::EnumEntry {
+                        name: "finger",
+                        value: 325u32,
+                    },
+                    interface::EnumEntry {
+                        name: "mouse",
+                        value: 326u32,
+                    },
+                    interface::EnumEntry {
+                        name: "lens",
+                        value: 327u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "capability",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "tilt",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pressure",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "distance",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rotation",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "slider",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "wheel",
+                        value: 6u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "button_state",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "released",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pressed",
+                        value: 1u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "role",
+                        value: 0u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwpTabletToolV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_tool_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_cursor(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            surface: Option<&scratchway::wayland::wl_surface::WlSurface>,
+            hotspot_x: i32,
+            hotspot_y: i32,
+        ) {
+            Request::SetCursor {
+                serial,
+                surface: surface.map(|o| Object::from_id(o.id())),
+                hotspot_x,
+                hotspot_y,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_tool_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletToolV2Handler {
+        fn r#type(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            tool_type: WEnum<Type>,
+        ) {}
+        fn hardware_serial(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            hardware_serial_hi: u32,
+            hardware_serial_lo: u32,
+        ) {}
+        fn hardware_id_wacom(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            hardware_id_hi: u32,
+            hardware_id_lo: u32,
+        ) {}
+        fn capability(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            capability: WEnum<Capability>,
+        ) {}
+        fn done(&mut self, conn: &Connection, tablet_tool: &ZwpTabletToolV2) {}
+        fn removed(&mut self, conn: &Connection, tablet_tool: &ZwpTabletToolV2) {}
+        fn proximity_in(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            serial: u32,
+            tablet: super::zwp_tablet_v2::ZwpTabletV2,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        ) {}
+        fn proximity_out(&mut self, conn: &Connection, tablet_tool: &ZwpTabletToolV2) {}
+        fn down(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            serial: u32,
+        ) {}
+        fn up(&mut self, conn: &Connection, tablet_tool: &ZwpTabletToolV2) {}
+        fn motion(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            x: f32,
+            y: f32,
+        ) {}
+        fn pressure(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            pressure: u32,
+        ) {}
+        fn distance(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            distance: u32,
+        ) {}
+        fn tilt(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            tilt_x: f32,
+            tilt_y: f32,
+        ) {}
+        fn rotation(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            degrees: f32,
+        ) {}
+        fn slider(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            position: i32,
+        ) {}
+        fn wheel(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            degrees: f32,
+            clicks: i32,
+        ) {}
+        fn button(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            serial: u32,
+            button: u32,
+            state: WEnum<ButtonState>,
+        ) {}
+        fn frame(
+            &mut self,
+            conn: &Connection,
+            tablet_tool: &ZwpTabletToolV2,
+            time: u32,
+        ) {}
+    }
+    impl<H: ZwpTabletToolV2Handler + ?Sized> Dispatch<H> for ZwpTabletToolV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Type { tool_type } => handler.r#type(conn, self, tool_type),
+                Event::HardwareSerial { hardware_serial_hi, hardware_serial_lo } => {
+                    handler
+                        .hardware_serial(
+                            conn,
+                            self,
+                            hardware_serial_hi,
+                            hardware_serial_lo,
+                        )
+                }
+                Event::HardwareIdWacom { hardware_id_hi, hardware_id_lo } => {
+                    handler.hardware_id_wacom(conn, self, hardware_id_hi, hardware_id_lo)
+                }
+                Event::Capability { capability } => {
+                    handler.capability(conn, self, capability)
+                }
+                Event::Done => handler.done(conn, self),
+                Event::Removed => handler.removed(conn, self),
+                Event::ProximityIn { serial, tablet, surface } => {
+                    handler.proximity_in(conn, self, serial, tablet, surface)
+                }
+                Event::ProximityOut => handler.proximity_out(conn, self),
+                Event::Down { serial } => handler.down(conn, self, serial),
+                Event::Up => handler.up(conn, self),
+                Event::Motion { x, y } => handler.motion(conn, self, x, y),
+                Event::Pressure { pressure } => handler.pressure(conn, self, pressure),
+                Event::Distance { distance } => handler.distance(conn, self, distance),
+                Event::Tilt { tilt_x, tilt_y } => {
+                    handler.tilt(conn, self, tilt_x, tilt_y)
+                }
+                Event::Rotation { degrees } => handler.rotation(conn, self, degrees),
+                Event::Slider { position } => handler.slider(conn, self, position),
+                Event::Wheel { degrees, clicks } => {
+                    handler.wheel(conn, self, degrees, clicks)
+                }
+                Event::Button { serial, button, state } => {
+                    handler.button(conn, self, serial, button, state)
+                }
+                Event::Frame { time } => handler.frame(conn, self, time),
+            }
+        }
+    }
+    impl Object for ZwpTabletToolV2 {
+        const INTERFACE: &'static str = "zwp_tablet_tool_v2";
+        const EVENT_FDS: &'static [u8] = &[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8,
+        ];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let tool_type = parser.get_u32()?.into();
+                        Event::Type { tool_type }
+                    }
+                    1u16 => {
+                        let hardware_serial_hi = parser.get_u32()?;
+                        let hardware_serial_lo = parser.get_u32()?;
+                        Event::HardwareSerial {
+                            hardware_serial_hi,
+                            hardware_serial_lo,
+                        }
+                    }
+                    2u16 => {
+                        let hardware_id_hi = parser.get_u32()?;
+                        let hardware_id_lo = parser.get_u32()?;
+                        Event::HardwareIdWacom {
+                            hardware_id_hi,
+                            hardware_id_lo,
+                        }
+                    }
+                    3u16 => {
+                        let capability = parser.get_u32()?.into();
+                        Event::Capability { capability }
+                    }
+                    4u16 => Event::Done,
+                    5u16 => Event::Removed,
+                    6u16 => {
+                        let serial = parser.get_u32()?;
+                        let tablet = Object::from_id(parser.get_u32()?);
+                        let surface = Object::from_id(parser.get_u32()?);
+                        Event::ProximityIn {
+                            serial,
+                            tablet,
+                            surface,
+                        }
+                    }
+                    7u16 => Event::ProximityOut,
+                    8u16 => {
+                        let serial = parser.get_u32()?;
+                        Event::Down { serial }
+                    }
+                    9u16 => Event::Up,
+                    10u16 => {
+                        let x = parser.get_fixed()?;
+                        let y = parser.get_fixed()?;
+                        Event::Motion { x, y }
+                    }
+                    11u16 => {
+                        let pressure = parser.get_u32()?;
+                        Event::Pressure { pressure }
+                    }
+                    12u16 => {
+                        let distance = parser.get_u32()?;
+                        Event::Distance { distance }
+                    }
+                    13u16 => {
+                        let tilt_x = parser.get_fixed()?;
+                        let tilt_y = parser.get_fixed()?;
+                        Event::Tilt { tilt_x, tilt_y }
+                    }
+                    14u16 => {
+                        let degrees = parser.get_fixed()?;
+                        Event::Rotation { degrees }
+                    }
+                    15u16 => {
+                        let position = parser.get_i32()?;
+                        Event::Slider { position }
+                    }
+                    16u16 => {
+                        let degrees = parser.get_fixed()?;
+                        let clicks = parser.get_i32()?;
+                        Event::Wheel { degrees, clicks }
+                    }
+                    17u16 => {
+                        let serial = parser.get_u32()?;
+                        let button = parser.get_u32()?;
+                        let state = parser.get_u32()?.into();
+                        Event::Button {
+                            serial,
+                            button,
+                            state,
+                        }
+                    }
+                    18u16 => {
+                        let time = parser.get_u32()?;
+                        Event::Frame { time }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_v2 {
+    use super::*;
+    pub struct ZwpTabletV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Name { name: &'a str },
+        Id { vid: u32, pid: u32 },
+        Path { path: &'a str },
+        Done,
+        Removed,
+        Bustype { bustype: WEnum<Bustype> },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Bustype {
+        Usb = 3u32,
+        Bluetooth = 5u32,
+        Virtual = 6u32,
+        Serial = 17u32,
+        I2c = 24u32,
+    }
+    impl PartialEq<Bustype> for u32 {
+        fn eq(&self, other: &Bustype) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Bustype {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Bustype {}
+    impl From<Bustype> for u32 {
+        fn from(value: Bustype) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Bustype {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                3u32 => Ok(Self::Usb),
+                5u32 => Ok(Self::Bluetooth),
+                6u32 => Ok(Self::Virtual),
+                17u32 => Ok(Self::Serial),
+                24u32 => Ok(Self::I2c),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "name",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "name",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "id",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "vid",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "pid",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "path",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "path",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "done",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "removed",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "bustype",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "bustype",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("bustype"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "bustype",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "usb",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bluetooth",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "virtual",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "serial",
+                        value: 17u32,
+                    },
+                    interface::EnumEntry {
+                        name: "i2c",
+                        value: 24u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwpTabletV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletV2Handler {
+        fn name(&mut self, conn: &Connection, tablet: &ZwpTabletV2, name: &str) {}
+        fn id(&mut self, conn: &Connection, tablet: &ZwpTabletV2, vid: u32, pid: u32) {}
+        fn path(&mut self, conn: &Connection, tablet: &ZwpTabletV2, path: &str) {}
+        fn done(&mut self, conn: &Connection, tablet: &ZwpTabletV2) {}
+        fn removed(&mut self, conn: &Connection, tablet: &ZwpTabletV2) {}
+        fn bustype(
+            &mut self,
+            conn: &Connection,
+            tablet: &ZwpTabletV2,
+            bustype: WEnum<Bustype>,
+        ) {}
+    }
+    impl<H: ZwpTabletV2Handler + ?Sized> Dispatch<H> for ZwpTabletV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Name { name } => handler.name(conn, self, name),
+                Event::Id { vid, pid } => handler.id(conn, self, vid, pid),
+                Event::Path { path } => handler.path(conn, self, path),
+                Event::Done => handler.done(conn, self),
+                Event::Removed => handler.removed(conn, self),
+                Event::Bustype { bustype } => handler.bustype(conn, self, bustype),
+            }
+        }
+    }
+    impl Object for ZwpTabletV2 {
+        const INTERFACE: &'static str = "zwp_tablet_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let name = parser.get_string()?;
+                        Event::Name { name }
+                    }
+                    1u16 => {
+                        let vid = parser.get_u32()?;
+                        let pid = parser.get_u32()?;
+                        Event::Id { vid, pid }
+                    }
+                    2u16 => {
+                        let path = parser.get_string()?;
+                        Event::Path { path }
+                    }
+                    3u16 => Event::Done,
+                    4u16 => Event::Removed,
+                    5u16 => {
+                        let bustype = parser.get_u32()?.into();
+                        Event::Bustype { bustype }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_pad_ring_v2 {
+    use super::*;
+    pub struct ZwpTabletPadRingV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletPadRingV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletPadRingV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        SetFeedback { description: &'a str, serial: u32 },
+        Destroy,
+    }
+    impl<'a> Request<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::SetFeedback { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::SetFeedback { description, serial } => {
+                    let mut msg = Message::<66usize>::new(sender_id, 0u16);
+                    msg.write_string(description);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let description = parser.get_string()?;
+                        let serial = parser.get_u32()?;
+                        Self::SetFeedback {
+                            description,
+                            serial,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Source { source: WEnum<Source> },
+        Angle { degrees: f32 },
+        Stop,
+        Frame { time: u32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Source {
+        Finger = 1u32,
+    }
+    impl PartialEq<Source> for u32 {
+        fn eq(&self, other: &Source) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Source {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Source {}
+    impl From<Source> for u32 {
+        fn from(value: Source) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Source {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::Finger),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_pad_ring_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_feedback",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "description",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "source",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "source",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("source"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "angle",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "degrees",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "stop",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "source",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "finger",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwpTabletPadRingV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_pad_ring_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_feedback(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            description: &str,
+            serial: u32,
+        ) {
+            Request::SetFeedback {
+                description,
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_pad_ring_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletPadRingV2Handler {
+        fn source(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_ring: &ZwpTabletPadRingV2,
+            source: WEnum<Source>,
+        ) {}
+        fn angle(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_ring: &ZwpTabletPadRingV2,
+            degrees: f32,
+        ) {}
+        fn stop(&mut self, conn: &Connection, tablet_pad_ring: &ZwpTabletPadRingV2) {}
+        fn frame(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_ring: &ZwpTabletPadRingV2,
+            time: u32,
+        ) {}
+    }
+    impl<H: ZwpTabletPadRingV2Handler + ?Sized> Dispatch<H> for ZwpTabletPadRingV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Source { source } => handler.source(conn, self, source),
+                Event::Angle { degrees } => handler.angle(conn, self, degrees),
+                Event::Stop => handler.stop(conn, self),
+                Event::Frame { time } => handler.frame(conn, self, time),
+            }
+        }
+    }
+    impl Object for ZwpTabletPadRingV2 {
+        const INTERFACE: &'static str = "zwp_tablet_pad_ring_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let source = parser.get_u32()?.into();
+                        Event::Source { source }
+                    }
+                    1u16 => {
+                        let degrees = parser.get_fixed()?;
+                        Event::Angle { degrees }
+                    }
+                    2u16 => Event::Stop,
+                    3u16 => {
+                        let time = parser.get_u32()?;
+                        Event::Frame { time }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_pad_strip_v2 {
+    use super::*;
+    pub struct ZwpTabletPadStripV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletPadStripV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletPadStripV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        SetFeedback { description: &'a str, serial: u32 },
+        Destroy,
+    }
+    impl<'a> Request<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::SetFeedback { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::SetFeedback { description, serial } => {
+                    let mut msg = Message::<66usize>::new(sender_id, 0u16);
+                    msg.write_string(description);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let description = parser.get_string()?;
+                        let serial = parser.get_u32()?;
+                        Self::SetFeedback {
+                            description,
+                            serial,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Source { source: WEnum<Source> },
+        Position { position: u32 },
+        Stop,
+        Frame { time: u32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Source {
+        Finger = 1u32,
+    }
+    impl PartialEq<Source> for u32 {
+        fn eq(&self, other: &Source) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Source {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Source {}
+    impl From<Source> for u32 {
+        fn from(value: Source) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Source {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::Finger),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_pad_strip_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_feedback",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "description",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "source",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "source",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("source"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "position",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "position",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "stop",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "source",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "finger",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwpTabletPadStripV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_pad_strip_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_feedback(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            description: &str,
+            serial: u32,
+        ) {
+            Request::SetFeedback {
+                description,
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_pad_strip_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletPadStripV2Handler {
+        fn source(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_strip: &ZwpTabletPadStripV2,
+            source: WEnum<Source>,
+        ) {}
+        fn position(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_strip: &ZwpTabletPadStripV2,
+            position: u32,
+        ) {}
+        fn stop(&mut self, conn: &Connection, tablet_pad_strip: &ZwpTabletPadStripV2) {}
+        fn frame(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_strip: &ZwpTabletPadStripV2,
+            time: u32,
+        ) {}
+    }
+    impl<H: ZwpTabletPadStripV2Handler + ?Sized> Dispatch<H> for ZwpTabletPadStripV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Source { source } => handler.source(conn, self, source),
+                Event::Position { position } => handler.position(conn, self, position),
+                Event::Stop => handler.stop(conn, self),
+                Event::Frame { time } => handler.frame(conn, self, time),
+            }
+        }
+    }
+    impl Object for ZwpTabletPadStripV2 {
+        const INTERFACE: &'static str = "zwp_tablet_pad_strip_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let source = parser.get_u32()?.into();
+                        Event::Source { source }
+                    }
+                    1u16 => {
+                        let position = parser.get_u32()?;
+                        Event::Position { position }
+                    }
+                    2u16 => Event::Stop,
+                    3u16 => {
+                        let time = parser.get_u32()?;
+                        Event::Frame { time }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_pad_group_v2 {
+    use super::*;
+    pub struct ZwpTabletPadGroupV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletPadGroupV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletPadGroupV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Buttons { buttons: &'a [u32] },
+        Ring { ring: super::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2 },
+        Strip { strip: super::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2 },
+        Modes { modes: u32 },
+        Done,
+        ModeSwitch { time: u32, serial: u32, mode: u32 },
+        Dial { dial: super::zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2 },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_pad_group_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "buttons",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "buttons",
+                        ty: interface::ArgType::Array,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "ring",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "ring",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_pad_ring_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "strip",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "strip",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_pad_strip_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "modes",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "modes",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "done",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "mode_switch",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "mode",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "dial",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "dial",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_pad_dial_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    impl ZwpTabletPadGroupV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_pad_group_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_pad_group_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletPadGroupV2Handler {
+        fn buttons(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_group: &ZwpTabletPadGroupV2,
+            buttons: &[u32],
+        ) {}
+        fn ring(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_group: &ZwpTabletPadGroupV2,
+            ring: super::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2,
+        ) {}
+        fn strip(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_group: &ZwpTabletPadGroupV2,
+            strip: super::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2,
+        ) {}
+        fn modes(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_group: &ZwpTabletPadGroupV2,
+            modes: u32,
+        ) {}
+        fn done(&mut self, conn: &Connection, tablet_pad_group: &ZwpTabletPadGroupV2) {}
+        fn mode_switch(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_group: &ZwpTabletPadGroupV2,
+            time: u32,
+            serial: u32,
+            mode: u32,
+        ) {}
+        fn dial(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_group: &ZwpTabletPadGroupV2,
+            dial: super::zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2,
+        ) {}
+    }
+    impl<H: ZwpTabletPadGroupV2Handler + ?Sized> Dispatch<H> for ZwpTabletPadGroupV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Buttons { buttons } => handler.buttons(conn, self, buttons),
+                Event::Ring { ring } => handler.ring(conn, self, ring),
+                Event::Strip { strip } => handler.strip(conn, self, strip),
+                Event::Modes { modes } => handler.modes(conn, self, modes),
+                Event::Done => handler.done(conn, self),
+                Event::ModeSwitch { time, serial, mode } => {
+                    handler.mode_switch(conn, self, time, serial, mode)
+                }
+                Event::Dial { dial } => handler.dial(conn, self, dial),
+            }
+        }
+    }
+    impl Object for ZwpTabletPadGroupV2 {
+        const INTERFACE: &'static str = "zwp_tablet_pad_group_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let buttons = parser.get_array()?;
+                        Event::Buttons { buttons }
+                    }
+                    1u16 => {
+                        let ring = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::Ring { ring }
+                    }
+                    2u16 => {
+                        let strip = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::Strip { strip }
+                    }
+                    3u16 => {
+                        let modes = parser.get_u32()?;
+                        Event::Modes { modes }
+                    }
+                    4u16 => Event::Done,
+                    5u16 => {
+                        let time = parser.get_u32()?;
+                        let serial = parser.get_u32()?;
+                        let mode = parser.get_u32()?;
+                        Event::ModeSwitch {
+                            time,
+                            serial,
+                            mode,
+                        }
+                    }
+                    6u16 => {
+                        let dial = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::Dial { dial }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_pad_v2 {
+    use super::*;
+    pub struct ZwpTabletPadV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletPadV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletPadV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        SetFeedback { button: u32, description: &'a str, serial: u32 },
+        Destroy,
+    }
+    impl<'a> Request<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::SetFeedback { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::SetFeedback { button, description, serial } => {
+                    let mut msg = Message::<70usize>::new(sender_id, 0u16);
+                    msg.write_u32(button);
+                    msg.write_string(description);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let button = parser.get_u32()?;
+                        let description = parser.get_string()?;
+                        let serial = parser.get_u32()?;
+                        Self::SetFeedback {
+                            button,
+                            description,
+                            serial,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Group { pad_group: super::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2 },
+        Path { path: &'a str },
+        Buttons { buttons: u32 },
+        Done,
+        Button { time: u32, button: u32, state: WEnum<ButtonState> },
+        Enter {
+            serial: u32,
+            tablet: super::zwp_tablet_v2::ZwpTabletV2,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        },
+        Leave { serial: u32, surface: scratchway::wayland::wl_surface::WlSurface },
+        Removed,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum ButtonState {
+        Released = 0u32,
+        Pressed = 1u32,
+    }
+    impl PartialEq<ButtonState> for u32 {
+        fn eq(&self, other: &ButtonState) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for ButtonState {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for ButtonState {}
+    impl From<ButtonState> for u32 {
+        fn from(value: ButtonState) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for ButtonState {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Released),
+                1u32 => Ok(Self::Pressed),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_pad_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_feedback",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "button",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "description",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "group",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "pad_group",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_pad_group_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "path",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "path",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "buttons",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "buttons",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "done",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "button",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "button",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "state",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("button_state"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "enter",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "tablet",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::zwp_tablet_v2::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "leave",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "removed",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "button_state",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "released",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pressed",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwpTabletPadV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_pad_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_feedback(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            button: u32,
+            description: &str,
+            serial: u32,
+        ) {
+            Request::SetFeedback {
+                button,
+                description,
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_pad_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletPadV2Handler {
+        fn group(
+            &mut self,
+            conn: &Connection,
+            tablet_pad: &ZwpTabletPadV2,
+            pad_group: super::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2,
+        ) {}
+        fn path(&mut self, conn: &Connection, tablet_pad: &ZwpTabletPadV2, path: &str) {}
+        fn buttons(
+            &mut self,
+            conn: &Connection,
+            tablet_pad: &ZwpTabletPadV2,
+            buttons: u32,
+        ) {}
+        fn done(&mut self, conn: &Connection, tablet_pad: &ZwpTabletPadV2) {}
+        fn button(
+            &mut self,
+            conn: &Connection,
+            tablet_pad: &ZwpTabletPadV2,
+            time: u32,
+            button: u32,
+            state: WEnum<ButtonState>,
+        ) {}
+        fn enter(
+            &mut self,
+            conn: &Connection,
+            tablet_pad: &ZwpTabletPadV2,
+            serial: u32,
+            tablet: super::zwp_tablet_v2::ZwpTabletV2,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        ) {}
+        fn leave(
+            &mut self,
+            conn: &Connection,
+            tablet_pad: &ZwpTabletPadV2,
+            serial: u32,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        ) {}
+        fn removed(&mut self, conn: &Connection, tablet_pad: &ZwpTabletPadV2) {}
+    }
+    impl<H: ZwpTabletPadV2Handler + ?Sized> Dispatch<H> for ZwpTabletPadV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Group { pad_group } => handler.group(conn, self, pad_group),
+                Event::Path { path } => handler.path(conn, self, path),
+                Event::Buttons { buttons } => handler.buttons(conn, self, buttons),
+                Event::Done => handler.done(conn, self),
+                Event::Button { time, button, state } => {
+                    handler.button(conn, self, time, button, state)
+                }
+                Event::Enter { serial, tablet, surface } => {
+                    handler.enter(conn, self, serial, tablet, surface)
+                }
+                Event::Leave { serial, surface } => {
+                    handler.leave(conn, self, serial, surface)
+                }
+                Event::Removed => handler.removed(conn, self),
+            }
+        }
+    }
+    impl Object for ZwpTabletPadV2 {
+        const INTERFACE: &'static str = "zwp_tablet_pad_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let pad_group = reader
+                            .new_object(
+                                parser.get_u32()?,
+                                reader.object_version(self.id),
+                            );
+                        Event::Group { pad_group }
+                    }
+                    1u16 => {
+                        let path = parser.get_string()?;
+                        Event::Path { path }
+                    }
+                    2u16 => {
+                        let buttons = parser.get_u32()?;
+                        Event::Buttons { buttons }
+                    }
+                    3u16 => Event::Done,
+                    4u16 => {
+                        let time = parser.get_u32()?;
+                        let button = parser.get_u32()?;
+                        let state = parser.get_u32()?.into();
+                        Event::Button {
+                            time,
+                            button,
+                            state,
+                        }
+                    }
+                    5u16 => {
+                        let serial = parser.get_u32()?;
+                        let tablet = Object::from_id(parser.get_u32()?);
+                        let surface = Object::from_id(parser.get_u32()?);
+                        Event::Enter {
+                            serial,
+                            tablet,
+                            surface,
+                        }
+                    }
+                    6u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Object::from_id(parser.get_u32()?);
+                        Event::Leave { serial, surface }
+                    }
+                    7u16 => Event::Removed,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod zwp_tablet_pad_dial_v2 {
+    use super::*;
+    pub struct ZwpTabletPadDialV2 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwpTabletPadDialV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwpTabletPadDialV2 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        SetFeedback { description: &'a str, serial: u32 },
+        Destroy,
+    }
+    impl<'a> Request<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::SetFeedback { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::SetFeedback { description, serial } => {
+                    let mut msg = Message::<66usize>::new(sender_id, 0u16);
+                    msg.write_string(description);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let description = parser.get_string()?;
+                        let serial = parser.get_u32()?;
+                        Self::SetFeedback {
+                            description,
+                            serial,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Delta { value120: i32 },
+        Frame { time: u32 },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwp_tablet_pad_dial_v2",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_feedback",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "description",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "delta",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "value120",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    impl ZwpTabletPadDialV2 {
+        pub const INTERFACE: &'static str = "zwp_tablet_pad_dial_v2";
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_feedback(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            description: &str,
+            serial: u32,
+        ) {
+            Request::SetFeedback {
+                description,
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwp_tablet_pad_dial_v2`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwpTabletPadDialV2Handler {
+        fn delta(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_dial: &ZwpTabletPadDialV2,
+            value120: i32,
+        ) {}
+        fn frame(
+            &mut self,
+            conn: &Connection,
+            tablet_pad_dial: &ZwpTabletPadDialV2,
+            time: u32,
+        ) {}
+    }
+    impl<H: ZwpTabletPadDialV2Handler + ?Sized> Dispatch<H> for ZwpTabletPadDialV2 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Delta { value120 } => handler.delta(conn, self, value120),
+                Event::Frame { time } => handler.frame(conn, self, time),
+            }
+        }
+    }
+    impl Object for ZwpTabletPadDialV2 {
+        const INTERFACE: &'static str = "zwp_tablet_pad_dial_v2";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let value120 = parser.get_i32()?;
+                        Event::Delta { value120 }
+                    }
+                    1u16 => {
+                        let time = parser.get_u32()?;
+                        Event::Frame { time }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &zwp_tablet_manager_v2::INTERFACE_DESC,
+    &zwp_tablet_seat_v2::INTERFACE_DESC,
+    &zwp_tablet_tool_v2::INTERFACE_DESC,
+    &zwp_tablet_v2::INTERFACE_DESC,
+    &zwp_tablet_pad_ring_v2::INTERFACE_DESC,
+    &zwp_tablet_pad_strip_v2::INTERFACE_DESC,
+    &zwp_tablet_pad_group_v2::INTERFACE_DESC,
+    &zwp_tablet_pad_v2::INTERFACE_DESC,
+    &zwp_tablet_pad_dial_v2::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &zwp_tablet_manager_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_manager_v2::ZwpTabletManagerV2 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_seat_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_seat_v2::ZwpTabletSeatV2 as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_tool_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_tool_v2::ZwpTabletToolV2 as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_v2::ZwpTabletV2 as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_pad_ring_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_pad_strip_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_pad_group_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_pad_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_pad_v2::ZwpTabletPadV2 as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwp_tablet_pad_dial_v2::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,535 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/viewporter.xml, do not edit.
+
+pub mod wp_viewporter {
+    use super::*;
+    pub struct WpViewporter {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WpViewporter {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WpViewporter {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        GetViewport {
+            id: super::wp_viewport::WpViewport,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::GetViewport { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::GetViewport { id, surface } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_u32(id.id());
+                    msg.write_u32(surface.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let id: super::wp_viewport::WpViewport = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let surface = Object::from_id(parser.get_u32()?);
+                        Self::GetViewport { id, surface }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        ViewportExists = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::ViewportExists),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wp_viewporter",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "get_viewport",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wp_viewport::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "viewport_exists",
+                        value: 0u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WpViewporter {
+        pub const INTERFACE: &'static str = "wp_viewporter";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_viewport(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            surface: &scratchway::wayland::wl_surface::WlSurface,
+        ) -> super::wp_viewport::WpViewport {
+            let new_id = writer.new_id();
+            let new_wp_viewport: super::wp_viewport::WpViewport = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetViewport {
+                id: Object::from_id(new_id),
+                surface: Object::from_id(surface.id()),
+            }
+                .encode(self.id, writer);
+            new_wp_viewport
+        }
+    }
+    /// Receives the events of `wp_viewporter`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WpViewporterHandler {}
+    impl<H: WpViewporterHandler + ?Sized> Dispatch<H> for WpViewporter {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for WpViewporter {
+        const INTERFACE: &'static str = "wp_viewporter";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod wp_viewport {
+    use super::*;
+    pub struct WpViewport {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WpViewport {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WpViewport {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        SetSource { x: f32, y: f32, width: f32, height: f32 },
+        SetDestination { width: i32, height: i32 },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::SetSource { .. } => 1u16,
+                Self::SetDestination { .. } => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetSource { x, y, width, height } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 1u16);
+                    msg.write_fixed(x);
+                    msg.write_fixed(y);
+                    msg.write_fixed(width);
+                    msg.write_fixed(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetDestination { width, height } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 2u16);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let x = parser.get_fixed()?;
+                        let y = parser.get_fixed()?;
+                        let width = parser.get_fixed()?;
+                        let height = parser.get_fixed()?;
+                        Self::SetSource {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    2u16 => {
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::SetDestination {
+                            width,
+                            height,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        BadValue = 0u32,
+        BadSize = 1u32,
+        OutOfBuffer = 2u32,
+        NoSurface = 3u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::BadValue),
+                1u32 => Ok(Self::BadSize),
+                2u32 => Ok(Self::OutOfBuffer),
+                3u32 => Ok(Self::NoSurface),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wp_viewport",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_source",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_destination",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "bad_value",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bad_size",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "out_of_buffer",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "no_surface",
+                        value: 3u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WpViewport {
+        pub const INTERFACE: &'static str = "wp_viewport";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_source(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            x: f32,
+            y: f32,
+            width: f32,
+            height: f32,
+        ) {
+            Request::SetSource {
+                x,
+                y,
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_destination(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            width: i32,
+            height: i32,
+        ) {
+            Request::SetDestination {
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `wp_viewport`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WpViewportHandler {}
+    impl<H: WpViewportHandler + ?Sized> Dispatch<H> for WpViewport {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for WpViewport {
+        const INTERFACE: &'static str = "wp_viewport";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &wp_viewporter::INTERFACE_DESC,
+    &wp_viewport::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &wp_viewporter::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wp_viewporter::WpViewporter as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wp_viewport::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wp_viewport::WpViewport as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,10113 @@
+// Generated by scr-scanner from ../protocols/wayland.xml, do not edit.
+
+pub mod wl_display {
+    use super::*;
+    pub struct WlDisplay {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlDisplay {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlDisplay {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Error { object_id: u32, code: u32, message: &'a str },
+        DeleteId { id: u32 },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Error { .. } => 0u16,
+                Self::DeleteId { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Error { object_id, code, message } => {
+                    let mut msg = Message::<70usize>::new(sender_id, 0u16);
+                    msg.write_u32(object_id);
+                    msg.write_u32(code);
+                    msg.write_string(message);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::DeleteId { id } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(id);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let object_id = parser.get_u32()?;
+                        let code = parser.get_u32()?;
+                        let message = parser.get_string()?;
+                        Self::Error {
+                            object_id,
+                            code,
+                            message,
+                        }
+                    }
+                    1u16 => {
+                        let id = parser.get_u32()?;
+                        Self::DeleteId { id }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Sync { callback: super::wl_callback::WlCallback },
+        GetRegistry { registry: super::wl_registry::WlRegistry },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidObject = 0u32,
+        InvalidMethod = 1u32,
+        NoMemory = 2u32,
+        Implementation = 3u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidObject),
+                1u32 => Ok(Self::InvalidMethod),
+                2u32 => Ok(Self::NoMemory),
+                3u32 => Ok(Self::Implementation),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_display",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "sync",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "callback",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_callback::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_registry",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "registry",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_registry::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "error",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "object_id",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "code",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "message",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "delete_id",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_object",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_method",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "no_memory",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "implementation",
+                        value: 3u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlDisplay {
+        pub const INTERFACE: &'static str = "wl_display";
+        #[allow(clippy::too_many_arguments)]
+        pub fn error(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            object_id: u32,
+            code: u32,
+            message: &str,
+        ) {
+            Event::Error {
+                object_id,
+                code,
+                message,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn delete_id(&self, writer: &WaylandBuffer<Writer>, id: u32) {
+            Event::DeleteId { id }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_display`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlDisplayHandler {
+        fn sync(
+            &mut self,
+            client: &Client,
+            display: &WlDisplay,
+            callback: super::wl_callback::WlCallback,
+        ) {}
+        fn get_registry(
+            &mut self,
+            client: &Client,
+            display: &WlDisplay,
+            registry: super::wl_registry::WlRegistry,
+        ) {}
+    }
+    impl<H: WlDisplayHandler + ?Sized> Dispatch<H> for WlDisplay {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Sync { callback } => handler.sync(client, self, callback),
+                Request::GetRegistry { registry } => {
+                    handler.get_registry(client, self, registry)
+                }
+            }
+        }
+    }
+    impl Resource for WlDisplay {
+        const INTERFACE: &'static str = "wl_display";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let callback = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::Sync { callback }
+                    }
+                    1u16 => {
+                        let registry = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::GetRegistry { registry }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_registry {
+    use super::*;
+    pub struct WlRegistry {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlRegistry {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlRegistry {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Global { name: u32, interface: &'a str, version: u32 },
+        GlobalRemove { name: u32 },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Global { .. } => 0u16,
+                Self::GlobalRemove { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Global { name, interface, version } => {
+                    let mut msg = Message::<70usize>::new(sender_id, 0u16);
+                    msg.write_u32(name);
+                    msg.write_string(interface);
+                    msg.write_u32(version);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::GlobalRemove { name } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(name);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let name = parser.get_u32()?;
+                        let interface = parser.get_string()?;
+                        let version = parser.get_u32()?;
+                        Self::Global {
+                            name,
+                            interface,
+                            version,
+                        }
+                    }
+                    1u16 => {
+                        let name = parser.get_u32()?;
+                        Self::GlobalRemove { name }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        Bind { name: u32, interface: &'a str, version: u32, id: u32 },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_registry",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "bind",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "name",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "global",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "name",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "interface",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "version",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "global_remove",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "name",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    impl WlRegistry {
+        pub const INTERFACE: &'static str = "wl_registry";
+        #[allow(clippy::too_many_arguments)]
+        pub fn global(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            name: u32,
+            interface: &str,
+            version: u32,
+        ) {
+            Event::Global {
+                name,
+                interface,
+                version,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn global_remove(&self, writer: &WaylandBuffer<Writer>, name: u32) {
+            Event::GlobalRemove { name }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_registry`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlRegistryHandler {
+        fn bind(
+            &mut self,
+            client: &Client,
+            registry: &WlRegistry,
+            name: u32,
+            interface: &str,
+            version: u32,
+            id: u32,
+        ) {}
+    }
+    impl<H: WlRegistryHandler + ?Sized> Dispatch<H> for WlRegistry {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Bind { name, interface, version, id } => {
+                    handler.bind(client, self, name, interface, version, id)
+                }
+            }
+        }
+    }
+    impl Resource for WlRegistry {
+        const INTERFACE: &'static str = "wl_registry";
+        const REQUEST_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let name = parser.get_u32()?;
+                        let interface = parser.get_string()?;
+                        let version = parser.get_u32()?;
+                        let id = parser.get_u32()?;
+                        Request::Bind {
+                            name,
+                            interface,
+                            version,
+                            id,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_callback {
+    use super::*;
+    pub struct WlCallback {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlCallback {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlCallback {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Done { callback_data: u32 },
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Done { .. } => 0u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Done { callback_data } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(callback_data);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let callback_data = parser.get_u32()?;
+                        Self::Done { callback_data }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {}
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_callback",
+        version: 1u32,
+        requests: &[],
+        events: &[
+            interface::MessageDesc {
+                name: "done",
+                since: 1u32,
+                destructor: true,
+                args: &[
+                    interface::ArgDesc {
+                        name: "callback_data",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    impl WlCallback {
+        pub const INTERFACE: &'static str = "wl_callback";
+        #[allow(clippy::too_many_arguments)]
+        pub fn done(self, writer: &WaylandBuffer<Writer>, callback_data: u32) {
+            Event::Done { callback_data }.encode(self.id, writer);
+            writer.destroy_resource(&self);
+        }
+    }
+    /// Receives the requests of `wl_callback`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlCallbackHandler {}
+    impl<H: WlCallbackHandler + ?Sized> Dispatch<H> for WlCallback {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            client.malformed(event);
+        }
+    }
+    impl Resource for WlCallback {
+        const INTERFACE: &'static str = "wl_callback";
+        const REQUEST_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            None
+        }
+    }
+}
+pub mod wl_compositor {
+    use super::*;
+    pub struct WlCompositor {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlCompositor {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlCompositor {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        CreateSurface { id: super::wl_surface::WlSurface },
+        CreateRegion { id: super::wl_region::WlRegion },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_compositor",
+        version: 6u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "create_surface",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "create_region",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_region::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl WlCompositor {
+        pub const INTERFACE: &'static str = "wl_compositor";
+    }
+    /// Receives the requests of `wl_compositor`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlCompositorHandler {
+        fn create_surface(
+            &mut self,
+            client: &Client,
+            compositor: &WlCompositor,
+            id: super::wl_surface::WlSurface,
+        ) {}
+        fn create_region(
+            &mut self,
+            client: &Client,
+            compositor: &WlCompositor,
+            id: super::wl_region::WlRegion,
+        ) {}
+    }
+    impl<H: WlCompositorHandler + ?Sized> Dispatch<H> for WlCompositor {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::CreateSurface { id } => handler.create_surface(client, self, id),
+                Request::CreateRegion { id } => handler.create_region(client, self, id),
+            }
+        }
+    }
+    impl Resource for WlCompositor {
+        const INTERFACE: &'static str = "wl_compositor";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::CreateSurface { id }
+                    }
+                    1u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::CreateRegion { id }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_shm_pool {
+    use super::*;
+    pub struct WlShmPool {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlShmPool {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlShmPool {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        CreateBuffer {
+            id: super::wl_buffer::WlBuffer,
+            offset: i32,
+            width: i32,
+            height: i32,
+            stride: i32,
+            format: WEnum<super::wl_shm::Format>,
+        },
+        Destroy,
+        Resize { size: i32 },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_shm_pool",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "create_buffer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_buffer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "offset",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "stride",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "format",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_shm.format"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "resize",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "size",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl WlShmPool {
+        pub const INTERFACE: &'static str = "wl_shm_pool";
+    }
+    /// Receives the requests of `wl_shm_pool`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlShmPoolHandler {
+        fn create_buffer(
+            &mut self,
+            client: &Client,
+            shm_pool: &WlShmPool,
+            id: super::wl_buffer::WlBuffer,
+            offset: i32,
+            width: i32,
+            height: i32,
+            stride: i32,
+            format: WEnum<super::wl_shm::Format>,
+        ) {}
+        fn destroy(&mut self, client: &Client, shm_pool: &WlShmPool) {}
+        fn resize(&mut self, client: &Client, shm_pool: &WlShmPool, size: i32) {}
+    }
+    impl<H: WlShmPoolHandler + ?Sized> Dispatch<H> for WlShmPool {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::CreateBuffer { id, offset, width, height, stride, format } => {
+                    handler
+                        .create_buffer(
+                            client,
+                            self,
+                            id,
+                            offset,
+                            width,
+                            height,
+                            stride,
+                            format,
+                        )
+                }
+                Request::Destroy => handler.destroy(client, self),
+                Request::Resize { size } => handler.resize(client, self, size),
+            }
+        }
+    }
+    impl Resource for WlShmPool {
+        const INTERFACE: &'static str = "wl_shm_pool";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        let offset = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        let stride = parser.get_i32()?;
+                        let format = parser.get_u32()?.into();
+                        Request::CreateBuffer {
+                            id,
+                            offset,
+                            width,
+                            height,
+                            stride,
+                            format,
+                        }
+                    }
+                    1u16 => Request::Destroy,
+                    2u16 => {
+                        let size = parser.get_i32()?;
+                        Request::Resize { size }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_shm {
+    use super::*;
+    pub struct WlShm {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlShm {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlShm {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Format { format: WEnum<Format> },
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Format { .. } => 0u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Format { format } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(format.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let format = parser.get_u32()?.into();
+                        Self::Format { format }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        CreatePool {
+            id: super::wl_shm_pool::WlShmPool,
+            fd: std::os::fd::OwnedFd,
+            size: i32,
+        },
+        Release,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidFormat = 0u32,
+        InvalidStride = 1u32,
+        InvalidFd = 2u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidFormat),
+                1u32 => Ok(Self::InvalidStride),
+                2u32 => Ok(Self::InvalidFd),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Format {
+        Argb8888 = 0u32,
+        Xrgb8888 = 1u32,
+        C8 = 538982467u32,
+        Rgb332 = 943867730u32,
+        Bgr233 = 944916290u32,
+        Xrgb4444 = 842093144u32,
+        Xbgr4444 = 842089048u32,
+        Rgbx4444 = 842094674u32,
+        Bgrx4444 = 842094658u32,
+        Argb4444 = 842093121u32,
+        Abgr4444 = 842089025u32,
+        Rgba4444 = 842088786u32,
+        Bgra4444 = 842088770u32,
+        Xrgb1555 = 892424792u32,
+        Xbgr1555 = 892420696u32,
+        Rgbx5551 = 892426322u32,
+        Bgrx5551 = 892426306u32,
+        Argb1555 = 892424769u32,
+        Abgr1555 = 892420673u32,
+        Rgba5551 = 892420434u32,
+        Bgra5551 = 892420418u32,
+        Rgb565 = 909199186u32,
+        Bgr565 = 909199170u32,
+        Rgb888 = 875710290u32,
+        Bgr888 = 875710274u32,
+        Xbgr8888 = 875709016u32,
+        Rgbx8888 = 875714642u32,
+        Bgrx8888 = 875714626u32,
+        Abgr8888 = 875708993u32,
+        Rgba8888 = 875708754u32,
+        Bgra8888 = 875708738u32,
+        Xrgb2101010 = 808669784u32,
+        Xbgr2101010 = 808665688u32,
+        Rgbx1010102 = 808671314u32,
+        Bgrx1010102 = 808671298u32,
+        Argb2101010 = 808669761u32,
+        Abgr2101010 = 808665665u32,
+        Rgba1010102 = 808665426u32,
+        Bgra1010102 = 808665410u32,
+        Yuyv = 1448695129u32,
+        Yvyu = 1431918169u32,
+        Uyvy = 1498831189u32,
+        Vyuy = 1498765654u32,
+        Ayuv = 1448433985u32,
+        Nv12 = 842094158u32,
+        Nv21 = 825382478u32,
+        Nv16 = 909203022u32,
+        Nv61 = 825644622u32,
+        Yuv410 = 961959257u32,
+        Yvu410 = 961893977u32,
+        Yuv411 = 825316697u32,
+        Yvu411 = 825316953u32,
+        Yuv420 = 842093913u32,
+        Yvu420 = 842094169u32,
+        Yuv422 = 909202777u32,
+        Yvu422 = 909203033u32,
+        Yuv444 = 875713881u32,
+        Yvu444 = 875714137u32,
+        R8 = 538982482u32,
+        R16 = 540422482u32,
+        Rg88 = 943212370u32,
+        Gr88 = 943215175u32,
+        Rg1616 = 842221394u32,
+        Gr1616 = 842224199u32,
+        Xrgb16161616f = 1211388504u32,
+        Xbgr16161616f = 1211384408u32,
+        Argb16161616f = 1211388481u32,
+        Abgr16161616f = 1211384385u32,
+        Xyuv8888 = 1448434008u32,
+        Vuy888 = 875713878u32,
+        Vuy101010 = 808670550u32,
+        Y210 = 808530521u32,
+        Y212 = 842084953u32,
+        Y216 = 909193817u32,
+        Y410 = 808531033u32,
+        Y412 = 842085465u32,
+        Y416 = 909194329u32,
+        Xvyu2101010 = 808670808u32,
+        Xvyu1216161616 = 909334104u32,
+        Xvyu16161616 = 942954072u32,
+        Y0l0 = 810299481u32,
+        X0l0 = 810299480u32,
+        Y0l2 = 843853913u32,
+        X0l2 = 843853912u32,
+        Yuv4208bit = 942691673u32,
+        Yuv42010bit = 808539481u32,
+        Xrgb8888A8 = 943805016u32,
+        Xbgr8888A8 = 943800920u32,
+        Rgbx8888A8 = 943806546u32,
+        Bgrx8888A8 = 943806530u32,
+        Rgb888A8 = 943798354u32,
+        Bgr888A8 = 943798338u32,
+        Rgb565A8 = 943797586u32,
+        Bgr565A8 = 943797570u32,
+        Nv24 = 875714126u32,
+        Nv42 = 842290766u32,
+        P210 = 808530512u32,
+        P010 = 808530000u32,
+        P012 = 842084432u32,
+        P016 = 909193296u32,
+        Axbxgxrx106106106106 = 808534593u32,
+        Nv15 = 892425806u32,
+        Q410 = 808531025u32,
+        Q401 = 825242705u32,
+        Xrgb16161616 = 942953048u32,
+        Xbgr16161616 = 942948952u32,
+        Argb16161616 = 942953025u32,
+        Abgr16161616 = 942948929u32,
+        C1 = 538980675u32,
+        C2 = 538980931u32,
+        C4 = 538981443u32,
+        D1 = 538980676u32,
+        D2 = 538980932u32,
+        D4 = 538981444u32,
+        D8 = 538982468u32,
+        R1 = 538980690u32,
+        R2 = 538980946u32,
+        R4 = 538981458u32,
+        R10 = 540029266u32,
+        R12 = 540160338u32,
+        Avuy8888 = 1498764865u32,
+        Xvuy8888 = 1498764888u32,
+        P030 = 808661072u32,
+        Rgb161616 = 942950226u32,
+        Bgr161616 = 942950210u32,
+        R16f = 1210064978u32,
+        Gr1616f = 1210077767u32,
+        Bgr161616f = 1213351746u32,
+        R32f = 1176510546u32,
+        Gr3232f = 1176523335u32,
+        Bgr323232f = 1179797314u32,
+        Abgr32323232f = 1178092097u32,
+        Nv20 = 808605262u32,
+        Nv30 = 808670798u32,
+        S010 = 808530003u32,
+        S210 = 808530515u32,
+        S410 = 808531027u32,
+        S012 = 842084435u32,
+        S212 = 842084947u32,
+        S412 = 842085459u32,
+        S016 = 909193299u32,
+        S216 = 909193811u32,
+        S416 = 909194323u32,
+    }
+    impl PartialEq<Format> for u32 {
+        fn eq(&self, other: &Format) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Format {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Format {}
+    impl From<Format> for u32 {
+        fn from(value: Format) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Format {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Argb8888),
+                1u32 => Ok(Self::Xrgb8888),
+                538982467u32 => Ok(Self::C8),
+                943867730u32 => Ok(Self::Rgb332),
+                944916290u32 => Ok(Self::Bgr233),
+                842093144u32 => Ok(Self::Xrgb4444),
+                842089048u32 => Ok(Self::Xbgr4444),
+                842094674u32 => Ok(Self::Rgbx4444),
+                842094658u32 => Ok(Self::Bgrx4444),
+                842093121u32 => Ok(Self::Argb4444),
+                842089025u32 => Ok(Self::Abgr4444),
+                842088786u32 => Ok(Self::Rgba4444),
+                842088770u32 => Ok(Self::Bgra4444),
+                892424792u32 => Ok(Self::Xrgb1555),
+                892420696u32 => Ok(Self::Xbgr1555),
+                892426322u32 => Ok(Self::Rgbx5551),
+                892426306u32 => Ok(Self::Bgrx5551),
+                892424769u32 => Ok(Self::Argb1555),
+                892420673u32 => Ok(Self::Abgr1555),
+                892420434u32 => Ok(Self::Rgba5551),
+                892420418u32 => Ok(Self::Bgra5551),
+                909199186u32 => Ok(Self::Rgb565),
+                909199170u32 => Ok(Self::Bgr565),
+                875710290u32 => Ok(Self::Rgb888),
+                875710274u32 => Ok(Self::Bgr888),
+                875709016u32 => Ok(Self::Xbgr8888),
+                875714642u32 => Ok(Self::Rgbx8888),
+                875714626u32 => Ok(Self::Bgrx8888),
+                875708993u32 => Ok(Self::Abgr8888),
+                875708754u32 => Ok(Self::Rgba8888),
+                875708738u32 => Ok(Self::Bgra8888),
+                808669784u32 => Ok(Self::Xrgb2101010),
+                808665688u32 => Ok(Self::Xbgr2101010),
+                808671314u32 => Ok(Self::Rgbx1010102),
+                808671298u32 => Ok(Self::Bgrx1010102),
+                808669761u32 => Ok(Self::Argb2101010),
+                808665665u32 => Ok(Self::Abgr2101010),
+                808665426u32 => Ok(Self::Rgba1010102),
+                808665410u32 => Ok(Self::Bgra1010102),
+                1448695129u32 => Ok(Self::Yuyv),
+                1431918169u32 => Ok(Self::Yvyu),
+                1498831189u32 => Ok(Self::Uyvy),
+                1498765654u32 => Ok(Self::Vyuy),
+                1448433985u32 => Ok(Self::Ayuv),
+                842094158u32 => Ok(Self::Nv12),
+                825382478u32 => Ok(Self::Nv21),
+                909203022u32 => Ok(Self::Nv16),
+                825644622u32 => Ok(Self::Nv61),
+                961959257u32 => Ok(Self::Yuv410),
+                961893977u32 => Ok(Self::Yvu410),
+                825316697u32 => Ok(Self::Yuv411),
+                825316953u32 => Ok(Self::Yvu411),
+                842093913u32 => Ok(Self::Yuv420),
+                842094169u32 => Ok(Self::Yvu420),
+                909202777u32 => Ok(Self::Yuv422),
+                909203033u32 => Ok(Self::Yvu422),
+                875713881u32 => Ok(Self::Yuv444),
+                875714137u32 => Ok(Self::Yvu444),
+                538982482u32 => Ok(Self::R8),
+                540422482u32 => Ok(Self::R16),
+                943212370u32 => Ok(Self::Rg88),
+                943215175u32 => Ok(Self::Gr88),
+                842221394u32 => Ok(Self::Rg1616),
+                842224199u32 => Ok(Self::Gr1616),
+                1211388504u32 => Ok(Self::Xrgb16161616f),
+                1211384408u32 => Ok(Self::Xbgr16161616f),
+                1211388481u32 => Ok(Self::Argb16161616f),
+                1211384385u32 => Ok(Self::Abgr16161616f),
+                1448434008u32 => Ok(Self::Xyuv8888),
+                875713878u32 => Ok(Self::Vuy888),
+                808670550u32 => Ok(Self::Vuy101010),
+                808530521u32 => Ok(Self::Y210),
+                842084953u32 => Ok(Self::Y212),
+                909193817u32 => Ok(Self::Y216),
+                808531033u32 => Ok(Self::Y410),
+                842085465u32 => Ok(Self::Y412),
+                909194329u32 => Ok(Self::Y416),
+                808670808u32 => Ok(Self::Xvyu2101010),
+                909334104u32 => Ok(Self::Xvyu1216161616),
+                942954072u32 => Ok(Self::Xvyu16161616),
+                810299481u32 => Ok(Self::Y0l0),
+                810299480u32 => Ok(Self::X0l0),
+                843853913u32 => Ok(Self::Y0l2),
+                843853912u32 => Ok(Self::X0l2),
+                942691673u32 => Ok(Self::Yuv4208bit),
+                808539481u32 => Ok(Self::Yuv42010bit),
+                943805016u32 => Ok(Self::Xrgb8888A8),
+                943800920u32 => Ok(Self::Xbgr8888A8),
+                943806546u32 => Ok(Self::Rgbx8888A8),
+                943806530u32 => Ok(Self::Bgrx8888A8),
+                943798354u32 => Ok(Self::Rgb888A8),
+                943798338u32 => Ok(Self::Bgr888A8),
+                943797586u32 => Ok(Self::Rgb565A8),
+                943797570u32 => Ok(Self::Bgr565A8),
+                875714126u32 => Ok(Self::Nv24),
+                842290766u32 => Ok(Self::Nv42),
+                808530512u32 => Ok(Self::P210),
+                808530000u32 => Ok(Self::P010),
+                842084432u32 => Ok(Self::P012),
+                909193296u32 => Ok(Self::P016),
+                808534593u32 => Ok(Self::Axbxgxrx106106106106),
+                892425806u32 => Ok(Self::Nv15),
+                808531025u32 => Ok(Self::Q410),
+                825242705u32 => Ok(Self::Q401),
+                942953048u32 => Ok(Self::Xrgb16161616),
+                942948952u32 => Ok(Self::Xbgr16161616),
+                942953025u32 => Ok(Self::Argb16161616),
+                942948929u32 => Ok(Self::Abgr16161616),
+                538980675u32 => Ok(Self::C1),
+                538980931u32 => Ok(Self::C2),
+                538981443u32 => Ok(Self::C4),
+                538980676u32 => Ok(Self::D1),
+                538980932u32 => Ok(Self::D2),
+                538981444u32 => Ok(Self::D4),
+                538982468u32 => Ok(Self::D8),
+                538980690u32 => Ok(Self::R1),
+                538980946u32 => Ok(Self::R2),
+                538981458u32 => Ok(Self::R4),
+                540029266u32 => Ok(Self::R10),
+                540160338u32 => Ok(Self::R12),
+                1498764865u32 => Ok(Self::Avuy8888),
+                1498764888u32 => Ok(Self::Xvuy8888),
+                808661072u32 => Ok(Self::P030),
+                942950226u32 => Ok(Self::Rgb161616),
+                942950210u32 => Ok(Self::Bgr161616),
+                1210064978u32 => Ok(Self::R16f),
+                1210077767u32 => Ok(Self::Gr1616f),
+                1213351746u32 => Ok(Self::Bgr161616f),
+                1176510546u32 => Ok(Self::R32f),
+                1176523335u32 => Ok(Self::Gr3232f),
+                1179797314u32 => Ok(Self::Bgr323232f),
+                1178092097u32 => Ok(Self::Abgr32323232f),
+                808605262u32 => Ok(Self::Nv20),
+                808670798u32 => Ok(Self::Nv30),
+                808530003u32 => Ok(Self::S010),
+                808530515u32 => Ok(Self::S210),
+                808531027u32 => Ok(Self::S410),
+                842084435u32 => Ok(Self::S012),
+                842084947u32 => Ok(Self::S212),
+                842085459u32 => Ok(Self::S412),
+                909193299u32 => Ok(Self::S016),
+                909193811u32 => Ok(Self::S216),
+                909194323u32 => Ok(Self::S416),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_shm",
+        version: 2u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "create_pool",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_shm_pool::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "fd",
+                        ty: interface::ArgType::Fd,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "size",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "release",
+                since: 2u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "format",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "format",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("format"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_format",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_stride",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_fd",
+                        value: 2u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "format",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "argb8888",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb8888",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "c8",
+                        value: 538982467u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgb332",
+                        value: 943867730u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr233",
+                        value: 944916290u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb4444",
+                        value: 842093144u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr4444",
+                        value: 842089048u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgbx4444",
+                        value: 842094674u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgrx4444",
+                        value: 842094658u32,
+                    },
+                    interface::EnumEntry {
+                        name: "argb4444",
+                        value: 842093121u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr4444",
+                        value: 842089025u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgba4444",
+                        value: 842088786u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgra4444",
+                        value: 842088770u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb1555",
+                        value: 892424792u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr1555",
+                        value: 892420696u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgbx5551",
+                        value: 892426322u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgrx5551",
+                        value: 892426306u32,
+                    },
+                    interface::EnumEntry {
+                        name: "argb1555",
+                        value: 892424769u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr1555",
+                        value: 892420673u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgba5551",
+                        value: 892420434u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgra5551",
+                        value: 892420418u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgb565",
+                        value: 909199186u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr565",
+                        value: 909199170u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgb888",
+                        value: 875710290u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr888",
+                        value: 875710274u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr8888",
+                        value: 875709016u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgbx8888",
+                        value: 875714642u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgrx8888",
+                        value: 875714626u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr8888",
+                        value: 875708993u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgba8888",
+                        value: 875708754u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgra8888",
+                        value: 875708738u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb2101010",
+                        value: 808669784u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr2101010",
+                        value: 808665688u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgbx1010102",
+                        value: 808671314u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgrx1010102",
+                        value: 808671298u32,
+                    },
+                    interface::EnumEntry {
+                        name: "argb2101010",
+                        value: 808669761u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr2101010",
+                        value: 808665665u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgba1010102",
+                        value: 808665426u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgra1010102",
+                        value: 808665410u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuyv",
+                        value: 1448695129u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yvyu",
+                        value: 1431918169u32,
+                    },
+                    interface::EnumEntry {
+                        name: "uyvy",
+                        value: 1498831189u32,
+                    },
+                    interface::EnumEntry {
+                        name: "vyuy",
+                        value: 1498765654u32,
+                    },
+                    interface::EnumEntry {
+                        name: "ayuv",
+                        value: 1448433985u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv12",
+                        value: 842094158u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv21",
+                        value: 825382478u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv16",
+                        value: 909203022u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv61",
+                        value: 825644622u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv410",
+                        value: 961959257u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yvu410",
+                        value: 961893977u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv411",
+                        value: 825316697u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yvu411",
+                        value: 825316953u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv420",
+                        value: 842093913u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yvu420",
+                        value: 842094169u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv422",
+                        value: 909202777u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yvu422",
+                        value: 909203033u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv444",
+                        value: 875713881u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yvu444",
+                        value: 875714137u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r8",
+                        value: 538982482u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r16",
+                        value: 540422482u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rg88",
+                        value: 943212370u32,
+                    },
+                    interface::EnumEntry {
+                        name: "gr88",
+                        value: 943215175u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rg1616",
+                        value: 842221394u32,
+                    },
+                    interface::EnumEntry {
+                        name: "gr1616",
+                        value: 842224199u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb16161616f",
+                        value: 1211388504u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr16161616f",
+                        value: 1211384408u32,
+                    },
+                    interface::EnumEntry {
+                        name: "argb16161616f",
+                        value: 1211388481u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr16161616f",
+                        value: 1211384385u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xyuv8888",
+                        value: 1448434008u32,
+                    },
+                    interface::EnumEntry {
+                        name: "vuy888",
+                        value: 875713878u32,
+                    },
+                    interface::EnumEntry {
+                        name: "vuy101010",
+                        value: 808670550u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y210",
+                        value: 808530521u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y212",
+                        value: 842084953u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y216",
+                        value: 909193817u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y410",
+                        value: 808531033u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y412",
+                        value: 842085465u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y416",
+                        value: 909194329u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xvyu2101010",
+                        value: 808670808u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xvyu12_16161616",
+                        value: 909334104u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xvyu16161616",
+                        value: 942954072u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y0l0",
+                        value: 810299481u32,
+                    },
+                    interface::EnumEntry {
+                        name: "x0l0",
+                        value: 810299480u32,
+                    },
+                    interface::EnumEntry {
+                        name: "y0l2",
+                        value: 843853913u32,
+                    },
+                    interface::EnumEntry {
+                        name: "x0l2",
+                        value: 843853912u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv420_8bit",
+                        value: 942691673u32,
+                    },
+                    interface::EnumEntry {
+                        name: "yuv420_10bit",
+                        value: 808539481u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb8888_a8",
+                        value: 943805016u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr8888_a8",
+                        value: 943800920u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgbx8888_a8",
+                        value: 943806546u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgrx8888_a8",
+                        value: 943806530u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgb888_a8",
+                        value: 943798354u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr888_a8",
+                        value: 943798338u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgb565_a8",
+                        value: 943797586u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr565_a8",
+                        value: 943797570u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv24",
+                        value: 875714126u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv42",
+                        value: 842290766u32,
+                    },
+                    interface::EnumEntry {
+                        name: "p210",
+                        value: 808530512u32,
+                    },
+                    interface::EnumEntry {
+                        name: "p010",
+                        value: 808530000u32,
+                    },
+                    interface::EnumEntry {
+                        name: "p012",
+                        value: 842084432u32,
+                    },
+                    interface::EnumEntry {
+                        name: "p016",
+                        value: 909193296u32,
+                    },
+                    interface::EnumEntry {
+                        name: "axbxgxrx106106106106",
+                        value: 808534593u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv15",
+                        value: 892425806u32,
+                    },
+                    interface::EnumEntry {
+                        name: "q410",
+                        value: 808531025u32,
+                    },
+                    interface::EnumEntry {
+                        name: "q401",
+                        value: 825242705u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xrgb16161616",
+                        value: 942953048u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xbgr16161616",
+                        value: 942948952u32,
+                    },
+                    interface::EnumEntry {
+                        name: "argb16161616",
+                        value: 942953025u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr16161616",
+                        value: 942948929u32,
+                    },
+                    interface::EnumEntry {
+                        name: "c1",
+                        value: 538980675u32,
+                    },
+                    interface::EnumEntry {
+                        name: "c2",
+                        value: 538980931u32,
+                    },
+                    interface::EnumEntry {
+                        name: "c4",
+                        value: 538981443u32,
+                    },
+                    interface::EnumEntry {
+                        name: "d1",
+                        value: 538980676u32,
+                    },
+                    interface::EnumEntry {
+                        name: "d2",
+                        value: 538980932u32,
+                    },
+                    interface::EnumEntry {
+                        name: "d4",
+                        value: 538981444u32,
+                    },
+                    interface::EnumEntry {
+                        name: "d8",
+                        value: 538982468u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r1",
+                        value: 538980690u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r2",
+                        value: 538980946u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r4",
+                        value: 538981458u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r10",
+                        value: 540029266u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r12",
+                        value: 540160338u32,
+                    },
+                    interface::EnumEntry {
+                        name: "avuy8888",
+                        value: 1498764865u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xvuy8888",
+                        value: 1498764888u32,
+                    },
+                    interface::EnumEntry {
+                        name: "p030",
+                        value: 808661072u32,
+                    },
+                    interface::EnumEntry {
+                        name: "rgb161616",
+                        value: 942950226u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr161616",
+                        value: 942950210u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r16f",
+                        value: 1210064978u32,
+                    },
+                    interface::EnumEntry {
+                        name: "gr1616f",
+                        value: 1210077767u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr161616f",
+                        value: 1213351746u32,
+                    },
+                    interface::EnumEntry {
+                        name: "r32f",
+                        value: 1176510546u32,
+                    },
+                    interface::EnumEntry {
+                        name: "gr3232f",
+                        value: 1176523335u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bgr323232f",
+                        value: 1179797314u32,
+                    },
+                    interface::EnumEntry {
+                        name: "abgr32323232f",
+                        value: 1178092097u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv20",
+                        value: 808605262u32,
+                    },
+                    interface::EnumEntry {
+                        name: "nv30",
+                        value: 808670798u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s010",
+                        value: 808530003u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s210",
+                        value: 808530515u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s410",
+                        value: 808531027u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s012",
+                        value: 842084435u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s212",
+                        value: 842084947u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s412",
+                        value: 842085459u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s016",
+                        value: 909193299u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s216",
+                        value: 909193811u32,
+                    },
+                    interface::EnumEntry {
+                        name: "s416",
+                        value: 909194323u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlShm {
+        pub const INTERFACE: &'static str = "wl_shm";
+        #[allow(clippy::too_many_arguments)]
+        pub fn format(&self, writer: &WaylandBuffer<Writer>, format: Format) {
+            Event::Format {
+                format: WEnum::Value(format),
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_shm`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlShmHandler {
+        fn create_pool(
+            &mut self,
+            client: &Client,
+            shm: &WlShm,
+            id: super::wl_shm_pool::WlShmPool,
+            fd: std::os::fd::OwnedFd,
+            size: i32,
+        ) {}
+        fn release(&mut self, client: &Client, shm: &WlShm) {}
+    }
+    impl<H: WlShmHandler + ?Sized> Dispatch<H> for WlShm {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::CreatePool { id, fd, size } => {
+                    handler.create_pool(client, self, id, fd, size)
+                }
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlShm {
+        const INTERFACE: &'static str = "wl_shm";
+        const REQUEST_FDS: &'static [u8] = &[1u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        let fd = reader.get_fd()?;
+                        let size = parser.get_i32()?;
+                        Request::CreatePool {
+                            id,
+                            fd,
+                            size,
+                        }
+                    }
+                    1u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_buffer {
+    use super::*;
+    pub struct WlBuffer {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlBuffer {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlBuffer {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Release,
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Release => 0u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Release => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_buffer",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "release",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    impl WlBuffer {
+        pub const INTERFACE: &'static str = "wl_buffer";
+        #[allow(clippy::too_many_arguments)]
+        pub fn release(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Release.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_buffer`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlBufferHandler {
+        fn destroy(&mut self, client: &Client, buffer: &WlBuffer) {}
+    }
+    impl<H: WlBufferHandler + ?Sized> Dispatch<H> for WlBuffer {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Destroy => handler.destroy(client, self),
+            }
+        }
+    }
+    impl Resource for WlBuffer {
+        const INTERFACE: &'static str = "wl_buffer";
+        const REQUEST_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_data_offer {
+    use super::*;
+    pub struct WlDataOffer {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlDataOffer {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlDataOffer {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Offer { mime_type: &'a str },
+        SourceActions { source_actions: super::wl_data_device_manager::DndAction },
+        Action { dnd_action: super::wl_data_device_manager::DndAction },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Offer { .. } => 0u16,
+                Self::SourceActions { .. } => 1u16,
+                Self::Action { .. } => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Offer { mime_type } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 0u16);
+                    msg.write_string(mime_type);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SourceActions { source_actions } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(source_actions.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Action { dnd_action } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 2u16);
+                    msg.write_u32(dnd_action.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let mime_type = parser.get_string()?;
+                        Self::Offer { mime_type }
+                    }
+                    1u16 => {
+                        let source_actions = parser.get_u32()?.into();
+                        Self::SourceActions {
+                            source_actions,
+                        }
+                    }
+                    2u16 => {
+                        let dnd_action = parser.get_u32()?.into();
+                        Self::Action { dnd_action }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        Accept { serial: u32, mime_type: Option<&'a str> },
+        Receive { mime_type: &'a str, fd: std::os::fd::OwnedFd },
+        Destroy,
+        Finish,
+        SetActions {
+            dnd_actions: super::wl_data_device_manager::DndAction,
+            preferred_action: super::wl_data_device_manager::DndAction,
+        },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidFinish = 0u32,
+        InvalidActionMask = 1u32,
+        InvalidAction = 2u32,
+        InvalidOffer = 3u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidFinish),
+                1u32 => Ok(Self::InvalidActionMask),
+                2u32 => Ok(Self::InvalidAction),
+                3u32 => Ok(Self::InvalidOffer),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_data_offer",
+        version: 3u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "accept",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "mime_type",
+                        ty: interface::ArgType::String,
+                        nullable: true,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "receive",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mime_type",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "fd",
+                        ty: interface::ArgType::Fd,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "finish",
+                since: 3u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_actions",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "dnd_actions",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_data_device_manager.dnd_action"),
+                    },
+                    interface::ArgDesc {
+                        name: "preferred_action",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_data_device_manager.dnd_action"),
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "offer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mime_type",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "source_actions",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "source_actions",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_data_device_manager.dnd_action"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "action",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "dnd_action",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_data_device_manager.dnd_action"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_finish",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_action_mask",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_action",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_offer",
+                        value: 3u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlDataOffer {
+        pub const INTERFACE: &'static str = "wl_data_offer";
+        #[allow(clippy::too_many_arguments)]
+        pub fn offer(&self, writer: &WaylandBuffer<Writer>, mime_type: &str) {
+            Event::Offer { mime_type }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn source_actions(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            source_actions: super::wl_data_device_manager::DndAction,
+        ) {
+            Event::SourceActions {
+                source_actions,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn action(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            dnd_action: super::wl_data_device_manager::DndAction,
+        ) {
+            Event::Action { dnd_action }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_data_offer`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlDataOfferHandler {
+        fn accept(
+            &mut self,
+            client: &Client,
+            data_offer: &WlDataOffer,
+            serial: u32,
+            mime_type: Option<&str>,
+        ) {}
+        fn receive(
+            &mut self,
+            client: &Client,
+            data_offer: &WlDataOffer,
+            mime_type: &str,
+            fd: std::os::fd::OwnedFd,
+        ) {}
+        fn destroy(&mut self, client: &Client, data_offer: &WlDataOffer) {}
+        fn finish(&mut self, client: &Client, data_offer: &WlDataOffer) {}
+        fn set_actions(
+            &mut self,
+            client: &Client,
+            data_offer: &WlDataOffer,
+            dnd_actions: super::wl_data_device_manager::DndAction,
+            preferred_action: super::wl_data_device_manager::DndAction,
+        ) {}
+    }
+    impl<H: WlDataOfferHandler + ?Sized> Dispatch<H> for WlDataOffer {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Accept { serial, mime_type } => {
+                    handler.accept(client, self, serial, mime_type)
+                }
+                Request::Receive { mime_type, fd } => {
+                    handler.receive(client, self, mime_type, fd)
+                }
+                Request::Destroy => handler.destroy(client, self),
+                Request::Finish => handler.finish(client, self),
+                Request::SetActions { dnd_actions, preferred_action } => {
+                    handler.set_actions(client, self, dnd_actions, preferred_action)
+                }
+            }
+        }
+    }
+    impl Resource for WlDataOffer {
+        const INTERFACE: &'static str = "wl_data_offer";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 1u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        let mime_type = parser.get_nullable_string()?;
+                        Request::Accept {
+                            serial,
+                            mime_type,
+                        }
+                    }
+                    1u16 => {
+                        let mime_type = parser.get_string()?;
+                        let fd = reader.get_fd()?;
+                        Request::Receive { mime_type, fd }
+                    }
+                    2u16 => Request::Destroy,
+                    3u16 => Request::Finish,
+                    4u16 => {
+                        let dnd_actions = parser.get_u32()?.into();
+                        let preferred_action = parser.get_u32()?.into();
+                        Request::SetActions {
+                            dnd_actions,
+                            preferred_action,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_data_source {
+    use super::*;
+    pub struct WlDataSource {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlDataSource {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlDataSource {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Target { mime_type: Option<&'a str> },
+        Send { mime_type: &'a str, fd: std::os::fd::RawFd },
+        Cancelled,
+        DndDropPerformed,
+        DndFinished,
+        Action { dnd_action: super::wl_data_device_manager::DndAction },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Target { .. } => 0u16,
+                Self::Send { .. } => 1u16,
+                Self::Cancelled => 2u16,
+                Self::DndDropPerformed => 3u16,
+                Self::DndFinished => 4u16,
+                Self::Action { .. } => 5u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Target { mime_type } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 0u16);
+                    match mime_type {
+                        Some(string) => msg.write_string(string),
+                        None => msg.write_null_string(),
+                    };
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Send { mime_type, fd } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 1u16);
+                    msg.write_string(mime_type);
+                    writer.add_fd(fd);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Cancelled => {
+                    let msg = Message::<8usize>::new(sender_id, 2u16);
+                    writer.write_request(msg.data());
+                }
+                Self::DndDropPerformed => {
+                    let msg = Message::<8usize>::new(sender_id, 3u16);
+                    writer.write_request(msg.data());
+                }
+                Self::DndFinished => {
+                    let msg = Message::<8usize>::new(sender_id, 4u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Action { dnd_action } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 5u16);
+                    msg.write_u32(dnd_action.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let mime_type = parser.get_nullable_string()?;
+                        Self::Target { mime_type }
+                    }
+                    1u16 => {
+                        let mime_type = parser.get_string()?;
+                        let fd = std::os::fd::IntoRawFd::into_raw_fd(reader.get_fd()?);
+                        Self::Send { mime_type, fd }
+                    }
+                    2u16 => Self::Cancelled,
+                    3u16 => Self::DndDropPerformed,
+                    4u16 => Self::DndFinished,
+                    5u16 => {
+                        let dnd_action = parser.get_u32()?.into();
+                        Self::Action { dnd_action }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        Offer { mime_type: &'a str },
+        Destroy,
+        SetActions { dnd_actions: super::wl_data_device_manager::DndAction },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidActionMask = 0u32,
+        InvalidSource = 1u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidActionMask),
+                1u32 => Ok(Self::InvalidSource),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_data_source",
+        version: 3u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "offer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mime_type",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_actions",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "dnd_actions",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_data_device_manager.dnd_action"),
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "target",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mime_type",
+                        ty: interface::ArgType::String,
+                        nullable: true,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "send",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mime_type",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "fd",
+                        ty: interface::ArgType::Fd,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "cancelled",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "dnd_drop_performed",
+                since: 3u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "dnd_finished",
+                since: 3u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "action",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "dnd_action",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_data_device_manager.dnd_action"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_action_mask",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_source",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlDataSource {
+        pub const INTERFACE: &'static str = "wl_data_source";
+        #[allow(clippy::too_many_arguments)]
+        pub fn target(&self, writer: &WaylandBuffer<Writer>, mime_type: Option<&str>) {
+            Event::Target { mime_type }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn send(&self, writer: &WaylandBuffer<Writer>, mime_type: &str, fd: i32) {
+            Event::Send { mime_type, fd }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn cancelled(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Cancelled.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn dnd_drop_performed(&self, writer: &WaylandBuffer<Writer>) {
+            Event::DndDropPerformed.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn dnd_finished(&self, writer: &WaylandBuffer<Writer>) {
+            Event::DndFinished.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn action(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            dnd_action: super::wl_data_device_manager::DndAction,
+        ) {
+            Event::Action { dnd_action }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_data_source`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlDataSourceHandler {
+        fn offer(
+            &mut self,
+            client: &Client,
+            data_source: &WlDataSource,
+            mime_type: &str,
+        ) {}
+        fn destroy(&mut self, client: &Client, data_source: &WlDataSource) {}
+        fn set_actions(
+            &mut self,
+            client: &Client,
+            data_source: &WlDataSource,
+            dnd_actions: super::wl_data_device_manager::DndAction,
+        ) {}
+    }
+    impl<H: WlDataSourceHandler + ?Sized> Dispatch<H> for WlDataSource {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Offer { mime_type } => handler.offer(client, self, mime_type),
+                Request::Destroy => handler.destroy(client, self),
+                Request::SetActions { dnd_actions } => {
+                    handler.set_actions(client, self, dnd_actions)
+                }
+            }
+        }
+    }
+    impl Resource for WlDataSource {
+        const INTERFACE: &'static str = "wl_data_source";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let mime_type = parser.get_string()?;
+                        Request::Offer { mime_type }
+                    }
+                    1u16 => Request::Destroy,
+                    2u16 => {
+                        let dnd_actions = parser.get_u32()?.into();
+                        Request::SetActions { dnd_actions }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_data_device {
+    use super::*;
+    pub struct WlDataDevice {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlDataDevice {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlDataDevice {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        DataOffer { id: super::wl_data_offer::WlDataOffer },
+        Enter {
+            serial: u32,
+            surface: super::wl_surface::WlSurface,
+            x: f32,
+            y: f32,
+            id: Option<super::wl_data_offer::WlDataOffer>,
+        },
+        Leave,
+        Motion { time: u32, x: f32, y: f32 },
+        Drop,
+        Selection { id: Option<super::wl_data_offer::WlDataOffer> },
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::DataOffer { .. } => 0u16,
+                Self::Enter { .. } => 1u16,
+                Self::Leave => 2u16,
+                Self::Motion { .. } => 3u16,
+                Self::Drop => 4u16,
+                Self::Selection { .. } => 5u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::DataOffer { id } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(id.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Enter { serial, surface, x, y, id } => {
+                    let mut msg = Message::<28usize>::new(sender_id, 1u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(surface.id());
+                    msg.write_fixed(x);
+                    msg.write_fixed(y);
+                    msg.write_u32(id.map_or(0, |o| o.id()));
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Leave => {
+                    let msg = Message::<8usize>::new(sender_id, 2u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Motion { time, x, y } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 3u16);
+                    msg.write_u32(time);
+                    msg.write_fixed(x);
+                    msg.write_fixed(y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Drop => {
+                    let msg = Message::<8usize>::new(sender_id, 4u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Selection { id } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 5u16);
+                    msg.write_u32(id.map_or(0, |o| o.id()));
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id: super::wl_data_offer::WlDataOffer = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        Self::DataOffer { id }
+                    }
+                    1u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        let x = parser.get_fixed()?;
+                        let y = parser.get_fixed()?;
+                        let id = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Resource::from_id);
+                        Self::Enter {
+                            serial,
+                            surface,
+                            x,
+                            y,
+                            id,
+                        }
+                    }
+                    2u16 => Self::Leave,
+                    3u16 => {
+                        let time = parser.get_u32()?;
+                        let x = parser.get_fixed()?;
+                        let y = parser.get_fixed()?;
+                        Self::Motion { time, x, y }
+                    }
+                    4u16 => Self::Drop,
+                    5u16 => {
+                        let id = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Resource::from_id);
+                        Self::Selection { id }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        StartDrag {
+            source: Option<super::wl_data_source::WlDataSource>,
+            origin: super::wl_surface::WlSurface,
+            icon: Option<super::wl_surface::WlSurface>,
+            serial: u32,
+        },
+        SetSelection {
+            source: Option<super::wl_data_source::WlDataSource>,
+            serial: u32,
+        },
+        Release,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        Role = 0u32,
+        UsedSource = 1u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Role),
+                1u32 => Ok(Self::UsedSource),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_data_device",
+        version: 3u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "start_drag",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "source",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_data_source::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "origin",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "icon",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_selection",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "source",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_data_source::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "release",
+                since: 2u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "data_offer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_data_offer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "enter",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_data_offer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "leave",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "motion",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "drop",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "selection",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_data_offer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "role",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "used_source",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlDataDevice {
+        pub const INTERFACE: &'static str = "wl_data_device";
+        #[allow(clippy::too_many_arguments)]
+        pub fn data_offer(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+        ) -> super::wl_data_offer::WlDataOffer {
+            let new_id = writer.new_server_id();
+            let new_wl_data_offer: super::wl_data_offer::WlDataOffer = writer
+                .new_resource(new_id, writer.object_version(self.id));
+            Event::DataOffer {
+                id: Resource::from_id(new_id),
+            }
+                .encode(self.id, writer);
+            new_wl_data_offer
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn enter(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            surface: &super::wl_surface::WlSurface,
+            x: f32,
+            y: f32,
+            id: Option<&super::wl_data_offer::WlDataOffer>,
+        ) {
+            Event::Enter {
+                serial,
+                surface: Resource::from_id(surface.id()),
+                x,
+                y,
+                id: id.map(|o| Resource::from_id(o.id())),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn leave(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Leave.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn motion(&self, writer: &WaylandBuffer<Writer>, time: u32, x: f32, y: f32) {
+            Event::Motion { time, x, y }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn drop(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Drop.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn selection(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            id: Option<&super::wl_data_offer::WlDataOffer>,
+        ) {
+            Event::Selection {
+                id: id.map(|o| Resource::from_id(o.id())),
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_data_device`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlDataDeviceHandler {
+        fn start_drag(
+            &mut self,
+            client: &Client,
+            data_device: &WlDataDevice,
+            source: Option<super::wl_data_source::WlDataSource>,
+            origin: super::wl_surface::WlSurface,
+            icon: Option<super::wl_surface::WlSurface>,
+            serial: u32,
+        ) {}
+        fn set_selection(
+            &mut self,
+            client: &Client,
+            data_device: &WlDataDevice,
+            source: Option<super::wl_data_source::WlDataSource>,
+            serial: u32,
+        ) {}
+        fn release(&mut self, client: &Client, data_device: &WlDataDevice) {}
+    }
+    impl<H: WlDataDeviceHandler + ?Sized> Dispatch<H> for WlDataDevice {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::StartDrag { source, origin, icon, serial } => {
+                    handler.start_drag(client, self, source, origin, icon, serial)
+                }
+                Request::SetSelection { source, serial } => {
+                    handler.set_selection(client, self, source, serial)
+                }
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlDataDevice {
+        const INTERFACE: &'static str = "wl_data_device";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let source = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        let origin = Resource::from_id(parser.get_u32()?);
+                        let icon = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        let serial = parser.get_u32()?;
+                        Request::StartDrag {
+                            source,
+                            origin,
+                            icon,
+                            serial,
+                        }
+                    }
+                    1u16 => {
+                        let source = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        let serial = parser.get_u32()?;
+                        Request::SetSelection {
+                            source,
+                            serial,
+                        }
+                    }
+                    2u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_data_device_manager {
+    use super::*;
+    pub struct WlDataDeviceManager {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlDataDeviceManager {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlDataDeviceManager {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        CreateDataSource { id: super::wl_data_source::WlDataSource },
+        GetDataDevice {
+            id: super::wl_data_device::WlDataDevice,
+            seat: super::wl_seat::WlSeat,
+        },
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct DndAction(u32);
+    impl DndAction {
+        pub const NONE: Self = Self(0u32);
+        pub const COPY: Self = Self(1u32);
+        pub const MOVE: Self = Self(2u32);
+        pub const ASK: Self = Self(4u32);
+        const NAMES: &'static [(&'static str, u32)] = &[
+            ("NONE", 0u32),
+            ("COPY", 1u32),
+            ("MOVE", 2u32),
+            ("ASK", 4u32),
+        ];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(7u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !7u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for DndAction {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for DndAction {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for DndAction {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for DndAction {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for DndAction {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<DndAction> for u32 {
+        fn from(value: DndAction) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for DndAction {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for DndAction {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("DndAction", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_data_device_manager",
+        version: 3u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "create_data_source",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_data_source::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_data_device",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_data_device::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "dnd_action",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "copy",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "move",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "ask",
+                        value: 4u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlDataDeviceManager {
+        pub const INTERFACE: &'static str = "wl_data_device_manager";
+    }
+    /// Receives the requests of `wl_data_device_manager`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlDataDeviceManagerHandler {
+        fn create_data_source(
+            &mut self,
+            client: &Client,
+            data_device_manager: &WlDataDeviceManager,
+            id: super::wl_data_source::WlDataSource,
+        ) {}
+        fn get_data_device(
+            &mut self,
+            client: &Client,
+            data_device_manager: &WlDataDeviceManager,
+            id: super::wl_data_device::WlDataDevice,
+            seat: super::wl_seat::WlSeat,
+        ) {}
+    }
+    impl<H: WlDataDeviceManagerHandler + ?Sized> Dispatch<H> for WlDataDeviceManager {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::CreateDataSource { id } => {
+                    handler.create_data_source(client, self, id)
+                }
+                Request::GetDataDevice { id, seat } => {
+                    handler.get_data_device(client, self, id, seat)
+                }
+            }
+        }
+    }
+    impl Resource for WlDataDeviceManager {
+        const INTERFACE: &'static str = "wl_data_device_manager";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::CreateDataSource { id }
+                    }
+                    1u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        let seat = Resource::from_id(parser.get_u32()?);
+                        Request::GetDataDevice { id, seat }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_shell {
+    use super::*;
+    pub struct WlShell {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlShell {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlShell {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        GetShellSurface {
+            id: super::wl_shell_surface::WlShellSurface,
+            surface: super::wl_surface::WlSurface,
+        },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        Role = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Role),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_shell",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "get_shell_surface",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_shell_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "role",
+                        value: 0u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlShell {
+        pub const INTERFACE: &'static str = "wl_shell";
+    }
+    /// Receives the requests of `wl_shell`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlShellHandler {
+        fn get_shell_surface(
+            &mut self,
+            client: &Client,
+            shell: &WlShell,
+            id: super::wl_shell_surface::WlShellSurface,
+            surface: super::wl_surface::WlSurface,
+        ) {}
+    }
+    impl<H: WlShellHandler + ?Sized> Dispatch<H> for WlShell {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::GetShellSurface { id, surface } => {
+                    handler.get_shell_surface(client, self, id, surface)
+                }
+            }
+        }
+    }
+    impl Resource for WlShell {
+        const INTERFACE: &'static str = "wl_shell";
+        const REQUEST_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        Request::GetShellSurface {
+                            id,
+                            surface,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_shell_surface {
+    use super::*;
+    pub struct WlShellSurface {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlShellSurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlShellSurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Ping { serial: u32 },
+        Configure { edges: Resize, width: i32, height: i32 },
+        PopupDone,
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Ping { .. } => 0u16,
+                Self::Configure { .. } => 1u16,
+                Self::PopupDone => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Ping { serial } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Configure { edges, width, height } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 1u16);
+                    msg.write_u32(edges.into());
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::PopupDone => {
+                    let msg = Message::<8usize>::new(sender_id, 2u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        Self::Ping { serial }
+                    }
+                    1u16 => {
+                        let edges = parser.get_u32()?.into();
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::Configure {
+                            edges,
+                            width,
+                            height,
+                        }
+                    }
+                    2u16 => Self::PopupDone,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        Pong { serial: u32 },
+        Move { seat: super::wl_seat::WlSeat, serial: u32 },
+        Resize { seat: super::wl_seat::WlSeat, serial: u32, edges: Resize },
+        SetToplevel,
+        SetTransient {
+            parent: super::wl_surface::WlSurface,
+            x: i32,
+            y: i32,
+            flags: Transient,
+        },
+        SetFullscreen {
+            method: WEnum<FullscreenMethod>,
+            framerate: u32,
+            output: Option<super::wl_output::WlOutput>,
+        },
+        SetPopup {
+            seat: super::wl_seat::WlSeat,
+            serial: u32,
+            parent: super::wl_surface::WlSurface,
+            x: i32,
+            y: i32,
+            flags: Transient,
+        },
+        SetMaximized { output: Option<super::wl_output::WlOutput> },
+        SetTitle { title: &'a str },
+        SetClass { class_: &'a str },
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Resize(u32);
+    impl Resize {
+        pub const NONE: Self = Self(0u32);
+        pub const TOP: Self = Self(1u32);
+        pub const BOTTOM: Self = Self(2u32);
+        pub const LEFT: Self = Self(4u32);
+        pub const TOP_LEFT: Self = Self(5u32);
+        pub const BOTTOM_LEFT: Self = Self(6u32);
+        pub const RIGHT: Self = Self(8u32);
+        pub const TOP_RIGHT: Self = Self(9u32);
+        pub const BOTTOM_RIGHT: Self = Self(10u32);
+        const NAMES: &'static [(&'static str, u32)] = &[
+            ("NONE", 0u32),
+            ("TOP", 1u32),
+            ("BOTTOM", 2u32),
+            ("LEFT", 4u32),
+            ("TOP_LEFT", 5u32),
+            ("BOTTOM_LEFT", 6u32),
+            ("RIGHT", 8u32),
+            ("TOP_RIGHT", 9u32),
+            ("BOTTOM_RIGHT", 10u32),
+        ];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(15u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !15u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for Resize {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for Resize {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for Resize {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for Resize {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for Resize {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<Resize> for u32 {
+        fn from(value: Resize) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for Resize {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for Resize {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("Resize", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Transient(u32);
+    impl Transient {
+        pub const INACTIVE: Self = Self(1u32);
+        const NAMES: &'static [(&'static str, u32)] = &[("INACTIVE", 1u32)];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(1u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !1u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for Transient {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for Transient {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for Transient {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for Transient {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for Transient {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<Transient> for u32 {
+        fn from(value: Transient) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for Transient {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for Transient {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("Transient", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum FullscreenMethod {
+        Default = 0u32,
+        Scale = 1u32,
+        Driver = 2u32,
+        Fill = 3u32,
+    }
+    impl PartialEq<FullscreenMethod> for u32 {
+        fn eq(&self, other: &FullscreenMethod) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for FullscreenMethod {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for FullscreenMethod {}
+    impl From<FullscreenMethod> for u32 {
+        fn from(value: FullscreenMethod) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for FullscreenMethod {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Default),
+                1u32 => Ok(Self::Scale),
+                2u32 => Ok(Self::Driver),
+                3u32 => Ok(Self::Fill),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_shell_surface",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "pong",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "move",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "resize",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "edges",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("resize"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_toplevel",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_transient",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "parent",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "flags",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("transient"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_fullscreen",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "method",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("fullscreen_method"),
+                    },
+                    interface::ArgDesc {
+                        name: "framerate",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_popup",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "parent",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "flags",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("transient"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_maximized",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_title",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "title",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_class",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "class_",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "ping",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "edges",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("resize"),
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "popup_done",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "resize",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "left",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_left",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_left",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "right",
+                        value: 8u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_right",
+                        value: 9u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_right",
+                        value: 10u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "transient",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "inactive",
+                        value: 1u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "fullscreen_method",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "default",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "scale",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "driver",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "fill",
+                        value: 3u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlShellSurface {
+        pub const INTERFACE: &'static str = "wl_shell_surface";
+        #[allow(clippy::too_many_arguments)]
+        pub fn ping(&self, writer: &WaylandBuffer<Writer>, serial: u32) {
+            Event::Ping { serial }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn configure(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            edges: Resize,
+            width: i32,
+            height: i32,
+        ) {
+            Event::Configure {
+                edges,
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn popup_done(&self, writer: &WaylandBuffer<Writer>) {
+            Event::PopupDone.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_shell_surface`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlShellSurfaceHandler {
+        fn pong(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            serial: u32,
+        ) {}
+        fn r#move(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            seat: super::wl_seat::WlSeat,
+            serial: u32,
+        ) {}
+        fn resize(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            seat: super::wl_seat::WlSeat,
+            serial: u32,
+            edges: Resize,
+        ) {}
+        fn set_toplevel(&mut self, client: &Client, shell_surface: &WlShellSurface) {}
+        fn set_transient(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            parent: super::wl_surface::WlSurface,
+            x: i32,
+            y: i32,
+            flags: Transient,
+        ) {}
+        fn set_fullscreen(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            method: WEnum<FullscreenMethod>,
+            framerate: u32,
+            output: Option<super::wl_output::WlOutput>,
+        ) {}
+        fn set_popup(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            seat: super::wl_seat::WlSeat,
+            serial: u32,
+            parent: super::wl_surface::WlSurface,
+            x: i32,
+            y: i32,
+            flags: Transient,
+        ) {}
+        fn set_maximized(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            output: Option<super::wl_output::WlOutput>,
+        ) {}
+        fn set_title(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            title: &str,
+        ) {}
+        fn set_class(
+            &mut self,
+            client: &Client,
+            shell_surface: &WlShellSurface,
+            class_: &str,
+        ) {}
+    }
+    impl<H: WlShellSurfaceHandler + ?Sized> Dispatch<H> for WlShellSurface {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Pong { serial } => handler.pong(client, self, serial),
+                Request::Move { seat, serial } => {
+                    handler.r#move(client, self, seat, serial)
+                }
+                Request::Resize { seat, serial, edges } => {
+                    handler.resize(client, self, seat, serial, edges)
+                }
+                Request::SetToplevel => handler.set_toplevel(client, self),
+                Request::SetTransient { parent, x, y, flags } => {
+                    handler.set_transient(client, self, parent, x, y, flags)
+                }
+                Request::SetFullscreen { method, framerate, output } => {
+                    handler.set_fullscreen(client, self, method, framerate, output)
+                }
+                Request::SetPopup { seat, serial, parent, x, y, flags } => {
+                    handler.set_popup(client, self, seat, serial, parent, x, y, flags)
+                }
+                Request::SetMaximized { output } => {
+                    handler.set_maximized(client, self, output)
+                }
+                Request::SetTitle { title } => handler.set_title(client, self, title),
+                Request::SetClass { class_ } => handler.set_class(client, self, class_),
+            }
+        }
+    }
+    impl Resource for WlShellSurface {
+        const INTERFACE: &'static str = "wl_shell_surface";
+        const REQUEST_FDS: &'static [u8] = &[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        Request::Pong { serial }
+                    }
+                    1u16 => {
+                        let seat = Resource::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        Request::Move { seat, serial }
+                    }
+                    2u16 => {
+                        let seat = Resource::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        let edges = parser.get_u32()?.into();
+                        Request::Resize {
+                            seat,
+                            serial,
+                            edges,
+                        }
+                    }
+                    3u16 => Request::SetToplevel,
+                    4u16 => {
+                        let parent = Resource::from_id(parser.get_u32()?);
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let flags = parser.get_u32()?.into();
+                        Request::SetTransient {
+                            parent,
+                            x,
+                            y,
+                            flags,
+                        }
+                    }
+                    5u16 => {
+                        let method = parser.get_u32()?.into();
+                        let framerate = parser.get_u32()?;
+                        let output = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        Request::SetFullscreen {
+                            method,
+                            framerate,
+                            output,
+                        }
+                    }
+                    6u16 => {
+                        let seat = Resource::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        let parent = Resource::from_id(parser.get_u32()?);
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let flags = parser.get_u32()?.into();
+                        Request::SetPopup {
+                            seat,
+                            serial,
+                            parent,
+                            x,
+                            y,
+                            flags,
+                        }
+                    }
+                    7u16 => {
+                        let output = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        Request::SetMaximized { output }
+                    }
+                    8u16 => {
+                        let title = parser.get_string()?;
+                        Request::SetTitle { title }
+                    }
+                    9u16 => {
+                        let class_ = parser.get_string()?;
+                        Request::SetClass { class_ }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_surface {
+    use super::*;
+    pub struct WlSurface {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlSurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlSurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Enter { output: super::wl_output::WlOutput },
+        Leave { output: super::wl_output::WlOutput },
+        PreferredBufferScale { factor: i32 },
+        PreferredBufferTransform { transform: WEnum<super::wl_output::Transform> },
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Enter { .. } => 0u16,
+                Self::Leave { .. } => 1u16,
+                Self::PreferredBufferScale { .. } => 2u16,
+                Self::PreferredBufferTransform { .. } => 3u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Enter { output } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(output.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Leave { output } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(output.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::PreferredBufferScale { factor } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 2u16);
+                    msg.write_i32(factor);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::PreferredBufferTransform { transform } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 3u16);
+                    msg.write_u32(transform.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let output = Resource::from_id(parser.get_u32()?);
+                        Self::Enter { output }
+                    }
+                    1u16 => {
+                        let output = Resource::from_id(parser.get_u32()?);
+                        Self::Leave { output }
+                    }
+                    2u16 => {
+                        let factor = parser.get_i32()?;
+                        Self::PreferredBufferScale {
+                            factor,
+                        }
+                    }
+                    3u16 => {
+                        let transform = parser.get_u32()?.into();
+                        Self::PreferredBufferTransform {
+                            transform,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        Attach { buffer: Option<super::wl_buffer::WlBuffer>, x: i32, y: i32 },
+        Damage { x: i32, y: i32, width: i32, height: i32 },
+        Frame { callback: super::wl_callback::WlCallback },
+        SetOpaqueRegion { region: Option<super::wl_region::WlRegion> },
+        SetInputRegion { region: Option<super::wl_region::WlRegion> },
+        Commit,
+        SetBufferTransform { transform: WEnum<super::wl_output::Transform> },
+        SetBufferScale { scale: i32 },
+        DamageBuffer { x: i32, y: i32, width: i32, height: i32 },
+        Offset { x: i32, y: i32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidScale = 0u32,
+        InvalidTransform = 1u32,
+        InvalidSize = 2u32,
+        InvalidOffset = 3u32,
+        DefunctRoleObject = 4u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidScale),
+                1u32 => Ok(Self::InvalidTransform),
+                2u32 => Ok(Self::InvalidSize),
+                3u32 => Ok(Self::InvalidOffset),
+                4u32 => Ok(Self::DefunctRoleObject),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_surface",
+        version: 6u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "attach",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "buffer",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_buffer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "damage",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "callback",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_callback::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_opaque_region",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "region",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_region::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_input_region",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "region",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_region::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "commit",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_buffer_transform",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "transform",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_output.transform"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_buffer_scale",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "scale",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "damage_buffer",
+                since: 4u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "offset",
+                since: 5u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "enter",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "leave",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "preferred_buffer_scale",
+                since: 6u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "factor",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "preferred_buffer_transform",
+                since: 6u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "transform",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_output.transform"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_scale",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_transform",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_size",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_offset",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "defunct_role_object",
+                        value: 4u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlSurface {
+        pub const INTERFACE: &'static str = "wl_surface";
+        #[allow(clippy::too_many_arguments)]
+        pub fn enter(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            output: &super::wl_output::WlOutput,
+        ) {
+            Event::Enter {
+                output: Resource::from_id(output.id()),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn leave(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            output: &super::wl_output::WlOutput,
+        ) {
+            Event::Leave {
+                output: Resource::from_id(output.id()),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn preferred_buffer_scale(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            factor: i32,
+        ) {
+            Event::PreferredBufferScale {
+                factor,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn preferred_buffer_transform(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            transform: super::wl_output::Transform,
+        ) {
+            Event::PreferredBufferTransform {
+                transform: WEnum::Value(transform),
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_surface`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlSurfaceHandler {
+        fn destroy(&mut self, client: &Client, surface: &WlSurface) {}
+        fn attach(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            buffer: Option<super::wl_buffer::WlBuffer>,
+            x: i32,
+            y: i32,
+        ) {}
+        fn damage(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {}
+        fn frame(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            callback: super::wl_callback::WlCallback,
+        ) {}
+        fn set_opaque_region(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            region: Option<super::wl_region::WlRegion>,
+        ) {}
+        fn set_input_region(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            region: Option<super::wl_region::WlRegion>,
+        ) {}
+        fn commit(&mut self, client: &Client, surface: &WlSurface) {}
+        fn set_buffer_transform(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            transform: WEnum<super::wl_output::Transform>,
+        ) {}
+        fn set_buffer_scale(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            scale: i32,
+        ) {}
+        fn damage_buffer(
+            &mut self,
+            client: &Client,
+            surface: &WlSurface,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {}
+        fn offset(&mut self, client: &Client, surface: &WlSurface, x: i32, y: i32) {}
+    }
+    impl<H: WlSurfaceHandler + ?Sized> Dispatch<H> for WlSurface {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Destroy => handler.destroy(client, self),
+                Request::Attach { buffer, x, y } => {
+                    handler.attach(client, self, buffer, x, y)
+                }
+                Request::Damage { x, y, width, height } => {
+                    handler.damage(client, self, x, y, width, height)
+                }
+                Request::Frame { callback } => handler.frame(client, self, callback),
+                Request::SetOpaqueRegion { region } => {
+                    handler.set_opaque_region(client, self, region)
+                }
+                Request::SetInputRegion { region } => {
+                    handler.set_input_region(client, self, region)
+                }
+                Request::Commit => handler.commit(client, self),
+                Request::SetBufferTransform { transform } => {
+                    handler.set_buffer_transform(client, self, transform)
+                }
+                Request::SetBufferScale { scale } => {
+                    handler.set_buffer_scale(client, self, scale)
+                }
+                Request::DamageBuffer { x, y, width, height } => {
+                    handler.damage_buffer(client, self, x, y, width, height)
+                }
+                Request::Offset { x, y } => handler.offset(client, self, x, y),
+            }
+        }
+    }
+    impl Resource for WlSurface {
+        const INTERFACE: &'static str = "wl_surface";
+        const REQUEST_FDS: &'static [u8] = &[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Destroy,
+                    1u16 => {
+                        let buffer = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        Request::Attach { buffer, x, y }
+                    }
+                    2u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Request::Damage {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    3u16 => {
+                        let callback = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::Frame { callback }
+                    }
+                    4u16 => {
+                        let region = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        Request::SetOpaqueRegion { region }
+                    }
+                    5u16 => {
+                        let region = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        Request::SetInputRegion { region }
+                    }
+                    6u16 => Request::Commit,
+                    7u16 => {
+                        let transform = parser.get_u32()?.into();
+                        Request::SetBufferTransform {
+                            transform,
+                        }
+                    }
+                    8u16 => {
+                        let scale = parser.get_i32()?;
+                        Request::SetBufferScale { scale }
+                    }
+                    9u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Request::DamageBuffer {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    10u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        Request::Offset { x, y }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_seat {
+    use super::*;
+    pub struct WlSeat {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlSeat {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlSeat {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Capabilities { capabilities: Capability },
+        Name { name: &'a str },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Capabilities { .. } => 0u16,
+                Self::Name { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Capabilities { capabilities } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(capabilities.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Name { name } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 1u16);
+                    msg.write_string(name);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let capabilities = parser.get_u32()?.into();
+                        Self::Capabilities { capabilities }
+                    }
+                    1u16 => {
+                        let name = parser.get_string()?;
+                        Self::Name { name }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        GetPointer { id: super::wl_pointer::WlPointer },
+        GetKeyboard { id: super::wl_keyboard::WlKeyboard },
+        GetTouch { id: super::wl_touch::WlTouch },
+        Release,
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Capability(u32);
+    impl Capability {
+        pub const POINTER: Self = Self(1u32);
+        pub const KEYBOARD: Self = Self(2u32);
+        pub const TOUCH: Self = Self(4u32);
+        const NAMES: &'static [(&'static str, u32)] = &[
+            ("POINTER", 1u32),
+            ("KEYBOARD", 2u32),
+            ("TOUCH", 4u32),
+        ];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(7u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !7u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for Capability {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for Capability {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for Capability {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for Capability {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for Capability {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<Capability> for u32 {
+        fn from(value: Capability) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for Capability {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for Capability {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("Capability", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        MissingCapability = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::MissingCapability),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_seat",
+        version: 10u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "get_pointer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_pointer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_keyboard",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_keyboard::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_touch",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_touch::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "release",
+                since: 5u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "capabilities",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "capabilities",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("capability"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "name",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "name",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "capability",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "pointer",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "keyboard",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "touch",
+                        value: 4u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "missing_capability",
+                        value: 0u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlSeat {
+        pub const INTERFACE: &'static str = "wl_seat";
+        #[allow(clippy::too_many_arguments)]
+        pub fn capabilities(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            capabilities: Capability,
+        ) {
+            Event::Capabilities {
+                capabilities,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn name(&self, writer: &WaylandBuffer<Writer>, name: &str) {
+            Event::Name { name }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_seat`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlSeatHandler {
+        fn get_pointer(
+            &mut self,
+            client: &Client,
+            seat: &WlSeat,
+            id: super::wl_pointer::WlPointer,
+        ) {}
+        fn get_keyboard(
+            &mut self,
+            client: &Client,
+            seat: &WlSeat,
+            id: super::wl_keyboard::WlKeyboard,
+        ) {}
+        fn get_touch(
+            &mut self,
+            client: &Client,
+            seat: &WlSeat,
+            id: super::wl_touch::WlTouch,
+        ) {}
+        fn release(&mut self, client: &Client, seat: &WlSeat) {}
+    }
+    impl<H: WlSeatHandler + ?Sized> Dispatch<H> for WlSeat {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::GetPointer { id } => handler.get_pointer(client, self, id),
+                Request::GetKeyboard { id } => handler.get_keyboard(client, self, id),
+                Request::GetTouch { id } => handler.get_touch(client, self, id),
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlSeat {
+        const INTERFACE: &'static str = "wl_seat";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::GetPointer { id }
+                    }
+                    1u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::GetKeyboard { id }
+                    }
+                    2u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        Request::GetTouch { id }
+                    }
+                    3u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_pointer {
+    use super::*;
+    pub struct WlPointer {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlPointer {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlPointer {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Enter {
+            serial: u32,
+            surface: super::wl_surface::WlSurface,
+            surface_x: f32,
+            surface_y: f32,
+        },
+        Leave { serial: u32, surface: super::wl_surface::WlSurface },
+        Motion { time: u32, surface_x: f32, surface_y: f32 },
+        Button { serial: u32, time: u32, button: u32, state: WEnum<ButtonState> },
+        Axis { time: u32, axis: WEnum<Axis>, value: f32 },
+        Frame,
+        AxisSource { axis_source: WEnum<AxisSource> },
+        AxisStop { time: u32, axis: WEnum<Axis> },
+        AxisDiscrete { axis: WEnum<Axis>, discrete: i32 },
+        AxisValue120 { axis: WEnum<Axis>, value120: i32 },
+        AxisRelativeDirection {
+            axis: WEnum<Axis>,
+            direction: WEnum<AxisRelativeDirection>,
+        },
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Enter { .. } => 0u16,
+                Self::Leave { .. } => 1u16,
+                Self::Motion { .. } => 2u16,
+                Self::Button { .. } => 3u16,
+                Self::Axis { .. } => 4u16,
+                Self::Frame => 5u16,
+                Self::AxisSource { .. } => 6u16,
+                Self::AxisStop { .. } => 7u16,
+                Self::AxisDiscrete { .. } => 8u16,
+                Self::AxisValue120 { .. } => 9u16,
+                Self::AxisRelativeDirection { .. } => 10u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Enter { serial, surface, surface_x, surface_y } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 0u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(surface.id());
+                    msg.write_fixed(surface_x);
+                    msg.write_fixed(surface_y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Leave { serial, surface } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(surface.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Motion { time, surface_x, surface_y } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 2u16);
+                    msg.write_u32(time);
+                    msg.write_fixed(surface_x);
+                    msg.write_fixed(surface_y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Button { serial, time, button, state } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 3u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(time);
+                    msg.write_u32(button);
+                    msg.write_u32(state.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Axis { time, axis, value } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 4u16);
+                    msg.write_u32(time);
+                    msg.write_u32(axis.into());
+                    msg.write_fixed(value);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Frame => {
+                    let msg = Message::<8usize>::new(sender_id, 5u16);
+                    writer.write_request(msg.data());
+                }
+                Self::AxisSource { axis_source } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 6u16);
+                    msg.write_u32(axis_source.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::AxisStop { time, axis } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 7u16);
+                    msg.write_u32(time);
+                    msg.write_u32(axis.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::AxisDiscrete { axis, discrete } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 8u16);
+                    msg.write_u32(axis.into());
+                    msg.write_i32(discrete);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::AxisValue120 { axis, value120 } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 9u16);
+                    msg.write_u32(axis.into());
+                    msg.write_i32(value120);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::AxisRelativeDirection { axis, direction } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 10u16);
+                    msg.write_u32(axis.into());
+                    msg.write_u32(direction.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        let surface_x = parser.get_fixed()?;
+                        let surface_y = parser.get_fixed()?;
+                        Self::Enter {
+                            serial,
+                            surface,
+                            surface_x,
+                            surface_y,
+                        }
+                    }
+                    1u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        Self::Leave { serial, surface }
+                    }
+                    2u16 => {
+                        let time = parser.get_u32()?;
+                        let surface_x = parser.get_fixed()?;
+                        let surface_y = parser.get_fixed()?;
+                        Self::Motion {
+                            time,
+                            surface_x,
+                            surface_y,
+                        }
+                    }
+                    3u16 => {
+                        let serial = parser.get_u32()?;
+                        let time = parser.get_u32()?;
+                        let button = parser.get_u32()?;
+                        let state = parser.get_u32()?.into();
+                        Self::Button {
+                            serial,
+                            time,
+                            button,
+                            state,
+                        }
+                    }
+                    4u16 => {
+                        let time = parser.get_u32()?;
+                        let axis = parser.get_u32()?.into();
+                        let value = parser.get_fixed()?;
+                        Self::Axis { time, axis, value }
+                    }
+                    5u16 => Self::Frame,
+                    6u16 => {
+                        let axis_source = parser.get_u32()?.into();
+                        Self::AxisSource { axis_source }
+                    }
+                    7u16 => {
+                        let time = parser.get_u32()?;
+                        let axis = parser.get_u32()?.into();
+                        Self::AxisStop { time, axis }
+                    }
+                    8u16 => {
+                        let axis = parser.get_u32()?.into();
+                        let discrete = parser.get_i32()?;
+                        Self::AxisDiscrete {
+                            axis,
+                            discrete,
+                        }
+                    }
+                    9u16 => {
+                        let axis = parser.get_u32()?.into();
+                        let value120 = parser.get_i32()?;
+                        Self::AxisValue120 {
+                            axis,
+                            value120,
+                        }
+                    }
+                    10u16 => {
+                        let axis = parser.get_u32()?.into();
+                        let direction = parser.get_u32()?.into();
+                        Self::AxisRelativeDirection {
+                            axis,
+                            direction,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        SetCursor {
+            serial: u32,
+            surface: Option<super::wl_surface::WlSurface>,
+            hotspot_x: i32,
+            hotspot_y: i32,
+        },
+        Release,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        Role = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Role),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum ButtonState {
+        Released = 0u32,
+        Pressed = 1u32,
+    }
+    impl PartialEq<ButtonState> for u32 {
+        fn eq(&self, other: &ButtonState) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for ButtonState {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for ButtonState {}
+    impl From<ButtonState> for u32 {
+        fn from(value: ButtonState) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for ButtonState {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Released),
+                1u32 => Ok(Self::Pressed),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Axis {
+        VerticalScroll = 0u32,
+        HorizontalScroll = 1u32,
+    }
+    impl PartialEq<Axis> for u32 {
+        fn eq(&self, other: &Axis) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Axis {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Axis {}
+    impl From<Axis> for u32 {
+        fn from(value: Axis) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Axis {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::VerticalScroll),
+                1u32 => Ok(Self::HorizontalScroll),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum AxisSource {
+        Wheel = 0u32,
+        Finger = 1u32,
+        Continuous = 2u32,
+        WheelTilt = 3u32,
+    }
+    impl PartialEq<AxisSource> for u32 {
+        fn eq(&self, other: &AxisSource) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for AxisSource {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for AxisSource {}
+    impl From<AxisSource> for u32 {
+        fn from(value: AxisSource) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for AxisSource {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Wheel),
+                1u32 => Ok(Self::Finger),
+                2u32 => Ok(Self::Continuous),
+                3u32 => Ok(Self::WheelTilt),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum AxisRelativeDirection {
+        Identical = 0u32,
+        Inverted = 1u32,
+    }
+    impl PartialEq<AxisRelativeDirection> for u32 {
+        fn eq(&self, other: &AxisRelativeDirection) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for AxisRelativeDirection {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for AxisRelativeDirection {}
+    impl From<AxisRelativeDirection> for u32 {
+        fn from(value: AxisRelativeDirection) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for AxisRelativeDirection {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Identical),
+                1u32 => Ok(Self::Inverted),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_pointer",
+        version: 10u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_cursor",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "hotspot_x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "hotspot_y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "release",
+                since: 3u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "enter",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface_x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface_y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "leave",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "motion",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface_x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface_y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "button",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "button",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "state",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("button_state"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "axis",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "axis",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis"),
+                    },
+                    interface::ArgDesc {
+                        name: "value",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 5u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "axis_source",
+                since: 5u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "axis_source",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis_source"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "axis_stop",
+                since: 5u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "axis",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "axis_discrete",
+                since: 5u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "axis",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis"),
+                    },
+                    interface::ArgDesc {
+                        name: "discrete",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "axis_value120",
+                since: 8u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "axis",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis"),
+                    },
+                    interface::ArgDesc {
+                        name: "value120",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "axis_relative_direction",
+                since: 9u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "axis",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis"),
+                    },
+                    interface::ArgDesc {
+                        name: "direction",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("axis_relative_direction"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "role",
+                        value: 0u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "button_state",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "released",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pressed",
+                        value: 1u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "axis",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "vertical_scroll",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "horizontal_scroll",
+                        value: 1u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "axis_source",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "wheel",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "finger",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "continuous",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "wheel_tilt",
+                        value: 3u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "axis_relative_direction",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "identical",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "inverted",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlPointer {
+        pub const INTERFACE: &'static str = "wl_pointer";
+        #[allow(clippy::too_many_arguments)]
+        pub fn enter(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            surface: &super::wl_surface::WlSurface,
+            surface_x: f32,
+            surface_y: f32,
+        ) {
+            Event::Enter {
+                serial,
+                surface: Resource::from_id(surface.id()),
+                surface_x,
+                surface_y,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn leave(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            surface: &super::wl_surface::WlSurface,
+        ) {
+            Event::Leave {
+                serial,
+                surface: Resource::from_id(surface.id()),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn motion(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            time: u32,
+            surface_x: f32,
+            surface_y: f32,
+        ) {
+            Event::Motion {
+                time,
+                surface_x,
+                surface_y,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn button(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: ButtonState,
+        ) {
+            Event::Button {
+                serial,
+                time,
+                button,
+                state: WEnum::Value(state),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn axis(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            time: u32,
+            axis: Axis,
+            value: f32,
+        ) {
+            Event::Axis {
+                time,
+                axis: WEnum::Value(axis),
+                value,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn frame(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Frame.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn axis_source(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            axis_source: AxisSource,
+        ) {
+            Event::AxisSource {
+                axis_source: WEnum::Value(axis_source),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn axis_stop(&self, writer: &WaylandBuffer<Writer>, time: u32, axis: Axis) {
+            Event::AxisStop {
+                time,
+                axis: WEnum::Value(axis),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn axis_discrete(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            axis: Axis,
+            discrete: i32,
+        ) {
+            Event::AxisDiscrete {
+                axis: WEnum::Value(axis),
+                discrete,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn axis_value120(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            axis: Axis,
+            value120: i32,
+        ) {
+            Event::AxisValue120 {
+                axis: WEnum::Value(axis),
+                value120,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn axis_relative_direction(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            axis: Axis,
+            direction: AxisRelativeDirection,
+        ) {
+            Event::AxisRelativeDirection {
+                axis: WEnum::Value(axis),
+                direction: WEnum::Value(direction),
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_pointer`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlPointerHandler {
+        fn set_cursor(
+            &mut self,
+            client: &Client,
+            pointer: &WlPointer,
+            serial: u32,
+            surface: Option<super::wl_surface::WlSurface>,
+            hotspot_x: i32,
+            hotspot_y: i32,
+        ) {}
+        fn release(&mut self, client: &Client, pointer: &WlPointer) {}
+    }
+    impl<H: WlPointerHandler + ?Sized> Dispatch<H> for WlPointer {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::SetCursor { serial, surface, hotspot_x, hotspot_y } => {
+                    handler
+                        .set_cursor(client, self, serial, surface, hotspot_x, hotspot_y)
+                }
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlPointer {
+        const INTERFACE: &'static str = "wl_pointer";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = {
+                            let id = parser.get_u32()?;
+                            if id == 0 { None } else { Some(Resource::from_id(id)) }
+                        };
+                        let hotspot_x = parser.get_i32()?;
+                        let hotspot_y = parser.get_i32()?;
+                        Request::SetCursor {
+                            serial,
+                            surface,
+                            hotspot_x,
+                            hotspot_y,
+                        }
+                    }
+                    1u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_keyboard {
+    use super::*;
+    pub struct WlKeyboard {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlKeyboard {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlKeyboard {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Keymap { format: WEnum<KeymapFormat>, fd: std::os::fd::RawFd, size: u32 },
+        Enter { serial: u32, surface: super::wl_surface::WlSurface, keys: &'a [u32] },
+        Leave { serial: u32, surface: super::wl_surface::WlSurface },
+        Key { serial: u32, time: u32, key: u32, state: WEnum<KeyState> },
+        Modifiers {
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        },
+        RepeatInfo { rate: i32, delay: i32 },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Keymap { .. } => 0u16,
+                Self::Enter { .. } => 1u16,
+                Self::Leave { .. } => 2u16,
+                Self::Key { .. } => 3u16,
+                Self::Modifiers { .. } => 4u16,
+                Self::RepeatInfo { .. } => 5u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Keymap { format, fd, size } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 0u16);
+                    msg.write_u32(format.into());
+                    writer.add_fd(fd);
+                    msg.write_u32(size);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Enter { serial, surface, keys } => {
+                    let mut msg = Message::<44usize>::new(sender_id, 1u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(surface.id());
+                    msg.write_array(keys);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Leave { serial, surface } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 2u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(surface.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Key { serial, time, key, state } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 3u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(time);
+                    msg.write_u32(key);
+                    msg.write_u32(state.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Modifiers {
+                    serial,
+                    mods_depressed,
+                    mods_latched,
+                    mods_locked,
+                    group,
+                } => {
+                    let mut msg = Message::<28usize>::new(sender_id, 4u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(mods_depressed);
+                    msg.write_u32(mods_latched);
+                    msg.write_u32(mods_locked);
+                    msg.write_u32(group);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::RepeatInfo { rate, delay } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 5u16);
+                    msg.write_i32(rate);
+                    msg.write_i32(delay);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let format = parser.get_u32()?.into();
+                        let fd = std::os::fd::IntoRawFd::into_raw_fd(reader.get_fd()?);
+                        let size = parser.get_u32()?;
+                        Self::Keymap { format, fd, size }
+                    }
+                    1u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        let keys = parser.get_array()?;
+                        Self::Enter {
+                            serial,
+                            surface,
+                            keys,
+                        }
+                    }
+                    2u16 => {
+                        let serial = parser.get_u32()?;
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        Self::Leave { serial, surface }
+                    }
+                    3u16 => {
+                        let serial = parser.get_u32()?;
+                        let time = parser.get_u32()?;
+                        let key = parser.get_u32()?;
+                        let state = parser.get_u32()?.into();
+                        Self::Key {
+                            serial,
+                            time,
+                            key,
+                            state,
+                        }
+                    }
+                    4u16 => {
+                        let serial = parser.get_u32()?;
+                        let mods_depressed = parser.get_u32()?;
+                        let mods_latched = parser.get_u32()?;
+                        let mods_locked = parser.get_u32()?;
+                        let group = parser.get_u32()?;
+                        Self::Modifiers {
+                            serial,
+                            mods_depressed,
+                            mods_latched,
+                            mods_locked,
+                            group,
+                        }
+                    }
+                    5u16 => {
+                        let rate = parser.get_i32()?;
+                        let delay = parser.get_i32()?;
+                        Self::RepeatInfo { rate, delay }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Release,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum KeymapFormat {
+        NoKeymap = 0u32,
+        XkbV1 = 1u32,
+    }
+    impl PartialEq<KeymapFormat> for u32 {
+        fn eq(&self, other: &KeymapFormat) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for KeymapFormat {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for KeymapFormat {}
+    impl From<KeymapFormat> for u32 {
+        fn from(value: KeymapFormat) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for KeymapFormat {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::NoKeymap),
+                1u32 => Ok(Self::XkbV1),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum KeyState {
+        Released = 0u32,
+        Pressed = 1u32,
+        Repeated = 2u32,
+    }
+    impl PartialEq<KeyState> for u32 {
+        fn eq(&self, other: &KeyState) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for KeyState {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for KeyState {}
+    impl From<KeyState> for u32 {
+        fn from(value: KeyState) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for KeyState {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Released),
+                1u32 => Ok(Self::Pressed),
+                2u32 => Ok(Self::Repeated),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_keyboard",
+        version: 10u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "release",
+                since: 3u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "keymap",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "format",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("keymap_format"),
+                    },
+                    interface::ArgDesc {
+                        name: "fd",
+                        ty: interface::ArgType::Fd,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "size",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "enter",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "keys",
+                        ty: interface::ArgType::Array,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "leave",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "key",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "key",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "state",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("key_state"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "modifiers",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "mods_depressed",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "mods_latched",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "mods_locked",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "group",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "repeat_info",
+                since: 4u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "rate",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "delay",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "keymap_format",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "no_keymap",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "xkb_v1",
+                        value: 1u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "key_state",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "released",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "pressed",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "repeated",
+                        value: 2u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlKeyboard {
+        pub const INTERFACE: &'static str = "wl_keyboard";
+        #[allow(clippy::too_many_arguments)]
+        pub fn keymap(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            format: KeymapFormat,
+            fd: i32,
+            size: u32,
+        ) {
+            Event::Keymap {
+                format: WEnum::Value(format),
+                fd,
+                size,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn enter(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            surface: &super::wl_surface::WlSurface,
+            keys: &[u32],
+        ) {
+            Event::Enter {
+                serial,
+                surface: Resource::from_id(surface.id()),
+                keys,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn leave(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            surface: &super::wl_surface::WlSurface,
+        ) {
+            Event::Leave {
+                serial,
+                surface: Resource::from_id(surface.id()),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn key(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: KeyState,
+        ) {
+            Event::Key {
+                serial,
+                time,
+                key,
+                state: WEnum::Value(state),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn modifiers(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ) {
+            Event::Modifiers {
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn repeat_info(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            rate: i32,
+            delay: i32,
+        ) {
+            Event::RepeatInfo { rate, delay }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_keyboard`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlKeyboardHandler {
+        fn release(&mut self, client: &Client, keyboard: &WlKeyboard) {}
+    }
+    impl<H: WlKeyboardHandler + ?Sized> Dispatch<H> for WlKeyboard {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlKeyboard {
+        const INTERFACE: &'static str = "wl_keyboard";
+        const REQUEST_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_touch {
+    use super::*;
+    pub struct WlTouch {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlTouch {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlTouch {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Down {
+            serial: u32,
+            time: u32,
+            surface: super::wl_surface::WlSurface,
+            id: i32,
+            x: f32,
+            y: f32,
+        },
+        Up { serial: u32, time: u32, id: i32 },
+        Motion { time: u32, id: i32, x: f32, y: f32 },
+        Frame,
+        Cancel,
+        Shape { id: i32, major: f32, minor: f32 },
+        Orientation { id: i32, orientation: f32 },
+    }
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Down { .. } => 0u16,
+                Self::Up { .. } => 1u16,
+                Self::Motion { .. } => 2u16,
+                Self::Frame => 3u16,
+                Self::Cancel => 4u16,
+                Self::Shape { .. } => 5u16,
+                Self::Orientation { .. } => 6u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Down { serial, time, surface, id, x, y } => {
+                    let mut msg = Message::<32usize>::new(sender_id, 0u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(time);
+                    msg.write_u32(surface.id());
+                    msg.write_i32(id);
+                    msg.write_fixed(x);
+                    msg.write_fixed(y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Up { serial, time, id } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 1u16);
+                    msg.write_u32(serial);
+                    msg.write_u32(time);
+                    msg.write_i32(id);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Motion { time, id, x, y } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 2u16);
+                    msg.write_u32(time);
+                    msg.write_i32(id);
+                    msg.write_fixed(x);
+                    msg.write_fixed(y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Frame => {
+                    let msg = Message::<8usize>::new(sender_id, 3u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Cancel => {
+                    let msg = Message::<8usize>::new(sender_id, 4u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Shape { id, major, minor } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 5u16);
+                    msg.write_i32(id);
+                    msg.write_fixed(major);
+                    msg.write_fixed(minor);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Orientation { id, orientation } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 6u16);
+                    msg.write_i32(id);
+                    msg.write_fixed(orientation);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        let time = parser.get_u32()?;
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        let id = parser.get_i32()?;
+                        let x = parser.get_fixed()?;
+                        let y = parser.get_fixed()?;
+                        Self::Down {
+                            serial,
+                            time,
+                            surface,
+                            id,
+                            x,
+                            y,
+                        }
+                    }
+                    1u16 => {
+                        let serial = parser.get_u32()?;
+                        let time = parser.get_u32()?;
+                        let id = parser.get_i32()?;
+                        Self::Up { serial, time, id }
+                    }
+                    2u16 => {
+                        let time = parser.get_u32()?;
+                        let id = parser.get_i32()?;
+                        let x = parser.get_fixed()?;
+                        let y = parser.get_fixed()?;
+                        Self::Motion { time, id, x, y }
+                    }
+                    3u16 => Self::Frame,
+                    4u16 => Self::Cancel,
+                    5u16 => {
+                        let id = parser.get_i32()?;
+                        let major = parser.get_fixed()?;
+                        let minor = parser.get_fixed()?;
+                        Self::Shape { id, major, minor }
+                    }
+                    6u16 => {
+                        let id = parser.get_i32()?;
+                        let orientation = parser.get_fixed()?;
+                        Self::Orientation {
+                            id,
+                            orientation,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Release,
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_touch",
+        version: 10u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "release",
+                since: 3u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "down",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "up",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "motion",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "time",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "frame",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "cancel",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "shape",
+                since: 6u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "major",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "minor",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "orientation",
+                since: 6u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "orientation",
+                        ty: interface::ArgType::Fixed,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    impl WlTouch {
+        pub const INTERFACE: &'static str = "wl_touch";
+        #[allow(clippy::too_many_arguments)]
+        pub fn down(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            time: u32,
+            surface: &super::wl_surface::WlSurface,
+            id: i32,
+            x: f32,
+            y: f32,
+        ) {
+            Event::Down {
+                serial,
+                time,
+                surface: Resource::from_id(surface.id()),
+                id,
+                x,
+                y,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn up(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            serial: u32,
+            time: u32,
+            id: i32,
+        ) {
+            Event::Up { serial, time, id }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn motion(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            time: u32,
+            id: i32,
+            x: f32,
+            y: f32,
+        ) {
+            Event::Motion { time, id, x, y }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn frame(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Frame.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn cancel(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Cancel.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn shape(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            id: i32,
+            major: f32,
+            minor: f32,
+        ) {
+            Event::Shape { id, major, minor }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn orientation(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            id: i32,
+            orientation: f32,
+        ) {
+            Event::Orientation {
+                id,
+                orientation,
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_touch`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlTouchHandler {
+        fn release(&mut self, client: &Client, touch: &WlTouch) {}
+    }
+    impl<H: WlTouchHandler + ?Sized> Dispatch<H> for WlTouch {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlTouch {
+        const INTERFACE: &'static str = "wl_touch";
+        const REQUEST_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_output {
+    use super::*;
+    pub struct WlOutput {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlOutput {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlOutput {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Geometry {
+            x: i32,
+            y: i32,
+            physical_width: i32,
+            physical_height: i32,
+            subpixel: WEnum<Subpixel>,
+            make: &'a str,
+            model: &'a str,
+            transform: WEnum<Transform>,
+        },
+        Mode { flags: Mode, width: i32, height: i32, refresh: i32 },
+        Done,
+        Scale { factor: i32 },
+        Name { name: &'a str },
+        Description { description: &'a str },
+    }
+    impl<'a> Event<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Geometry { .. } => 0u16,
+                Self::Mode { .. } => 1u16,
+                Self::Done => 2u16,
+                Self::Scale { .. } => 3u16,
+                Self::Name { .. } => 4u16,
+                Self::Description { .. } => 5u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Geometry {
+                    x,
+                    y,
+                    physical_width,
+                    physical_height,
+                    subpixel,
+                    make,
+                    model,
+                    transform,
+                } => {
+                    let mut msg = Message::<140usize>::new(sender_id, 0u16);
+                    msg.write_i32(x);
+                    msg.write_i32(y);
+                    msg.write_i32(physical_width);
+                    msg.write_i32(physical_height);
+                    msg.write_u32(subpixel.into());
+                    msg.write_string(make);
+                    msg.write_string(model);
+                    msg.write_u32(transform.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Mode { flags, width, height, refresh } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 1u16);
+                    msg.write_u32(flags.into());
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.write_i32(refresh);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Done => {
+                    let msg = Message::<8usize>::new(sender_id, 2u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Scale { factor } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 3u16);
+                    msg.write_i32(factor);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Name { name } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 4u16);
+                    msg.write_string(name);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Description { description } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 5u16);
+                    msg.write_string(description);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let physical_width = parser.get_i32()?;
+                        let physical_height = parser.get_i32()?;
+                        let subpixel = parser.get_u32()?.into();
+                        let make = parser.get_string()?;
+                        let model = parser.get_string()?;
+                        let transform = parser.get_u32()?.into();
+                        Self::Geometry {
+                            x,
+                            y,
+                            physical_width,
+                            physical_height,
+                            subpixel,
+                            make,
+                            model,
+                            transform,
+                        }
+                    }
+                    1u16 => {
+                        let flags = parser.get_u32()?.into();
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        let refresh = parser.get_i32()?;
+                        Self::Mode {
+                            flags,
+                            width,
+                            height,
+                            refresh,
+                        }
+                    }
+                    2u16 => Self::Done,
+                    3u16 => {
+                        let factor = parser.get_i32()?;
+                        Self::Scale { factor }
+                    }
+                    4u16 => {
+                        let name = parser.get_string()?;
+                        Self::Name { name }
+                    }
+                    5u16 => {
+                        let description = parser.get_string()?;
+                        Self::Description { description }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Release,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Subpixel {
+        Unknown = 0u32,
+        None = 1u32,
+        HorizontalRgb = 2u32,
+        HorizontalBgr = 3u32,
+        VerticalRgb = 4u32,
+        VerticalBgr = 5u32,
+    }
+    impl PartialEq<Subpixel> for u32 {
+        fn eq(&self, other: &Subpixel) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Subpixel {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Subpixel {}
+    impl From<Subpixel> for u32 {
+        fn from(value: Subpixel) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Subpixel {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Unknown),
+                1u32 => Ok(Self::None),
+                2u32 => Ok(Self::HorizontalRgb),
+                3u32 => Ok(Self::HorizontalBgr),
+                4u32 => Ok(Self::VerticalRgb),
+                5u32 => Ok(Self::VerticalBgr),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Transform {
+        Normal = 0u32,
+        _90 = 1u32,
+        _180 = 2u32,
+        _270 = 3u32,
+        Flipped = 4u32,
+        Flipped90 = 5u32,
+        Flipped180 = 6u32,
+        Flipped270 = 7u32,
+    }
+    impl PartialEq<Transform> for u32 {
+        fn eq(&self, other: &Transform) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Transform {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Transform {}
+    impl From<Transform> for u32 {
+        fn from(value: Transform) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Transform {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Normal),
+                1u32 => Ok(Self::_90),
+                2u32 => Ok(Self::_180),
+                3u32 => Ok(Self::_270),
+                4u32 => Ok(Self::Flipped),
+                5u32 => Ok(Self::Flipped90),
+                6u32 => Ok(Self::Flipped180),
+                7u32 => Ok(Self::Flipped270),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Mode(u32);
+    impl Mode {
+        pub const CURRENT: Self = Self(1u32);
+        pub const PREFERRED: Self = Self(2u32);
+        const NAMES: &'static [(&'static str, u32)] = &[
+            ("CURRENT", 1u32),
+            ("PREFERRED", 2u32),
+        ];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(3u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !3u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for Mode {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for Mode {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for Mode {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for Mode {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for Mode {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<Mode> for u32 {
+        fn from(value: Mode) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for Mode {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for Mode {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("Mode", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_output",
+        version: 4u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "release",
+                since: 3u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "geometry",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "physical_width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "physical_height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "subpixel",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("subpixel"),
+                    },
+                    interface::ArgDesc {
+                        name: "make",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "model",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "transform",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("transform"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "mode",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "flags",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("mode"),
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "refresh",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "done",
+                since: 2u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "scale",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "factor",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "name",
+                since: 4u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "name",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "description",
+                since: 4u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "description",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "subpixel",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "unknown",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "horizontal_rgb",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "horizontal_bgr",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "vertical_rgb",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "vertical_bgr",
+                        value: 5u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "transform",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "normal",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "90",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "180",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "270",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "flipped",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "flipped_90",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "flipped_180",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "flipped_270",
+                        value: 7u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "mode",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "current",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "preferred",
+                        value: 2u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlOutput {
+        pub const INTERFACE: &'static str = "wl_output";
+        #[allow(clippy::too_many_arguments)]
+        pub fn geometry(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            x: i32,
+            y: i32,
+            physical_width: i32,
+            physical_height: i32,
+            subpixel: Subpixel,
+            make: &str,
+            model: &str,
+            transform: Transform,
+        ) {
+            Event::Geometry {
+                x,
+                y,
+                physical_width,
+                physical_height,
+                subpixel: WEnum::Value(subpixel),
+                make,
+                model,
+                transform: WEnum::Value(transform),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn mode(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            flags: Mode,
+            width: i32,
+            height: i32,
+            refresh: i32,
+        ) {
+            Event::Mode {
+                flags,
+                width,
+                height,
+                refresh,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn done(&self, writer: &WaylandBuffer<Writer>) {
+            Event::Done.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn scale(&self, writer: &WaylandBuffer<Writer>, factor: i32) {
+            Event::Scale { factor }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn name(&self, writer: &WaylandBuffer<Writer>, name: &str) {
+            Event::Name { name }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn description(&self, writer: &WaylandBuffer<Writer>, description: &str) {
+            Event::Description { description }.encode(self.id, writer);
+        }
+    }
+    /// Receives the requests of `wl_output`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlOutputHandler {
+        fn release(&mut self, client: &Client, output: &WlOutput) {}
+    }
+    impl<H: WlOutputHandler + ?Sized> Dispatch<H> for WlOutput {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Release => handler.release(client, self),
+            }
+        }
+    }
+    impl Resource for WlOutput {
+        const INTERFACE: &'static str = "wl_output";
+        const REQUEST_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Release,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_region {
+    use super::*;
+    pub struct WlRegion {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlRegion {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlRegion {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        Add { x: i32, y: i32, width: i32, height: i32 },
+        Subtract { x: i32, y: i32, width: i32, height: i32 },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_region",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "add",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "subtract",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl WlRegion {
+        pub const INTERFACE: &'static str = "wl_region";
+    }
+    /// Receives the requests of `wl_region`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlRegionHandler {
+        fn destroy(&mut self, client: &Client, region: &WlRegion) {}
+        fn add(
+            &mut self,
+            client: &Client,
+            region: &WlRegion,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {}
+        fn subtract(
+            &mut self,
+            client: &Client,
+            region: &WlRegion,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {}
+    }
+    impl<H: WlRegionHandler + ?Sized> Dispatch<H> for WlRegion {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Destroy => handler.destroy(client, self),
+                Request::Add { x, y, width, height } => {
+                    handler.add(client, self, x, y, width, height)
+                }
+                Request::Subtract { x, y, width, height } => {
+                    handler.subtract(client, self, x, y, width, height)
+                }
+            }
+        }
+    }
+    impl Resource for WlRegion {
+        const INTERFACE: &'static str = "wl_region";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Destroy,
+                    1u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Request::Add {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    2u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Request::Subtract {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_subcompositor {
+    use super::*;
+    pub struct WlSubcompositor {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlSubcompositor {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlSubcompositor {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        GetSubsurface {
+            id: super::wl_subsurface::WlSubsurface,
+            surface: super::wl_surface::WlSurface,
+            parent: super::wl_surface::WlSurface,
+        },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        BadSurface = 0u32,
+        BadParent = 1u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::BadSurface),
+                1u32 => Ok(Self::BadParent),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_subcompositor",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "get_subsurface",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::wl_subsurface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "parent",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "bad_surface",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bad_parent",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlSubcompositor {
+        pub const INTERFACE: &'static str = "wl_subcompositor";
+    }
+    /// Receives the requests of `wl_subcompositor`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlSubcompositorHandler {
+        fn destroy(&mut self, client: &Client, subcompositor: &WlSubcompositor) {}
+        fn get_subsurface(
+            &mut self,
+            client: &Client,
+            subcompositor: &WlSubcompositor,
+            id: super::wl_subsurface::WlSubsurface,
+            surface: super::wl_surface::WlSurface,
+            parent: super::wl_surface::WlSurface,
+        ) {}
+    }
+    impl<H: WlSubcompositorHandler + ?Sized> Dispatch<H> for WlSubcompositor {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Destroy => handler.destroy(client, self),
+                Request::GetSubsurface { id, surface, parent } => {
+                    handler.get_subsurface(client, self, id, surface, parent)
+                }
+            }
+        }
+    }
+    impl Resource for WlSubcompositor {
+        const INTERFACE: &'static str = "wl_subcompositor";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Destroy,
+                    1u16 => {
+                        let id = reader
+                            .new_resource(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(self.id),
+                            );
+                        let surface = Resource::from_id(parser.get_u32()?);
+                        let parent = Resource::from_id(parser.get_u32()?);
+                        Request::GetSubsurface {
+                            id,
+                            surface,
+                            parent,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_subsurface {
+    use super::*;
+    pub struct WlSubsurface {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlSubsurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlSubsurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        SetPosition { x: i32, y: i32 },
+        PlaceAbove { sibling: super::wl_surface::WlSurface },
+        PlaceBelow { sibling: super::wl_surface::WlSurface },
+        SetSync,
+        SetDesync,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        BadSurface = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::BadSurface),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_subsurface",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_position",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "place_above",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "sibling",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "place_below",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "sibling",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_sync",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_desync",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "bad_surface",
+                        value: 0u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl WlSubsurface {
+        pub const INTERFACE: &'static str = "wl_subsurface";
+    }
+    /// Receives the requests of `wl_subsurface`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlSubsurfaceHandler {
+        fn destroy(&mut self, client: &Client, subsurface: &WlSubsurface) {}
+        fn set_position(
+            &mut self,
+            client: &Client,
+            subsurface: &WlSubsurface,
+            x: i32,
+            y: i32,
+        ) {}
+        fn place_above(
+            &mut self,
+            client: &Client,
+            subsurface: &WlSubsurface,
+            sibling: super::wl_surface::WlSurface,
+        ) {}
+        fn place_below(
+            &mut self,
+            client: &Client,
+            subsurface: &WlSubsurface,
+            sibling: super::wl_surface::WlSurface,
+        ) {}
+        fn set_sync(&mut self, client: &Client, subsurface: &WlSubsurface) {}
+        fn set_desync(&mut self, client: &Client, subsurface: &WlSubsurface) {}
+    }
+    impl<H: WlSubsurfaceHandler + ?Sized> Dispatch<H> for WlSubsurface {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Destroy => handler.destroy(client, self),
+                Request::SetPosition { x, y } => handler.set_position(client, self, x, y),
+                Request::PlaceAbove { sibling } => {
+                    handler.place_above(client, self, sibling)
+                }
+                Request::PlaceBelow { sibling } => {
+                    handler.place_below(client, self, sibling)
+                }
+                Request::SetSync => handler.set_sync(client, self),
+                Request::SetDesync => handler.set_desync(client, self),
+            }
+        }
+    }
+    impl Resource for WlSubsurface {
+        const INTERFACE: &'static str = "wl_subsurface";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Destroy,
+                    1u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        Request::SetPosition { x, y }
+                    }
+                    2u16 => {
+                        let sibling = Resource::from_id(parser.get_u32()?);
+                        Request::PlaceAbove { sibling }
+                    }
+                    3u16 => {
+                        let sibling = Resource::from_id(parser.get_u32()?);
+                        Request::PlaceBelow { sibling }
+                    }
+                    4u16 => Request::SetSync,
+                    5u16 => Request::SetDesync,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod wl_fixes {
+    use super::*;
+    pub struct WlFixes {
+        id: u32,
+    }
+    impl ::std::fmt::Display for WlFixes {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for WlFixes {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    impl<'a> Event {
+        pub fn opcode(&self) -> u16 {
+            match *self {}
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {}
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            None
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        DestroyRegistry { registry: super::wl_registry::WlRegistry },
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "wl_fixes",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "destroy_registry",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "registry",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::wl_registry::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl WlFixes {
+        pub const INTERFACE: &'static str = "wl_fixes";
+    }
+    /// Receives the requests of `wl_fixes`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait WlFixesHandler {
+        fn destroy(&mut self, client: &Client, fixes: &WlFixes) {}
+        fn destroy_registry(
+            &mut self,
+            client: &Client,
+            fixes: &WlFixes,
+            registry: super::wl_registry::WlRegistry,
+        ) {}
+    }
+    impl<H: WlFixesHandler + ?Sized> Dispatch<H> for WlFixes {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, client: &Client, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_request(client.reader(), event) else {
+                client.malformed(event);
+                return;
+            };
+            match parsed {
+                Request::Destroy => handler.destroy(client, self),
+                Request::DestroyRegistry { registry } => {
+                    handler.destroy_registry(client, self, registry)
+                }
+            }
+        }
+    }
+    impl Resource for WlFixes {
+        const INTERFACE: &'static str = "wl_fixes";
+        const REQUEST_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Request<'a> = Request;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_request<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Request<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Request::Destroy,
+                    1u16 => {
+                        let registry = Resource::from_id(parser.get_u32()?);
+                        Request::DestroyRegistry {
+                            registry,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &wl_display::INTERFACE_DESC,
+    &wl_registry::INTERFACE_DESC,
+    &wl_callback::INTERFACE_DESC,
+    &wl_compositor::INTERFACE_DESC,
+    &wl_shm_pool::INTERFACE_DESC,
+    &wl_shm::INTERFACE_DESC,
+    &wl_buffer::INTERFACE_DESC,
+    &wl_data_offer::INTERFACE_DESC,
+    &wl_data_source::INTERFACE_DESC,
+    &wl_data_device::INTERFACE_DESC,
+    &wl_data_device_manager::INTERFACE_DESC,
+    &wl_shell::INTERFACE_DESC,
+    &wl_shell_surface::INTERFACE_DESC,
+    &wl_surface::INTERFACE_DESC,
+    &wl_seat::INTERFACE_DESC,
+    &wl_pointer::INTERFACE_DESC,
+    &wl_keyboard::INTERFACE_DESC,
+    &wl_touch::INTERFACE_DESC,
+    &wl_output::INTERFACE_DESC,
+    &wl_region::INTERFACE_DESC,
+    &wl_subcompositor::INTERFACE_DESC,
+    &wl_subsurface::INTERFACE_DESC,
+    &wl_fixes::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &wl_display::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_display::WlDisplay as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_registry::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_registry::WlRegistry as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_callback::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_callback::WlCallback as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_compositor::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_compositor::WlCompositor as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_shm_pool::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_shm_pool::WlShmPool as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_shm::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_shm::WlShm as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_buffer::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_buffer::WlBuffer as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_data_offer::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_data_offer::WlDataOffer as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_data_source::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_data_source::WlDataSource as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_data_device::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_data_device::WlDataDevice as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_data_device_manager::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_data_device_manager::WlDataDeviceManager as Resource>::from_id(
+                    message.header.id,
+                )
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_shell::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_shell::WlShell as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_shell_surface::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_shell_surface::WlShellSurface as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_surface::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_surface::WlSurface as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_seat::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_seat::WlSeat as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_pointer::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_pointer::WlPointer as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_keyboard::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_keyboard::WlKeyboard as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_touch::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_touch::WlTouch as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_output::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_output::WlOutput as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_region::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_region::WlRegion as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_subcompositor::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_subcompositor::WlSubcompositor as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_subsurface::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_subsurface::WlSubsurface as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &wl_fixes::INTERFACE_DESC,
+        parse: |reader, message| {
+            <wl_fixes::WlFixes as Resource>::from_id(message.header.id)
+                .parse_request(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,1136 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/wlr-layer-shell-unstable-v1.xml, do not edit.
+
+pub mod zwlr_layer_shell_v1 {
+    use super::*;
+    pub struct ZwlrLayerShellV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwlrLayerShellV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwlrLayerShellV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        GetLayerSurface {
+            id: super::zwlr_layer_surface_v1::ZwlrLayerSurfaceV1,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+            output: Option<scratchway::wayland::wl_output::WlOutput>,
+            layer: WEnum<Layer>,
+            namespace: &'a str,
+        },
+        Destroy,
+    }
+    impl<'a> Request<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::GetLayerSurface { .. } => 0u16,
+                Self::Destroy => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::GetLayerSurface { id, surface, output, layer, namespace } => {
+                    let mut msg = Message::<78usize>::new(sender_id, 0u16);
+                    msg.write_u32(id.id());
+                    msg.write_u32(surface.id());
+                    msg.write_u32(output.map_or(0, |o| o.id()));
+                    msg.write_u32(layer.into());
+                    msg.write_string(namespace);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let id: super::zwlr_layer_surface_v1::ZwlrLayerSurfaceV1 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let surface = Object::from_id(parser.get_u32()?);
+                        let output = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Object::from_id);
+                        let layer = parser.get_u32()?.into();
+                        let namespace = parser.get_string()?;
+                        Self::GetLayerSurface {
+                            id,
+                            surface,
+                            output,
+                            layer,
+                            namespace,
+                        }
+                    }
+                    1u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        Role = 0u32,
+        InvalidLayer = 1u32,
+        AlreadyConstructed = 2u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Role),
+                1u32 => Ok(Self::InvalidLayer),
+                2u32 => Ok(Self::AlreadyConstructed),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Layer {
+        Background = 0u32,
+        Bottom = 1u32,
+        Top = 2u32,
+        Overlay = 3u32,
+    }
+    impl PartialEq<Layer> for u32 {
+        fn eq(&self, other: &Layer) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Layer {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Layer {}
+    impl From<Layer> for u32 {
+        fn from(value: Layer) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Layer {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Background),
+                1u32 => Ok(Self::Bottom),
+                2u32 => Ok(Self::Top),
+                3u32 => Ok(Self::Overlay),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwlr_layer_shell_v1",
+        version: 5u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "get_layer_surface",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::zwlr_layer_surface_v1::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&scratchway::wayland::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "layer",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("layer"),
+                    },
+                    interface::ArgDesc {
+                        name: "namespace",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 3u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "role",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_layer",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "already_constructed",
+                        value: 2u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "layer",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "background",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "overlay",
+                        value: 3u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwlrLayerShellV1 {
+        pub const INTERFACE: &'static str = "zwlr_layer_shell_v1";
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_layer_surface(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            surface: &scratchway::wayland::wl_surface::WlSurface,
+            output: Option<&scratchway::wayland::wl_output::WlOutput>,
+            layer: Layer,
+            namespace: &str,
+        ) -> super::zwlr_layer_surface_v1::ZwlrLayerSurfaceV1 {
+            let new_id = writer.new_id();
+            let new_zwlr_layer_surface_v1: super::zwlr_layer_surface_v1::ZwlrLayerSurfaceV1 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetLayerSurface {
+                id: Object::from_id(new_id),
+                surface: Object::from_id(surface.id()),
+                output: output.map(|o| Object::from_id(o.id())),
+                layer: WEnum::Value(layer),
+                namespace,
+            }
+                .encode(self.id, writer);
+            new_zwlr_layer_surface_v1
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwlr_layer_shell_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwlrLayerShellV1Handler {}
+    impl<H: ZwlrLayerShellV1Handler + ?Sized> Dispatch<H> for ZwlrLayerShellV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for ZwlrLayerShellV1 {
+        const INTERFACE: &'static str = "zwlr_layer_shell_v1";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod zwlr_layer_surface_v1 {
+    use super::*;
+    pub struct ZwlrLayerSurfaceV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwlrLayerSurfaceV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwlrLayerSurfaceV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        SetSize { width: u32, height: u32 },
+        SetAnchor { anchor: Anchor },
+        SetExclusiveZone { zone: i32 },
+        SetMargin { top: i32, right: i32, bottom: i32, left: i32 },
+        SetKeyboardInteractivity {
+            keyboard_interactivity: WEnum<KeyboardInteractivity>,
+        },
+        GetPopup { popup: crate::xdg_shell::xdg_popup::XdgPopup },
+        AckConfigure { serial: u32 },
+        Destroy,
+        SetLayer { layer: WEnum<super::zwlr_layer_shell_v1::Layer> },
+        SetExclusiveEdge { edge: Anchor },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::SetSize { .. } => 0u16,
+                Self::SetAnchor { .. } => 1u16,
+                Self::SetExclusiveZone { .. } => 2u16,
+                Self::SetMargin { .. } => 3u16,
+                Self::SetKeyboardInteractivity { .. } => 4u16,
+                Self::GetPopup { .. } => 5u16,
+                Self::AckConfigure { .. } => 6u16,
+                Self::Destroy => 7u16,
+                Self::SetLayer { .. } => 8u16,
+                Self::SetExclusiveEdge { .. } => 9u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::SetSize { width, height } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 0u16);
+                    msg.write_u32(width);
+                    msg.write_u32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetAnchor { anchor } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(anchor.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetExclusiveZone { zone } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 2u16);
+                    msg.write_i32(zone);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetMargin { top, right, bottom, left } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 3u16);
+                    msg.write_i32(top);
+                    msg.write_i32(right);
+                    msg.write_i32(bottom);
+                    msg.write_i32(left);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetKeyboardInteractivity { keyboard_interactivity } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 4u16);
+                    msg.write_u32(keyboard_interactivity.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::GetPopup { popup } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 5u16);
+                    msg.write_u32(popup.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::AckConfigure { serial } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 6u16);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 7u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetLayer { layer } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 8u16);
+                    msg.write_u32(layer.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetExclusiveEdge { edge } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 9u16);
+                    msg.write_u32(edge.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let width = parser.get_u32()?;
+                        let height = parser.get_u32()?;
+                        Self::SetSize { width, height }
+                    }
+                    1u16 => {
+                        let anchor = parser.get_u32()?.into();
+                        Self::SetAnchor { anchor }
+                    }
+                    2u16 => {
+                        let zone = parser.get_i32()?;
+                        Self::SetExclusiveZone { zone }
+                    }
+                    3u16 => {
+                        let top = parser.get_i32()?;
+                        let right = parser.get_i32()?;
+                        let bottom = parser.get_i32()?;
+                        let left = parser.get_i32()?;
+                        Self::SetMargin {
+                            top,
+                            right,
+                            bottom,
+                            left,
+                        }
+                    }
+                    4u16 => {
+                        let keyboard_interactivity = parser.get_u32()?.into();
+                        Self::SetKeyboardInteractivity {
+                            keyboard_interactivity,
+                        }
+                    }
+                    5u16 => {
+                        let popup = Object::from_id(parser.get_u32()?);
+                        Self::GetPopup { popup }
+                    }
+                    6u16 => {
+                        let serial = parser.get_u32()?;
+                        Self::AckConfigure { serial }
+                    }
+                    7u16 => Self::Destroy,
+                    8u16 => {
+                        let layer = parser.get_u32()?.into();
+                        Self::SetLayer { layer }
+                    }
+                    9u16 => {
+                        let edge = parser.get_u32()?.into();
+                        Self::SetExclusiveEdge { edge }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Configure { serial: u32, width: u32, height: u32 },
+        Closed,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum KeyboardInteractivity {
+        None = 0u32,
+        Exclusive = 1u32,
+        OnDemand = 2u32,
+    }
+    impl PartialEq<KeyboardInteractivity> for u32 {
+        fn eq(&self, other: &KeyboardInteractivity) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for KeyboardInteractivity {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for KeyboardInteractivity {}
+    impl From<KeyboardInteractivity> for u32 {
+        fn from(value: KeyboardInteractivity) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for KeyboardInteractivity {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::None),
+                1u32 => Ok(Self::Exclusive),
+                2u32 => Ok(Self::OnDemand),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidSurfaceState = 0u32,
+        InvalidSize = 1u32,
+        InvalidAnchor = 2u32,
+        InvalidKeyboardInteractivity = 3u32,
+        InvalidExclusiveEdge = 4u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidSurfaceState),
+                1u32 => Ok(Self::InvalidSize),
+                2u32 => Ok(Self::InvalidAnchor),
+                3u32 => Ok(Self::InvalidKeyboardInteractivity),
+                4u32 => Ok(Self::InvalidExclusiveEdge),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Anchor(u32);
+    impl Anchor {
+        pub const TOP: Self = Self(1u32);
+        pub const BOTTOM: Self = Self(2u32);
+        pub const LEFT: Self = Self(4u32);
+        pub const RIGHT: Self = Self(8u32);
+        const NAMES: &'static [(&'static str, u32)] = &[
+            ("TOP", 1u32),
+            ("BOTTOM", 2u32),
+            ("LEFT", 4u32),
+            ("RIGHT", 8u32),
+        ];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(15u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !15u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for Anchor {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for Anchor {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for Anchor {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for Anchor {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for Anchor {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<Anchor> for u32 {
+        fn from(value: Anchor) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for Anchor {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for Anchor {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("Anchor", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwlr_layer_surface_v1",
+        version: 5u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "set_size",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_anchor",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "anchor",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("anchor"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_exclusive_zone",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "zone",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_margin",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "top",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "right",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "bottom",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "left",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_keyboard_interactivity",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "keyboard_interactivity",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("keyboard_interactivity"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_popup",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "popup",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&crate::xdg_shell::xdg_popup::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "ack_configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_layer",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "layer",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("zwlr_layer_shell_v1.layer"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_exclusive_edge",
+                since: 5u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "edge",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("anchor"),
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "closed",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "keyboard_interactivity",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "exclusive",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "on_demand",
+                        value: 2u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_surface_state",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_size",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_anchor",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_keyboard_interactivity",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_exclusive_edge",
+                        value: 4u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "anchor",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "top",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "left",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "right",
+                        value: 8u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwlrLayerSurfaceV1 {
+        pub const INTERFACE: &'static str = "zwlr_layer_surface_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_size(&self, writer: &WaylandBuffer<Writer>, width: u32, height: u32) {
+            Request::SetSize { width, height }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_anchor(&self, writer: &WaylandBuffer<Writer>, anchor: Anchor) {
+            Request::SetAnchor { anchor }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_exclusive_zone(&self, writer: &WaylandBuffer<Writer>, zone: i32) {
+            Request::SetExclusiveZone { zone }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_margin(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            top: i32,
+            right: i32,
+            bottom: i32,
+            left: i32,
+        ) {
+            Request::SetMargin {
+                top,
+                right,
+                bottom,
+                left,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_keyboard_interactivity(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            keyboard_interactivity: KeyboardInteractivity,
+        ) {
+            Request::SetKeyboardInteractivity {
+                keyboard_interactivity: WEnum::Value(keyboard_interactivity),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn get_popup(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            popup: &crate::xdg_shell::xdg_popup::XdgPopup,
+        ) {
+            Request::GetPopup {
+                popup: Object::from_id(popup.id()),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn ack_configure(&self, writer: &WaylandBuffer<Writer>, serial: u32) {
+            Request::AckConfigure { serial }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_layer(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            layer: super::zwlr_layer_shell_v1::Layer,
+        ) {
+            Request::SetLayer {
+                layer: WEnum::Value(layer),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_exclusive_edge(&self, writer: &WaylandBuffer<Writer>, edge: Anchor) {
+            Request::SetExclusiveEdge { edge }.encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `zwlr_layer_surface_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwlrLayerSurfaceV1Handler {
+        fn configure(
+            &mut self,
+            conn: &Connection,
+            layer_surface: &ZwlrLayerSurfaceV1,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {}
+        fn closed(&mut self, conn: &Connection, layer_surface: &ZwlrLayerSurfaceV1) {}
+    }
+    impl<H: ZwlrLayerSurfaceV1Handler + ?Sized> Dispatch<H> for ZwlrLayerSurfaceV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Configure { serial, width, height } => {
+                    handler.configure(conn, self, serial, width, height)
+                }
+                Event::Closed => handler.closed(conn, self),
+            }
+        }
+    }
+    impl Object for ZwlrLayerSurfaceV1 {
+        const INTERFACE: &'static str = "zwlr_layer_surface_v1";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        let width = parser.get_u32()?;
+                        let height = parser.get_u32()?;
+                        Event::Configure {
+                            serial,
+                            width,
+                            height,
+                        }
+                    }
+                    1u16 => Event::Closed,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &zwlr_layer_shell_v1::INTERFACE_DESC,
+    &zwlr_layer_surface_v1::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &zwlr_layer_shell_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwlr_layer_shell_v1::ZwlrLayerShellV1 as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwlr_layer_surface_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwlr_layer_surface_v1::ZwlrLayerSurfaceV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,992 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/wlr-screencopy-unstable-v1.xml, do not edit.
+
+pub mod zwlr_screencopy_manager_v1 {
+    use super::*;
+    pub struct ZwlrScreencopyManagerV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwlrScreencopyManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwlrScreencopyManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        CaptureOutput {
+            frame: super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1,
+            overlay_cursor: i32,
+            output: scratchway::wayland::wl_output::WlOutput,
+        },
+        CaptureOutputRegion {
+            frame: super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1,
+            overlay_cursor: i32,
+            output: scratchway::wayland::wl_output::WlOutput,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        },
+        Destroy,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::CaptureOutput { .. } => 0u16,
+                Self::CaptureOutputRegion { .. } => 1u16,
+                Self::Destroy => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::CaptureOutput { frame, overlay_cursor, output } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 0u16);
+                    msg.write_u32(frame.id());
+                    msg.write_i32(overlay_cursor);
+                    msg.write_u32(output.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::CaptureOutputRegion {
+                    frame,
+                    overlay_cursor,
+                    output,
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    let mut msg = Message::<36usize>::new(sender_id, 1u16);
+                    msg.write_u32(frame.id());
+                    msg.write_i32(overlay_cursor);
+                    msg.write_u32(output.id());
+                    msg.write_i32(x);
+                    msg.write_i32(y);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 2u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let frame: super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let overlay_cursor = parser.get_i32()?;
+                        let output = Object::from_id(parser.get_u32()?);
+                        Self::CaptureOutput {
+                            frame,
+                            overlay_cursor,
+                            output,
+                        }
+                    }
+                    1u16 => {
+                        let frame: super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let overlay_cursor = parser.get_i32()?;
+                        let output = Object::from_id(parser.get_u32()?);
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::CaptureOutputRegion {
+                            frame,
+                            overlay_cursor,
+                            output,
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    2u16 => Self::Destroy,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwlr_screencopy_manager_v1",
+        version: 3u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "capture_output",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "frame",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(
+                            &super::zwlr_screencopy_frame_v1::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "overlay_cursor",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "capture_output_region",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "frame",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(
+                            &super::zwlr_screencopy_frame_v1::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "overlay_cursor",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl ZwlrScreencopyManagerV1 {
+        pub const INTERFACE: &'static str = "zwlr_screencopy_manager_v1";
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn capture_output(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            overlay_cursor: i32,
+            output: &scratchway::wayland::wl_output::WlOutput,
+        ) -> super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 {
+            let new_id = writer.new_id();
+            let new_zwlr_screencopy_frame_v1: super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::CaptureOutput {
+                frame: Object::from_id(new_id),
+                overlay_cursor,
+                output: Object::from_id(output.id()),
+            }
+                .encode(self.id, writer);
+            new_zwlr_screencopy_frame_v1
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn capture_output_region(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            overlay_cursor: i32,
+            output: &scratchway::wayland::wl_output::WlOutput,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) -> super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 {
+            let new_id = writer.new_id();
+            let new_zwlr_screencopy_frame_v1: super::zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::CaptureOutputRegion {
+                frame: Object::from_id(new_id),
+                overlay_cursor,
+                output: Object::from_id(output.id()),
+                x,
+                y,
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+            new_zwlr_screencopy_frame_v1
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+    }
+    /// Receives the events of `zwlr_screencopy_manager_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwlrScreencopyManagerV1Handler {}
+    impl<H: ZwlrScreencopyManagerV1Handler + ?Sized> Dispatch<H>
+    for ZwlrScreencopyManagerV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for ZwlrScreencopyManagerV1 {
+        const INTERFACE: &'static str = "zwlr_screencopy_manager_v1";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod zwlr_screencopy_frame_v1 {
+    use super::*;
+    pub struct ZwlrScreencopyFrameV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZwlrScreencopyFrameV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZwlrScreencopyFrameV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Copy { buffer: scratchway::wayland::wl_buffer::WlBuffer },
+        Destroy,
+        CopyWithDamage { buffer: scratchway::wayland::wl_buffer::WlBuffer },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Copy { .. } => 0u16,
+                Self::Destroy => 1u16,
+                Self::CopyWithDamage { .. } => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Copy { buffer } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 0u16);
+                    msg.write_u32(buffer.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 1u16);
+                    writer.write_request(msg.data());
+                }
+                Self::CopyWithDamage { buffer } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 2u16);
+                    msg.write_u32(buffer.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let buffer = Object::from_id(parser.get_u32()?);
+                        Self::Copy { buffer }
+                    }
+                    1u16 => Self::Destroy,
+                    2u16 => {
+                        let buffer = Object::from_id(parser.get_u32()?);
+                        Self::CopyWithDamage { buffer }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Buffer {
+            format: WEnum<scratchway::wayland::wl_shm::Format>,
+            width: u32,
+            height: u32,
+            stride: u32,
+        },
+        Flags { flags: Flags },
+        Ready { tv_sec_hi: u32, tv_sec_lo: u32, tv_nsec: u32 },
+        Failed,
+        Damage { x: u32, y: u32, width: u32, height: u32 },
+        LinuxDmabuf { format: u32, width: u32, height: u32 },
+        BufferDone,
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        AlreadyUsed = 0u32,
+        InvalidBuffer = 1u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::AlreadyUsed),
+                1u32 => Ok(Self::InvalidBuffer),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct Flags(u32);
+    impl Flags {
+        pub const Y_INVERT: Self = Self(1u32);
+        const NAMES: &'static [(&'static str, u32)] = &[("Y_INVERT", 1u32)];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(1u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !1u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for Flags {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for Flags {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for Flags {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for Flags {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for Flags {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<Flags> for u32 {
+        fn from(value: Flags) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for Flags {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for Flags {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("Flags", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zwlr_screencopy_frame_v1",
+        version: 3u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "copy",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "buffer",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_buffer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "copy_with_damage",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "buffer",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_buffer::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "buffer",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "format",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("wl_shm.format"),
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "stride",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "flags",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "flags",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("flags"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "ready",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "tv_sec_hi",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "tv_sec_lo",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "tv_nsec",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "failed",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "damage",
+                since: 2u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "linux_dmabuf",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "format",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "buffer_done",
+                since: 3u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "already_used",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_buffer",
+                        value: 1u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "flags",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "y_invert",
+                        value: 1u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZwlrScreencopyFrameV1 {
+        pub const INTERFACE: &'static str = "zwlr_screencopy_frame_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn copy(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            buffer: &scratchway::wayland::wl_buffer::WlBuffer,
+        ) {
+            Request::Copy {
+                buffer: Object::from_id(buffer.id()),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn copy_with_damage(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            buffer: &scratchway::wayland::wl_buffer::WlBuffer,
+        ) {
+            Request::CopyWithDamage {
+                buffer: Object::from_id(buffer.id()),
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `zwlr_screencopy_frame_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZwlrScreencopyFrameV1Handler {
+        fn buffer(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+            format: WEnum<scratchway::wayland::wl_shm::Format>,
+            width: u32,
+            height: u32,
+            stride: u32,
+        ) {}
+        fn flags(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+            flags: Flags,
+        ) {}
+        fn ready(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {}
+        fn failed(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+        ) {}
+        fn damage(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+        ) {}
+        fn linux_dmabuf(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+            format: u32,
+            width: u32,
+            height: u32,
+        ) {}
+        fn buffer_done(
+            &mut self,
+            conn: &Connection,
+            screencopy_frame: &ZwlrScreencopyFrameV1,
+        ) {}
+    }
+    impl<H: ZwlrScreencopyFrameV1Handler + ?Sized> Dispatch<H>
+    for ZwlrScreencopyFrameV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Buffer { format, width, height, stride } => {
+                    handler.buffer(conn, self, format, width, height, stride)
+                }
+                Event::Flags { flags } => handler.flags(conn, self, flags),
+                Event::Ready { tv_sec_hi, tv_sec_lo, tv_nsec } => {
+                    handler.ready(conn, self, tv_sec_hi, tv_sec_lo, tv_nsec)
+                }
+                Event::Failed => handler.failed(conn, self),
+                Event::Damage { x, y, width, height } => {
+                    handler.damage(conn, self, x, y, width, height)
+                }
+                Event::LinuxDmabuf { format, width, height } => {
+                    handler.linux_dmabuf(conn, self, format, width, height)
+                }
+                Event::BufferDone => handler.buffer_done(conn, self),
+            }
+        }
+    }
+    impl Object for ZwlrScreencopyFrameV1 {
+        const INTERFACE: &'static str = "zwlr_screencopy_frame_v1";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let format = parser.get_u32()?.into();
+                        let width = parser.get_u32()?;
+                        let height = parser.get_u32()?;
+                        let stride = parser.get_u32()?;
+                        Event::Buffer {
+                            format,
+                            width,
+                            height,
+                            stride,
+                        }
+                    }
+                    1u16 => {
+                        let flags = parser.get_u32()?.into();
+                        Event::Flags { flags }
+                    }
+                    2u16 => {
+                        let tv_sec_hi = parser.get_u32()?;
+                        let tv_sec_lo = parser.get_u32()?;
+                        let tv_nsec = parser.get_u32()?;
+                        Event::Ready {
+                            tv_sec_hi,
+                            tv_sec_lo,
+                            tv_nsec,
+                        }
+                    }
+                    3u16 => Event::Failed,
+                    4u16 => {
+                        let x = parser.get_u32()?;
+                        let y = parser.get_u32()?;
+                        let width = parser.get_u32()?;
+                        let height = parser.get_u32()?;
+                        Event::Damage {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    5u16 => {
+                        let format = parser.get_u32()?;
+                        let width = parser.get_u32()?;
+                        let height = parser.get_u32()?;
+                        Event::LinuxDmabuf {
+                            format,
+                            width,
+                            height,
+                        }
+                    }
+                    6u16 => Event::BufferDone,
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &zwlr_screencopy_manager_v1::INTERFACE_DESC,
+    &zwlr_screencopy_frame_v1::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &zwlr_screencopy_manager_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwlr_screencopy_manager_v1::ZwlrScreencopyManagerV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zwlr_screencopy_frame_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,512 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/xdg-decoration-unstable-v1.xml, do not edit.
+
+pub mod zxdg_decoration_manager_v1 {
+    use super::*;
+    pub struct ZxdgDecorationManagerV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZxdgDecorationManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZxdgDecorationManagerV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        GetToplevelDecoration {
+            id: super::zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1,
+            toplevel: crate::xdg_shell::xdg_toplevel::XdgToplevel,
+        },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::GetToplevelDecoration { .. } => 1u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::GetToplevelDecoration { id, toplevel } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_u32(id.id());
+                    msg.write_u32(toplevel.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let id: super::zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1 = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let toplevel = Object::from_id(parser.get_u32()?);
+                        Self::GetToplevelDecoration {
+                            id,
+                            toplevel,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zxdg_decoration_manager_v1",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "get_toplevel_decoration",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(
+                            &super::zxdg_toplevel_decoration_v1::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "toplevel",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&crate::xdg_shell::xdg_toplevel::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    impl ZxdgDecorationManagerV1 {
+        pub const INTERFACE: &'static str = "zxdg_decoration_manager_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_toplevel_decoration(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            toplevel: &crate::xdg_shell::xdg_toplevel::XdgToplevel,
+        ) -> super::zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1 {
+            let new_id = writer.new_id();
+            let new_zxdg_toplevel_decoration_v1: super::zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1 = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetToplevelDecoration {
+                id: Object::from_id(new_id),
+                toplevel: Object::from_id(toplevel.id()),
+            }
+                .encode(self.id, writer);
+            new_zxdg_toplevel_decoration_v1
+        }
+    }
+    /// Receives the events of `zxdg_decoration_manager_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZxdgDecorationManagerV1Handler {}
+    impl<H: ZxdgDecorationManagerV1Handler + ?Sized> Dispatch<H>
+    for ZxdgDecorationManagerV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for ZxdgDecorationManagerV1 {
+        const INTERFACE: &'static str = "zxdg_decoration_manager_v1";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod zxdg_toplevel_decoration_v1 {
+    use super::*;
+    pub struct ZxdgToplevelDecorationV1 {
+        id: u32,
+    }
+    impl ::std::fmt::Display for ZxdgToplevelDecorationV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for ZxdgToplevelDecorationV1 {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        SetMode { mode: WEnum<Mode> },
+        UnsetMode,
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::SetMode { .. } => 1u16,
+                Self::UnsetMode => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetMode { mode } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(mode.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::UnsetMode => {
+                    let msg = Message::<8usize>::new(sender_id, 2u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let mode = parser.get_u32()?.into();
+                        Self::SetMode { mode }
+                    }
+                    2u16 => Self::UnsetMode,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Configure { mode: WEnum<Mode> },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        UnconfiguredBuffer = 0u32,
+        AlreadyConstructed = 1u32,
+        Orphaned = 2u32,
+        InvalidMode = 3u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::UnconfiguredBuffer),
+                1u32 => Ok(Self::AlreadyConstructed),
+                2u32 => Ok(Self::Orphaned),
+                3u32 => Ok(Self::InvalidMode),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Mode {
+        ClientSide = 1u32,
+        ServerSide = 2u32,
+    }
+    impl PartialEq<Mode> for u32 {
+        fn eq(&self, other: &Mode) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Mode {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Mode {}
+    impl From<Mode> for u32 {
+        fn from(value: Mode) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Mode {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::ClientSide),
+                2u32 => Ok(Self::ServerSide),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "zxdg_toplevel_decoration_v1",
+        version: 1u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_mode",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mode",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("mode"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "unset_mode",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "mode",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("mode"),
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "unconfigured_buffer",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "already_constructed",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "orphaned",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_mode",
+                        value: 3u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "mode",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "client_side",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "server_side",
+                        value: 2u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl ZxdgToplevelDecorationV1 {
+        pub const INTERFACE: &'static str = "zxdg_toplevel_decoration_v1";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_mode(&self, writer: &WaylandBuffer<Writer>, mode: Mode) {
+            Request::SetMode {
+                mode: WEnum::Value(mode),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn unset_mode(&self, writer: &WaylandBuffer<Writer>) {
+            Request::UnsetMode.encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `zxdg_toplevel_decoration_v1`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait ZxdgToplevelDecorationV1Handler {
+        fn configure(
+            &mut self,
+            conn: &Connection,
+            toplevel_decoration: &ZxdgToplevelDecorationV1,
+            mode: WEnum<Mode>,
+        ) {}
+    }
+    impl<H: ZxdgToplevelDecorationV1Handler + ?Sized> Dispatch<H>
+    for ZxdgToplevelDecorationV1 {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Configure { mode } => handler.configure(conn, self, mode),
+            }
+        }
+    }
+    impl Object for ZxdgToplevelDecorationV1 {
+        const INTERFACE: &'static str = "zxdg_toplevel_decoration_v1";
+        const EVENT_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let mode = parser.get_u32()?.into();
+                        Event::Configure { mode }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &zxdg_decoration_manager_v1::INTERFACE_DESC,
+    &zxdg_toplevel_decoration_v1::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &zxdg_decoration_manager_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zxdg_decoration_manager_v1::ZxdgDecorationManagerV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &zxdg_toplevel_decoration_v1::INTERFACE_DESC,
+        parse: |reader, message| {
+            <zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1 as Object>::from_id(
+                    message.header.id,
+                )
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
@@ -0,0 +1,3107 @@
+// Generated by scr-scanner from ../scr_protocols/protocols/xdg-shell.xml, do not edit.
+
+pub mod xdg_wm_base {
+    use super::*;
+    pub struct XdgWmBase {
+        id: u32,
+    }
+    impl ::std::fmt::Display for XdgWmBase {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for XdgWmBase {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        CreatePositioner { id: super::xdg_positioner::XdgPositioner },
+        GetXdgSurface {
+            id: super::xdg_surface::XdgSurface,
+            surface: scratchway::wayland::wl_surface::WlSurface,
+        },
+        Pong { serial: u32 },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::CreatePositioner { .. } => 1u16,
+                Self::GetXdgSurface { .. } => 2u16,
+                Self::Pong { .. } => 3u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::CreatePositioner { id } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(id.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::GetXdgSurface { id, surface } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 2u16);
+                    msg.write_u32(id.id());
+                    msg.write_u32(surface.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Pong { serial } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 3u16);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let id: super::xdg_positioner::XdgPositioner = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        Self::CreatePositioner { id }
+                    }
+                    2u16 => {
+                        let id: super::xdg_surface::XdgSurface = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let surface = Object::from_id(parser.get_u32()?);
+                        Self::GetXdgSurface { id, surface }
+                    }
+                    3u16 => {
+                        let serial = parser.get_u32()?;
+                        Self::Pong { serial }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Ping { serial: u32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        Role = 0u32,
+        DefunctSurfaces = 1u32,
+        NotTheTopmostPopup = 2u32,
+        InvalidPopupParent = 3u32,
+        InvalidSurfaceState = 4u32,
+        InvalidPositioner = 5u32,
+        Unresponsive = 6u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::Role),
+                1u32 => Ok(Self::DefunctSurfaces),
+                2u32 => Ok(Self::NotTheTopmostPopup),
+                3u32 => Ok(Self::InvalidPopupParent),
+                4u32 => Ok(Self::InvalidSurfaceState),
+                5u32 => Ok(Self::InvalidPositioner),
+                6u32 => Ok(Self::Unresponsive),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "xdg_wm_base",
+        version: 7u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "create_positioner",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::xdg_positioner::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_xdg_surface",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::xdg_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "surface",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(
+                            &scratchway::wayland::wl_surface::INTERFACE_DESC,
+                        ),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "pong",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "ping",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "role",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "defunct_surfaces",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "not_the_topmost_popup",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_popup_parent",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_surface_state",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_positioner",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "unresponsive",
+                        value: 6u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl XdgWmBase {
+        pub const INTERFACE: &'static str = "xdg_wm_base";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn create_positioner(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+        ) -> super::xdg_positioner::XdgPositioner {
+            let new_id = writer.new_id();
+            let new_xdg_positioner: super::xdg_positioner::XdgPositioner = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::CreatePositioner {
+                id: Object::from_id(new_id),
+            }
+                .encode(self.id, writer);
+            new_xdg_positioner
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_xdg_surface(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            surface: &scratchway::wayland::wl_surface::WlSurface,
+        ) -> super::xdg_surface::XdgSurface {
+            let new_id = writer.new_id();
+            let new_xdg_surface: super::xdg_surface::XdgSurface = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetXdgSurface {
+                id: Object::from_id(new_id),
+                surface: Object::from_id(surface.id()),
+            }
+                .encode(self.id, writer);
+            new_xdg_surface
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn pong(&self, writer: &WaylandBuffer<Writer>, serial: u32) {
+            Request::Pong { serial }.encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `xdg_wm_base`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait XdgWmBaseHandler {
+        fn ping(&mut self, conn: &Connection, wm_base: &XdgWmBase, serial: u32) {}
+    }
+    impl<H: XdgWmBaseHandler + ?Sized> Dispatch<H> for XdgWmBase {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Ping { serial } => handler.ping(conn, self, serial),
+            }
+        }
+    }
+    impl Object for XdgWmBase {
+        const INTERFACE: &'static str = "xdg_wm_base";
+        const EVENT_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        Event::Ping { serial }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod xdg_positioner {
+    use super::*;
+    pub struct XdgPositioner {
+        id: u32,
+    }
+    impl ::std::fmt::Display for XdgPositioner {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for XdgPositioner {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        SetSize { width: i32, height: i32 },
+        SetAnchorRect { x: i32, y: i32, width: i32, height: i32 },
+        SetAnchor { anchor: WEnum<Anchor> },
+        SetGravity { gravity: WEnum<Gravity> },
+        SetConstraintAdjustment { constraint_adjustment: ConstraintAdjustment },
+        SetOffset { x: i32, y: i32 },
+        SetReactive,
+        SetParentSize { parent_width: i32, parent_height: i32 },
+        SetParentConfigure { serial: u32 },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::SetSize { .. } => 1u16,
+                Self::SetAnchorRect { .. } => 2u16,
+                Self::SetAnchor { .. } => 3u16,
+                Self::SetGravity { .. } => 4u16,
+                Self::SetConstraintAdjustment { .. } => 5u16,
+                Self::SetOffset { .. } => 6u16,
+                Self::SetReactive => 7u16,
+                Self::SetParentSize { .. } => 8u16,
+                Self::SetParentConfigure { .. } => 9u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetSize { width, height } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetAnchorRect { x, y, width, height } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 2u16);
+                    msg.write_i32(x);
+                    msg.write_i32(y);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetAnchor { anchor } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 3u16);
+                    msg.write_u32(anchor.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetGravity { gravity } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 4u16);
+                    msg.write_u32(gravity.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetConstraintAdjustment { constraint_adjustment } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 5u16);
+                    msg.write_u32(constraint_adjustment.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetOffset { x, y } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 6u16);
+                    msg.write_i32(x);
+                    msg.write_i32(y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetReactive => {
+                    let msg = Message::<8usize>::new(sender_id, 7u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetParentSize { parent_width, parent_height } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 8u16);
+                    msg.write_i32(parent_width);
+                    msg.write_i32(parent_height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetParentConfigure { serial } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 9u16);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::SetSize { width, height }
+                    }
+                    2u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::SetAnchorRect {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    3u16 => {
+                        let anchor = parser.get_u32()?.into();
+                        Self::SetAnchor { anchor }
+                    }
+                    4u16 => {
+                        let gravity = parser.get_u32()?.into();
+                        Self::SetGravity { gravity }
+                    }
+                    5u16 => {
+                        let constraint_adjustment = parser.get_u32()?.into();
+                        Self::SetConstraintAdjustment {
+                            constraint_adjustment,
+                        }
+                    }
+                    6u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        Self::SetOffset { x, y }
+                    }
+                    7u16 => Self::SetReactive,
+                    8u16 => {
+                        let parent_width = parser.get_i32()?;
+                        let parent_height = parser.get_i32()?;
+                        Self::SetParentSize {
+                            parent_width,
+                            parent_height,
+                        }
+                    }
+                    9u16 => {
+                        let serial = parser.get_u32()?;
+                        Self::SetParentConfigure { serial }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {}
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidInput = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidInput),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Anchor {
+        None = 0u32,
+        Top = 1u32,
+        Bottom = 2u32,
+        Left = 3u32,
+        Right = 4u32,
+        TopLeft = 5u32,
+        BottomLeft = 6u32,
+        TopRight = 7u32,
+        BottomRight = 8u32,
+    }
+    impl PartialEq<Anchor> for u32 {
+        fn eq(&self, other: &Anchor) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Anchor {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Anchor {}
+    impl From<Anchor> for u32 {
+        fn from(value: Anchor) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Anchor {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::None),
+                1u32 => Ok(Self::Top),
+                2u32 => Ok(Self::Bottom),
+                3u32 => Ok(Self::Left),
+                4u32 => Ok(Self::Right),
+                5u32 => Ok(Self::TopLeft),
+                6u32 => Ok(Self::BottomLeft),
+                7u32 => Ok(Self::TopRight),
+                8u32 => Ok(Self::BottomRight),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Gravity {
+        None = 0u32,
+        Top = 1u32,
+        Bottom = 2u32,
+        Left = 3u32,
+        Right = 4u32,
+        TopLeft = 5u32,
+        BottomLeft = 6u32,
+        TopRight = 7u32,
+        BottomRight = 8u32,
+    }
+    impl PartialEq<Gravity> for u32 {
+        fn eq(&self, other: &Gravity) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Gravity {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Gravity {}
+    impl From<Gravity> for u32 {
+        fn from(value: Gravity) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Gravity {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::None),
+                1u32 => Ok(Self::Top),
+                2u32 => Ok(Self::Bottom),
+                3u32 => Ok(Self::Left),
+                4u32 => Ok(Self::Right),
+                5u32 => Ok(Self::TopLeft),
+                6u32 => Ok(Self::BottomLeft),
+                7u32 => Ok(Self::TopRight),
+                8u32 => Ok(Self::BottomRight),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(transparent)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct ConstraintAdjustment(u32);
+    impl ConstraintAdjustment {
+        pub const NONE: Self = Self(0u32);
+        pub const SLIDE_X: Self = Self(1u32);
+        pub const SLIDE_Y: Self = Self(2u32);
+        pub const FLIP_X: Self = Self(4u32);
+        pub const FLIP_Y: Self = Self(8u32);
+        pub const RESIZE_X: Self = Self(16u32);
+        pub const RESIZE_Y: Self = Self(32u32);
+        const NAMES: &'static [(&'static str, u32)] = &[
+            ("NONE", 0u32),
+            ("SLIDE_X", 1u32),
+            ("SLIDE_Y", 2u32),
+            ("FLIP_X", 4u32),
+            ("FLIP_Y", 8u32),
+            ("RESIZE_X", 16u32),
+            ("RESIZE_Y", 32u32),
+        ];
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        pub const fn all() -> Self {
+            Self(63u32)
+        }
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+        /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+        pub const fn from_bits(bits: u32) -> Option<Self> {
+            if bits & !63u32 == 0 { Some(Self(bits)) } else { None }
+        }
+        pub const fn from_bits_retain(bits: u32) -> Self {
+            Self(bits)
+        }
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl ::std::ops::BitOr for ConstraintAdjustment {
+        type Output = Self;
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+    impl ::std::ops::BitOrAssign for ConstraintAdjustment {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0;
+        }
+    }
+    impl ::std::ops::BitAnd for ConstraintAdjustment {
+        type Output = Self;
+        fn bitand(self, rhs: Self) -> Self {
+            Self(self.0 & rhs.0)
+        }
+    }
+    impl ::std::ops::BitAndAssign for ConstraintAdjustment {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0;
+        }
+    }
+    impl ::std::ops::Sub for ConstraintAdjustment {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 & !rhs.0)
+        }
+    }
+    impl From<ConstraintAdjustment> for u32 {
+        fn from(value: ConstraintAdjustment) -> u32 {
+            value.0
+        }
+    }
+    impl From<u32> for ConstraintAdjustment {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::fmt::Debug for ConstraintAdjustment {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_str(concat!("ConstraintAdjustment", "("))?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, bits) in Self::NAMES {
+                let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                if named {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !bits;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_fmt(format_args!("{:#x}", rest))?;
+            }
+            f.write_str(")")
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "xdg_positioner",
+        version: 7u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_size",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_anchor_rect",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_anchor",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "anchor",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("anchor"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_gravity",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "gravity",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("gravity"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_constraint_adjustment",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "constraint_adjustment",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("constraint_adjustment"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_offset",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_reactive",
+                since: 3u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_parent_size",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "parent_width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "parent_height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_parent_configure",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_input",
+                        value: 0u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "anchor",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "left",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "right",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_left",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_left",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_right",
+                        value: 7u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_right",
+                        value: 8u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "gravity",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "left",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "right",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_left",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_left",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_right",
+                        value: 7u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_right",
+                        value: 8u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "constraint_adjustment",
+                bitfield: true,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "slide_x",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "slide_y",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "flip_x",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "flip_y",
+                        value: 8u32,
+                    },
+                    interface::EnumEntry {
+                        name: "resize_x",
+                        value: 16u32,
+                    },
+                    interface::EnumEntry {
+                        name: "resize_y",
+                        value: 32u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl XdgPositioner {
+        pub const INTERFACE: &'static str = "xdg_positioner";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_size(&self, writer: &WaylandBuffer<Writer>, width: i32, height: i32) {
+            Request::SetSize { width, height }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_anchor_rect(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            Request::SetAnchorRect {
+                x,
+                y,
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_anchor(&self, writer: &WaylandBuffer<Writer>, anchor: Anchor) {
+            Request::SetAnchor {
+                anchor: WEnum::Value(anchor),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_gravity(&self, writer: &WaylandBuffer<Writer>, gravity: Gravity) {
+            Request::SetGravity {
+                gravity: WEnum::Value(gravity),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_constraint_adjustment(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            constraint_adjustment: ConstraintAdjustment,
+        ) {
+            Request::SetConstraintAdjustment {
+                constraint_adjustment,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_offset(&self, writer: &WaylandBuffer<Writer>, x: i32, y: i32) {
+            Request::SetOffset { x, y }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_reactive(&self, writer: &WaylandBuffer<Writer>) {
+            Request::SetReactive.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_parent_size(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            parent_width: i32,
+            parent_height: i32,
+        ) {
+            Request::SetParentSize {
+                parent_width,
+                parent_height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_parent_configure(&self, writer: &WaylandBuffer<Writer>, serial: u32) {
+            Request::SetParentConfigure {
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `xdg_positioner`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait XdgPositionerHandler {}
+    impl<H: XdgPositionerHandler + ?Sized> Dispatch<H> for XdgPositioner {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            conn.malformed(event);
+        }
+    }
+    impl Object for XdgPositioner {
+        const INTERFACE: &'static str = "xdg_positioner";
+        const EVENT_FDS: &'static [u8] = &[];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            None
+        }
+    }
+}
+pub mod xdg_surface {
+    use super::*;
+    pub struct XdgSurface {
+        id: u32,
+    }
+    impl ::std::fmt::Display for XdgSurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for XdgSurface {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        GetToplevel { id: super::xdg_toplevel::XdgToplevel },
+        GetPopup {
+            id: super::xdg_popup::XdgPopup,
+            parent: Option<super::xdg_surface::XdgSurface>,
+            positioner: super::xdg_positioner::XdgPositioner,
+        },
+        SetWindowGeometry { x: i32, y: i32, width: i32, height: i32 },
+        AckConfigure { serial: u32 },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::GetToplevel { .. } => 1u16,
+                Self::GetPopup { .. } => 2u16,
+                Self::SetWindowGeometry { .. } => 3u16,
+                Self::AckConfigure { .. } => 4u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::GetToplevel { id } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(id.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::GetPopup { id, parent, positioner } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 2u16);
+                    msg.write_u32(id.id());
+                    msg.write_u32(parent.map_or(0, |o| o.id()));
+                    msg.write_u32(positioner.id());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetWindowGeometry { x, y, width, height } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 3u16);
+                    msg.write_i32(x);
+                    msg.write_i32(y);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::AckConfigure { serial } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 4u16);
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let id: super::xdg_toplevel::XdgToplevel = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        Self::GetToplevel { id }
+                    }
+                    2u16 => {
+                        let id: super::xdg_popup::XdgPopup = reader
+                            .new_object(
+                                reader.check_new_id(parser.get_u32()?)?,
+                                reader.object_version(event.header.id),
+                            );
+                        let parent = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Object::from_id);
+                        let positioner = Object::from_id(parser.get_u32()?);
+                        Self::GetPopup {
+                            id,
+                            parent,
+                            positioner,
+                        }
+                    }
+                    3u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::SetWindowGeometry {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    4u16 => {
+                        let serial = parser.get_u32()?;
+                        Self::AckConfigure { serial }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Configure { serial: u32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        NotConstructed = 1u32,
+        AlreadyConstructed = 2u32,
+        UnconfiguredBuffer = 3u32,
+        InvalidSerial = 4u32,
+        InvalidSize = 5u32,
+        DefunctRoleObject = 6u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::NotConstructed),
+                2u32 => Ok(Self::AlreadyConstructed),
+                3u32 => Ok(Self::UnconfiguredBuffer),
+                4u32 => Ok(Self::InvalidSerial),
+                5u32 => Ok(Self::InvalidSize),
+                6u32 => Ok(Self::DefunctRoleObject),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "xdg_surface",
+        version: 7u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "get_toplevel",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::xdg_toplevel::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "get_popup",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "id",
+                        ty: interface::ArgType::NewId,
+                        nullable: false,
+                        interface: Some(&super::xdg_popup::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "parent",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::xdg_surface::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "positioner",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::xdg_positioner::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_window_geometry",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "ack_configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "not_constructed",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "already_constructed",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "unconfigured_buffer",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_serial",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_size",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "defunct_role_object",
+                        value: 6u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl XdgSurface {
+        pub const INTERFACE: &'static str = "xdg_surface";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_toplevel(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+        ) -> super::xdg_toplevel::XdgToplevel {
+            let new_id = writer.new_id();
+            let new_xdg_toplevel: super::xdg_toplevel::XdgToplevel = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetToplevel {
+                id: Object::from_id(new_id),
+            }
+                .encode(self.id, writer);
+            new_xdg_toplevel
+        }
+        #[allow(clippy::too_many_arguments)]
+        #[track_caller]
+        pub fn get_popup(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            parent: Option<&super::xdg_surface::XdgSurface>,
+            positioner: &super::xdg_positioner::XdgPositioner,
+        ) -> super::xdg_popup::XdgPopup {
+            let new_id = writer.new_id();
+            let new_xdg_popup: super::xdg_popup::XdgPopup = writer
+                .new_object(new_id, writer.object_version(self.id));
+            Request::GetPopup {
+                id: Object::from_id(new_id),
+                parent: parent.map(|o| Object::from_id(o.id())),
+                positioner: Object::from_id(positioner.id()),
+            }
+                .encode(self.id, writer);
+            new_xdg_popup
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_window_geometry(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            Request::SetWindowGeometry {
+                x,
+                y,
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn ack_configure(&self, writer: &WaylandBuffer<Writer>, serial: u32) {
+            Request::AckConfigure { serial }.encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `xdg_surface`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait XdgSurfaceHandler {
+        fn configure(&mut self, conn: &Connection, surface: &XdgSurface, serial: u32) {}
+    }
+    impl<H: XdgSurfaceHandler + ?Sized> Dispatch<H> for XdgSurface {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Configure { serial } => handler.configure(conn, self, serial),
+            }
+        }
+    }
+    impl Object for XdgSurface {
+        const INTERFACE: &'static str = "xdg_surface";
+        const EVENT_FDS: &'static [u8] = &[0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let serial = parser.get_u32()?;
+                        Event::Configure { serial }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod xdg_toplevel {
+    use super::*;
+    pub struct XdgToplevel {
+        id: u32,
+    }
+    impl ::std::fmt::Display for XdgToplevel {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for XdgToplevel {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request<'a> {
+        Destroy,
+        SetParent { parent: Option<super::xdg_toplevel::XdgToplevel> },
+        SetTitle { title: &'a str },
+        SetAppId { app_id: &'a str },
+        ShowWindowMenu {
+            seat: scratchway::wayland::wl_seat::WlSeat,
+            serial: u32,
+            x: i32,
+            y: i32,
+        },
+        Move { seat: scratchway::wayland::wl_seat::WlSeat, serial: u32 },
+        Resize {
+            seat: scratchway::wayland::wl_seat::WlSeat,
+            serial: u32,
+            edges: WEnum<ResizeEdge>,
+        },
+        SetMaxSize { width: i32, height: i32 },
+        SetMinSize { width: i32, height: i32 },
+        SetMaximized,
+        UnsetMaximized,
+        SetFullscreen { output: Option<scratchway::wayland::wl_output::WlOutput> },
+        UnsetFullscreen,
+        SetMinimized,
+    }
+    impl<'a> Request<'a> {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::SetParent { .. } => 1u16,
+                Self::SetTitle { .. } => 2u16,
+                Self::SetAppId { .. } => 3u16,
+                Self::ShowWindowMenu { .. } => 4u16,
+                Self::Move { .. } => 5u16,
+                Self::Resize { .. } => 6u16,
+                Self::SetMaxSize { .. } => 7u16,
+                Self::SetMinSize { .. } => 8u16,
+                Self::SetMaximized => 9u16,
+                Self::UnsetMaximized => 10u16,
+                Self::SetFullscreen { .. } => 11u16,
+                Self::UnsetFullscreen => 12u16,
+                Self::SetMinimized => 13u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetParent { parent } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 1u16);
+                    msg.write_u32(parent.map_or(0, |o| o.id()));
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetTitle { title } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 2u16);
+                    msg.write_string(title);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetAppId { app_id } => {
+                    let mut msg = Message::<62usize>::new(sender_id, 3u16);
+                    msg.write_string(app_id);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::ShowWindowMenu { seat, serial, x, y } => {
+                    let mut msg = Message::<24usize>::new(sender_id, 4u16);
+                    msg.write_u32(seat.id());
+                    msg.write_u32(serial);
+                    msg.write_i32(x);
+                    msg.write_i32(y);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Move { seat, serial } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 5u16);
+                    msg.write_u32(seat.id());
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Resize { seat, serial, edges } => {
+                    let mut msg = Message::<20usize>::new(sender_id, 6u16);
+                    msg.write_u32(seat.id());
+                    msg.write_u32(serial);
+                    msg.write_u32(edges.into());
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetMaxSize { width, height } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 7u16);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetMinSize { width, height } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 8u16);
+                    msg.write_i32(width);
+                    msg.write_i32(height);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::SetMaximized => {
+                    let msg = Message::<8usize>::new(sender_id, 9u16);
+                    writer.write_request(msg.data());
+                }
+                Self::UnsetMaximized => {
+                    let msg = Message::<8usize>::new(sender_id, 10u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetFullscreen { output } => {
+                    let mut msg = Message::<12usize>::new(sender_id, 11u16);
+                    msg.write_u32(output.map_or(0, |o| o.id()));
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::UnsetFullscreen => {
+                    let msg = Message::<8usize>::new(sender_id, 12u16);
+                    writer.write_request(msg.data());
+                }
+                Self::SetMinimized => {
+                    let msg = Message::<8usize>::new(sender_id, 13u16);
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let parent = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Object::from_id);
+                        Self::SetParent { parent }
+                    }
+                    2u16 => {
+                        let title = parser.get_string()?;
+                        Self::SetTitle { title }
+                    }
+                    3u16 => {
+                        let app_id = parser.get_string()?;
+                        Self::SetAppId { app_id }
+                    }
+                    4u16 => {
+                        let seat = Object::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        Self::ShowWindowMenu {
+                            seat,
+                            serial,
+                            x,
+                            y,
+                        }
+                    }
+                    5u16 => {
+                        let seat = Object::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        Self::Move { seat, serial }
+                    }
+                    6u16 => {
+                        let seat = Object::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        let edges = parser.get_u32()?.into();
+                        Self::Resize {
+                            seat,
+                            serial,
+                            edges,
+                        }
+                    }
+                    7u16 => {
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::SetMaxSize { width, height }
+                    }
+                    8u16 => {
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Self::SetMinSize { width, height }
+                    }
+                    9u16 => Self::SetMaximized,
+                    10u16 => Self::UnsetMaximized,
+                    11u16 => {
+                        let output = Some(parser.get_u32()?)
+                            .filter(|id| *id != 0)
+                            .map(Object::from_id);
+                        Self::SetFullscreen { output }
+                    }
+                    12u16 => Self::UnsetFullscreen,
+                    13u16 => Self::SetMinimized,
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event<'a> {
+        Configure { width: i32, height: i32, states: &'a [u32] },
+        Close,
+        ConfigureBounds { width: i32, height: i32 },
+        WmCapabilities { capabilities: &'a [u32] },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidResizeEdge = 0u32,
+        InvalidParent = 1u32,
+        InvalidSize = 2u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidResizeEdge),
+                1u32 => Ok(Self::InvalidParent),
+                2u32 => Ok(Self::InvalidSize),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum ResizeEdge {
+        None = 0u32,
+        Top = 1u32,
+        Bottom = 2u32,
+        Left = 4u32,
+        TopLeft = 5u32,
+        BottomLeft = 6u32,
+        Right = 8u32,
+        TopRight = 9u32,
+        BottomRight = 10u32,
+    }
+    impl PartialEq<ResizeEdge> for u32 {
+        fn eq(&self, other: &ResizeEdge) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for ResizeEdge {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for ResizeEdge {}
+    impl From<ResizeEdge> for u32 {
+        fn from(value: ResizeEdge) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for ResizeEdge {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::None),
+                1u32 => Ok(Self::Top),
+                2u32 => Ok(Self::Bottom),
+                4u32 => Ok(Self::Left),
+                5u32 => Ok(Self::TopLeft),
+                6u32 => Ok(Self::BottomLeft),
+                8u32 => Ok(Self::Right),
+                9u32 => Ok(Self::TopRight),
+                10u32 => Ok(Self::BottomRight),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum State {
+        Maximized = 1u32,
+        Fullscreen = 2u32,
+        Resizing = 3u32,
+        Activated = 4u32,
+        TiledLeft = 5u32,
+        TiledRight = 6u32,
+        TiledTop = 7u32,
+        TiledBottom = 8u32,
+        Suspended = 9u32,
+        ConstrainedLeft = 10u32,
+        ConstrainedRight = 11u32,
+        ConstrainedTop = 12u32,
+        ConstrainedBottom = 13u32,
+    }
+    impl PartialEq<State> for u32 {
+        fn eq(&self, other: &State) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for State {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for State {}
+    impl From<State> for u32 {
+        fn from(value: State) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for State {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::Maximized),
+                2u32 => Ok(Self::Fullscreen),
+                3u32 => Ok(Self::Resizing),
+                4u32 => Ok(Self::Activated),
+                5u32 => Ok(Self::TiledLeft),
+                6u32 => Ok(Self::TiledRight),
+                7u32 => Ok(Self::TiledTop),
+                8u32 => Ok(Self::TiledBottom),
+                9u32 => Ok(Self::Suspended),
+                10u32 => Ok(Self::ConstrainedLeft),
+                11u32 => Ok(Self::ConstrainedRight),
+                12u32 => Ok(Self::ConstrainedTop),
+                13u32 => Ok(Self::ConstrainedBottom),
+                _ => Err(value),
+            }
+        }
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum WmCapabilities {
+        WindowMenu = 1u32,
+        Maximize = 2u32,
+        Fullscreen = 3u32,
+        Minimize = 4u32,
+    }
+    impl PartialEq<WmCapabilities> for u32 {
+        fn eq(&self, other: &WmCapabilities) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for WmCapabilities {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for WmCapabilities {}
+    impl From<WmCapabilities> for u32 {
+        fn from(value: WmCapabilities) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for WmCapabilities {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                1u32 => Ok(Self::WindowMenu),
+                2u32 => Ok(Self::Maximize),
+                3u32 => Ok(Self::Fullscreen),
+                4u32 => Ok(Self::Minimize),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "xdg_toplevel",
+        version: 7u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_parent",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "parent",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&super::xdg_toplevel::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_title",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "title",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_app_id",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "app_id",
+                        ty: interface::ArgType::String,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "show_window_menu",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "move",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "resize",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "edges",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: Some("resize_edge"),
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_max_size",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_min_size",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "set_maximized",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "unset_maximized",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_fullscreen",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "output",
+                        ty: interface::ArgType::Object,
+                        nullable: true,
+                        interface: Some(&scratchway::wayland::wl_output::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "unset_fullscreen",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "set_minimized",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "states",
+                        ty: interface::ArgType::Array,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "close",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "configure_bounds",
+                since: 4u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "wm_capabilities",
+                since: 5u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "capabilities",
+                        ty: interface::ArgType::Array,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_resize_edge",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_parent",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "invalid_size",
+                        value: 2u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "resize_edge",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "none",
+                        value: 0u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "left",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_left",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_left",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "right",
+                        value: 8u32,
+                    },
+                    interface::EnumEntry {
+                        name: "top_right",
+                        value: 9u32,
+                    },
+                    interface::EnumEntry {
+                        name: "bottom_right",
+                        value: 10u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "state",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "maximized",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "fullscreen",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "resizing",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "activated",
+                        value: 4u32,
+                    },
+                    interface::EnumEntry {
+                        name: "tiled_left",
+                        value: 5u32,
+                    },
+                    interface::EnumEntry {
+                        name: "tiled_right",
+                        value: 6u32,
+                    },
+                    interface::EnumEntry {
+                        name: "tiled_top",
+                        value: 7u32,
+                    },
+                    interface::EnumEntry {
+                        name: "tiled_bottom",
+                        value: 8u32,
+                    },
+                    interface::EnumEntry {
+                        name: "suspended",
+                        value: 9u32,
+                    },
+                    interface::EnumEntry {
+                        name: "constrained_left",
+                        value: 10u32,
+                    },
+                    interface::EnumEntry {
+                        name: "constrained_right",
+                        value: 11u32,
+                    },
+                    interface::EnumEntry {
+                        name: "constrained_top",
+                        value: 12u32,
+                    },
+                    interface::EnumEntry {
+                        name: "constrained_bottom",
+                        value: 13u32,
+                    },
+                ],
+            },
+            interface::EnumDesc {
+                name: "wm_capabilities",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "window_menu",
+                        value: 1u32,
+                    },
+                    interface::EnumEntry {
+                        name: "maximize",
+                        value: 2u32,
+                    },
+                    interface::EnumEntry {
+                        name: "fullscreen",
+                        value: 3u32,
+                    },
+                    interface::EnumEntry {
+                        name: "minimize",
+                        value: 4u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl XdgToplevel {
+        pub const INTERFACE: &'static str = "xdg_toplevel";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_parent(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            parent: Option<&super::xdg_toplevel::XdgToplevel>,
+        ) {
+            Request::SetParent {
+                parent: parent.map(|o| Object::from_id(o.id())),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_title(&self, writer: &WaylandBuffer<Writer>, title: &str) {
+            Request::SetTitle { title }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_app_id(&self, writer: &WaylandBuffer<Writer>, app_id: &str) {
+            Request::SetAppId { app_id }.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn show_window_menu(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            seat: &scratchway::wayland::wl_seat::WlSeat,
+            serial: u32,
+            x: i32,
+            y: i32,
+        ) {
+            Request::ShowWindowMenu {
+                seat: Object::from_id(seat.id()),
+                serial,
+                x,
+                y,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn r#move(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            seat: &scratchway::wayland::wl_seat::WlSeat,
+            serial: u32,
+        ) {
+            Request::Move {
+                seat: Object::from_id(seat.id()),
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn resize(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            seat: &scratchway::wayland::wl_seat::WlSeat,
+            serial: u32,
+            edges: ResizeEdge,
+        ) {
+            Request::Resize {
+                seat: Object::from_id(seat.id()),
+                serial,
+                edges: WEnum::Value(edges),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_max_size(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            width: i32,
+            height: i32,
+        ) {
+            Request::SetMaxSize {
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_min_size(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            width: i32,
+            height: i32,
+        ) {
+            Request::SetMinSize {
+                width,
+                height,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_maximized(&self, writer: &WaylandBuffer<Writer>) {
+            Request::SetMaximized.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn unset_maximized(&self, writer: &WaylandBuffer<Writer>) {
+            Request::UnsetMaximized.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_fullscreen(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            output: Option<&scratchway::wayland::wl_output::WlOutput>,
+        ) {
+            Request::SetFullscreen {
+                output: output.map(|o| Object::from_id(o.id())),
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn unset_fullscreen(&self, writer: &WaylandBuffer<Writer>) {
+            Request::UnsetFullscreen.encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_minimized(&self, writer: &WaylandBuffer<Writer>) {
+            Request::SetMinimized.encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `xdg_toplevel`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait XdgToplevelHandler {
+        fn configure(
+            &mut self,
+            conn: &Connection,
+            toplevel: &XdgToplevel,
+            width: i32,
+            height: i32,
+            states: &[u32],
+        ) {}
+        fn close(&mut self, conn: &Connection, toplevel: &XdgToplevel) {}
+        fn configure_bounds(
+            &mut self,
+            conn: &Connection,
+            toplevel: &XdgToplevel,
+            width: i32,
+            height: i32,
+        ) {}
+        fn wm_capabilities(
+            &mut self,
+            conn: &Connection,
+            toplevel: &XdgToplevel,
+            capabilities: &[u32],
+        ) {}
+    }
+    impl<H: XdgToplevelHandler + ?Sized> Dispatch<H> for XdgToplevel {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Configure { width, height, states } => {
+                    handler.configure(conn, self, width, height, states)
+                }
+                Event::Close => handler.close(conn, self),
+                Event::ConfigureBounds { width, height } => {
+                    handler.configure_bounds(conn, self, width, height)
+                }
+                Event::WmCapabilities { capabilities } => {
+                    handler.wm_capabilities(conn, self, capabilities)
+                }
+            }
+        }
+    }
+    impl Object for XdgToplevel {
+        const INTERFACE: &'static str = "xdg_toplevel";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event<'a>;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        let states = parser.get_array()?;
+                        Event::Configure {
+                            width,
+                            height,
+                            states,
+                        }
+                    }
+                    1u16 => Event::Close,
+                    2u16 => {
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Event::ConfigureBounds {
+                            width,
+                            height,
+                        }
+                    }
+                    3u16 => {
+                        let capabilities = parser.get_array()?;
+                        Event::WmCapabilities {
+                            capabilities,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+pub mod xdg_popup {
+    use super::*;
+    pub struct XdgPopup {
+        id: u32,
+    }
+    impl ::std::fmt::Display for XdgPopup {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    impl ::std::fmt::Debug for XdgPopup {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+        }
+    }
+    #[derive(Debug)]
+    pub enum Request {
+        Destroy,
+        Grab { seat: scratchway::wayland::wl_seat::WlSeat, serial: u32 },
+        Reposition { positioner: super::xdg_positioner::XdgPositioner, token: u32 },
+    }
+    impl<'a> Request {
+        pub fn opcode(&self) -> u16 {
+            match self {
+                Self::Destroy => 0u16,
+                Self::Grab { .. } => 1u16,
+                Self::Reposition { .. } => 2u16,
+            }
+        }
+        /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+        #[allow(unused_variables)]
+        pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+            match self {
+                Self::Destroy => {
+                    let msg = Message::<8usize>::new(sender_id, 0u16);
+                    writer.write_request(msg.data());
+                }
+                Self::Grab { seat, serial } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 1u16);
+                    msg.write_u32(seat.id());
+                    msg.write_u32(serial);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+                Self::Reposition { positioner, token } => {
+                    let mut msg = Message::<16usize>::new(sender_id, 2u16);
+                    msg.write_u32(positioner.id());
+                    msg.write_u32(token);
+                    msg.build();
+                    writer.write_request(msg.data());
+                }
+            }
+        }
+        /// Parses a message received for an object of this interface, `None` for unknown
+        /// opcodes and messages that don't match their description.
+        ///
+        /// Objects with a known interface the message creates are checked with
+        /// `check_new_id` and tracked, other objects in it aren't looked up.
+        #[allow(unused_variables)]
+        pub fn decode(
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => Self::Destroy,
+                    1u16 => {
+                        let seat = Object::from_id(parser.get_u32()?);
+                        let serial = parser.get_u32()?;
+                        Self::Grab { seat, serial }
+                    }
+                    2u16 => {
+                        let positioner = Object::from_id(parser.get_u32()?);
+                        let token = parser.get_u32()?;
+                        Self::Reposition {
+                            positioner,
+                            token,
+                        }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+    #[derive(Debug)]
+    pub enum Event {
+        Configure { x: i32, y: i32, width: i32, height: i32 },
+        PopupDone,
+        Repositioned { token: u32 },
+    }
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Error {
+        InvalidGrab = 0u32,
+    }
+    impl PartialEq<Error> for u32 {
+        fn eq(&self, other: &Error) -> bool {
+            *self == *other as u32
+        }
+    }
+    impl PartialEq<u32> for Error {
+        fn eq(&self, other: &u32) -> bool {
+            *self as u32 == *other
+        }
+    }
+    impl Eq for Error {}
+    impl From<Error> for u32 {
+        fn from(value: Error) -> u32 {
+            value as u32
+        }
+    }
+    impl TryFrom<u32> for Error {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, u32> {
+            match value {
+                0u32 => Ok(Self::InvalidGrab),
+                _ => Err(value),
+            }
+        }
+    }
+    pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+        name: "xdg_popup",
+        version: 7u32,
+        requests: &[
+            interface::MessageDesc {
+                name: "destroy",
+                since: 1u32,
+                destructor: true,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "grab",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "seat",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&scratchway::wayland::wl_seat::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "serial",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "reposition",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "positioner",
+                        ty: interface::ArgType::Object,
+                        nullable: false,
+                        interface: Some(&super::xdg_positioner::INTERFACE_DESC),
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "token",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            interface::MessageDesc {
+                name: "configure",
+                since: 1u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "x",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "y",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "width",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                    interface::ArgDesc {
+                        name: "height",
+                        ty: interface::ArgType::Int,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+            interface::MessageDesc {
+                name: "popup_done",
+                since: 1u32,
+                destructor: false,
+                args: &[],
+            },
+            interface::MessageDesc {
+                name: "repositioned",
+                since: 3u32,
+                destructor: false,
+                args: &[
+                    interface::ArgDesc {
+                        name: "token",
+                        ty: interface::ArgType::Uint,
+                        nullable: false,
+                        interface: None,
+                        enumeration: None,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            interface::EnumDesc {
+                name: "error",
+                bitfield: false,
+                entries: &[
+                    interface::EnumEntry {
+                        name: "invalid_grab",
+                        value: 0u32,
+                    },
+                ],
+            },
+        ],
+    };
+    impl XdgPopup {
+        pub const INTERFACE: &'static str = "xdg_popup";
+        #[allow(clippy::too_many_arguments)]
+        pub fn destroy(self, writer: &WaylandBuffer<Writer>) {
+            Request::Destroy.encode(self.id, writer);
+            writer.destroy_object(&self);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn grab(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            seat: &scratchway::wayland::wl_seat::WlSeat,
+            serial: u32,
+        ) {
+            Request::Grab {
+                seat: Object::from_id(seat.id()),
+                serial,
+            }
+                .encode(self.id, writer);
+        }
+        #[allow(clippy::too_many_arguments)]
+        pub fn reposition(
+            &self,
+            writer: &WaylandBuffer<Writer>,
+            positioner: &super::xdg_positioner::XdgPositioner,
+            token: u32,
+        ) {
+            Request::Reposition {
+                positioner: Object::from_id(positioner.id()),
+                token,
+            }
+                .encode(self.id, writer);
+        }
+    }
+    /// Receives the events of `xdg_popup`, every method defaults to doing nothing.
+    #[allow(unused_variables, clippy::too_many_arguments)]
+    pub trait XdgPopupHandler {
+        fn configure(
+            &mut self,
+            conn: &Connection,
+            popup: &XdgPopup,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {}
+        fn popup_done(&mut self, conn: &Connection, popup: &XdgPopup) {}
+        fn repositioned(&mut self, conn: &Connection, popup: &XdgPopup, token: u32) {}
+    }
+    impl<H: XdgPopupHandler + ?Sized> Dispatch<H> for XdgPopup {
+        #[allow(unused_variables)]
+        fn dispatch(&self, handler: &mut H, conn: &Connection, event: WlEvent<'_>) {
+            let Some(parsed) = self.parse_event(conn.reader(), event) else {
+                conn.malformed(event);
+                return;
+            };
+            match parsed {
+                Event::Configure { x, y, width, height } => {
+                    handler.configure(conn, self, x, y, width, height)
+                }
+                Event::PopupDone => handler.popup_done(conn, self),
+                Event::Repositioned { token } => handler.repositioned(conn, self, token),
+            }
+        }
+    }
+    impl Object for XdgPopup {
+        const INTERFACE: &'static str = "xdg_popup";
+        const EVENT_FDS: &'static [u8] = &[0u8, 0u8, 0u8];
+        const DESC: &'static interface::Interface = &INTERFACE_DESC;
+        type Event<'a> = Event;
+        fn from_id(id: u32) -> Self {
+            Self { id }
+        }
+        fn id(&self) -> u32 {
+            self.id
+        }
+        fn interface(&self) -> &'static str {
+            Self::INTERFACE
+        }
+        #[allow(unused_variables)]
+        fn parse_event<'a>(
+            &self,
+            reader: &WaylandBuffer<Reader>,
+            event: WlEvent<'a>,
+        ) -> Option<Self::Event<'a>> {
+            let parser = event.parser();
+            Some(
+                match event.header.opcode {
+                    0u16 => {
+                        let x = parser.get_i32()?;
+                        let y = parser.get_i32()?;
+                        let width = parser.get_i32()?;
+                        let height = parser.get_i32()?;
+                        Event::Configure {
+                            x,
+                            y,
+                            width,
+                            height,
+                        }
+                    }
+                    1u16 => Event::PopupDone,
+                    2u16 => {
+                        let token = parser.get_u32()?;
+                        Event::Repositioned { token }
+                    }
+                    _ => return None,
+                },
+            )
+        }
+    }
+}
+/// The descriptions of every interface generated from the protocol.
+pub static INTERFACES: &[&interface::Interface] = &[
+    &xdg_wm_base::INTERFACE_DESC,
+    &xdg_positioner::INTERFACE_DESC,
+    &xdg_surface::INTERFACE_DESC,
+    &xdg_toplevel::INTERFACE_DESC,
+    &xdg_popup::INTERFACE_DESC,
+];
+/// Parsers of the incoming messages of every interface generated from the protocol.
+pub static PARSERS: &[interface::Parser] = &[
+    interface::Parser {
+        desc: &xdg_wm_base::INTERFACE_DESC,
+        parse: |reader, message| {
+            <xdg_wm_base::XdgWmBase as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &xdg_positioner::INTERFACE_DESC,
+        parse: |reader, message| {
+            <xdg_positioner::XdgPositioner as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &xdg_surface::INTERFACE_DESC,
+        parse: |reader, message| {
+            <xdg_surface::XdgSurface as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &xdg_toplevel::INTERFACE_DESC,
+        parse: |reader, message| {
+            <xdg_toplevel::XdgToplevel as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+    interface::Parser {
+        desc: &xdg_popup::INTERFACE_DESC,
+        parse: |reader, message| {
+            <xdg_popup::XdgPopup as Object>::from_id(message.header.id)
+                .parse_event(reader, message)
+                .is_some()
+        },
+    },
+];
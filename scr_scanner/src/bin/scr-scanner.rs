@@ -0,0 +1,68 @@
+//! Prints the code `generate!` would expand to for a protocol file.
+//!
+//! Usage: scr-scanner [--server] [-i interface]... [-I other.xml=crate::other]... protocol.xml > out.rs
+
+use std::process::ExitCode;
+
+const USAGE: &str =
+    "Usage: scr-scanner [--server] [-i interface]... [-I other.xml=module::path]... protocol.xml";
+
+fn main() -> ExitCode {
+    let mut path = None;
+    let mut interfaces = Vec::new();
+    let mut imports = Vec::new();
+    let mut side = scr_scanner::Side::Client;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-i" | "--interface" => match args.next() {
+                Some(interface) => interfaces.push(interface),
+                None => {
+                    eprintln!("{arg} requires an interface name\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-I" | "--import" => match args.next().as_deref().and_then(|a| a.split_once('=')) {
+                Some((xml, module)) => imports.push((xml.to_string(), module.to_string())),
+                None => {
+                    eprintln!("{arg} requires a `protocol.xml=module::path` mapping\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--server" => side = scr_scanner::Side::Server,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => {
+                eprintln!("unexpected argument: {arg}\n{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let Some(path) = path else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let mut generator = scr_scanner::Generator::new(path).side(side);
+    if !interfaces.is_empty() {
+        generator = generator.interfaces(interfaces);
+    }
+    for (xml, module) in imports {
+        generator = generator.import_protocol(xml, module);
+    }
+
+    match generator.generate_string() {
+        Ok(code) => {
+            print!("{code}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
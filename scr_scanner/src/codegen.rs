@@ -0,0 +1,1378 @@
+#![allow(unused)]
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+
+use crate::{ident, parser};
+
+/// An interface generated elsewhere that this protocol refers to.
+#[derive(Debug, Clone)]
+pub(crate) struct Import {
+    pub(crate) interface: String,
+    /// Path of the module the interface's module lives in, e.g. `scratchway::wayland`.
+    pub(crate) module:    String,
+    /// Names of the interface's enums that are bitfields, if known.
+    pub(crate) bitfields: Vec<String>,
+}
+
+impl Import {
+    pub(crate) fn from_protocol(protocol: &parser::Protocol<'_>, module: &str) -> Vec<Import> {
+        protocol
+            .interfaces
+            .iter()
+            .map(|iface| Import {
+                interface: iface.name.clone(),
+                module:    module.to_string(),
+                bitfields: iface
+                    .enums
+                    .iter()
+                    .filter(|en| en.is_bitfield)
+                    .map(|en| en.name.clone())
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Which end of the connection the code is generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Side {
+    /// Proxies that send requests and receive events.
+    #[default]
+    Client,
+    /// Resources that receive requests and send events.
+    Server,
+}
+
+/// Resolves references to interfaces, either to a sibling module of the same protocol
+/// or to wherever an [`Import`] says they are.
+struct Context<'a> {
+    protocol:   &'a parser::Protocol<'a>,
+    interfaces: Option<&'a [String]>,
+    imports:    &'a [Import],
+    side:       Side,
+}
+
+impl Context<'_> {
+    /// The trait implemented by the generated object types, `Object` or `Resource`.
+    fn object_trait(&self) -> TokenStream {
+        match self.side {
+            Side::Client => quote! { Object },
+            Side::Server => quote! { Resource },
+        }
+    }
+
+    /// How a buffer registers a newly created object of this side.
+    fn new_object_fn(&self) -> TokenStream {
+        match self.side {
+            Side::Client => quote! { new_object },
+            Side::Server => quote! { new_resource },
+        }
+    }
+
+    /// What handlers get to reply through, the connection or the client that sent the request.
+    fn peer(&self) -> (Ident, TokenStream) {
+        match self.side {
+            Side::Client => (format_ident!("conn"), quote! { Connection }),
+            Side::Server => (format_ident!("client"), quote! { Client }),
+        }
+    }
+
+    /// The messages sent from this side and the name of their enum.
+    fn outgoing<'i>(&self, iface: &'i parser::Interface<'_>) -> (&'i [parser::Message<'i>], Ident) {
+        match self.side {
+            Side::Client => (&iface.requests, format_ident!("Request")),
+            Side::Server => (&iface.events, format_ident!("Event")),
+        }
+    }
+
+    /// The messages received by this side and the name of their enum.
+    fn incoming<'i>(&self, iface: &'i parser::Interface<'_>) -> (&'i [parser::Message<'i>], Ident) {
+        match self.side {
+            Side::Client => (&iface.events, format_ident!("Event")),
+            Side::Server => (&iface.requests, format_ident!("Request")),
+        }
+    }
+
+    fn is_generated(&self, iface: &str) -> bool {
+        self.protocol.interfaces.iter().any(|i| i.name == iface)
+            && self
+                .interfaces
+                .is_none_or(|wanted| wanted.iter().any(|n| n == iface))
+    }
+
+    fn module(&self, iface: &str) -> TokenStream {
+        let mod_idnt = ident::snake(iface);
+        if self.is_generated(iface) {
+            return quote! { super::#mod_idnt };
+        }
+        match self.imports.iter().rev().find(|i| i.interface == iface) {
+            Some(import) => {
+                let path: TokenStream = import.module.parse().unwrap();
+                quote! { #path::#mod_idnt }
+            }
+            // Left to whatever the including module has in scope
+            None => quote! { #mod_idnt },
+        }
+    }
+
+    fn object(&self, iface: &str) -> TokenStream {
+        let module = self.module(iface);
+        let obj_idnt = ident::pascal(iface);
+        quote! { #module::#obj_idnt }
+    }
+
+    fn is_bitfield(&self, iface: &str, en_name: &str) -> bool {
+        if let Some(owner) = self.protocol.interfaces.iter().find(|i| i.name == iface) {
+            return owner
+                .enums
+                .iter()
+                .any(|e| e.name == en_name && e.is_bitfield);
+        }
+        self.imports
+            .iter()
+            .rev()
+            .find(|i| i.interface == iface)
+            .is_some_and(|i| i.bitfields.iter().any(|b| b == en_name))
+    }
+}
+
+pub(crate) fn generate_protocol(
+    protocol: &parser::Protocol<'_>, interfaces: Option<&[String]>, imports: &[Import], side: Side,
+) -> TokenStream {
+    let ctx = Context {
+        protocol,
+        interfaces,
+        imports,
+        side,
+    };
+    let ctx = &ctx;
+    let interfaces = protocol.interfaces.iter().filter(|o| ctx.is_generated(&o.name)).map(|o| {
+        let iface_name = &o.name;
+        let iface_mod = ident::snake(&o.name);
+        let object_name = ident::pascal(&o.name);
+        let object_trait = ctx.object_trait();
+        let new_object_fn = ctx.new_object_fn();
+        let (outgoing, out_enum) = ctx.outgoing(o);
+        let (incoming, in_enum) = ctx.incoming(o);
+        let (reqs, outgoing_enum) = gen_outgoing(ctx, o, outgoing, &out_enum);
+
+        let mut ev_variants = Vec::<TokenStream>::new();
+        let mut ev_parse = Vec::<TokenStream>::new();
+        let mut ev_lifetime = false;
+        let mut handler_methods = Vec::<TokenStream>::new();
+        let mut dispatch_arms = Vec::<TokenStream>::new();
+        let proxy_idnt = proxy_name(o);
+        let (peer_idnt, peer_ty) = ctx.peer();
+
+        for (i, ev) in incoming.iter().enumerate() {
+            let i = i as u16;
+            let ev_idnt = ident::pascal(&ev.name);
+            let mut fields = Vec::new();
+            let mut handler_params = Vec::new();
+            let mut variant_parse = Vec::new();
+            let mut ev_fields: Vec<TokenStream> = Vec::new();
+            let ty = quote!{ u32 };
+            if ev.args.is_empty() {
+                ev_variants.push(quote!{
+                    #ev_idnt
+                });
+                variant_parse.push(quote!{
+                    #in_enum::#ev_idnt
+                });
+            } else {
+                for (arg, field_idnt) in ev.args.iter().zip(arg_idents(ev)) {
+                    let field_type = match &arg.arg_type {
+                        parser::ArgType::Int => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_i32()?;
+                            });
+                            quote! { i32 }
+                        },
+                        parser::ArgType::Uint => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_u32()?;
+                            });
+                            quote! { u32 }
+                        },
+                        parser::ArgType::Enum { name: en, .. } => {
+                            let en = resolve_enum(ctx, o, en);
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_u32()?.into();
+                            });
+                            let en_ty = en.ty;
+                            if en.is_bitfield {
+                                en_ty
+                            } else {
+                                quote! { WEnum<#en_ty> }
+                            }
+                        },
+                        parser::ArgType::Fixed => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_fixed()?;
+                            });
+                            quote! { f32 }
+                        },
+                        parser::ArgType::String { allow_null: true } => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_nullable_string()?;
+                            });
+                            ev_lifetime = true;
+                            quote! { Option<&'a str> }
+                        },
+                        parser::ArgType::String { allow_null: false } => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_string()?;
+                            });
+                            ev_lifetime = true;
+                            quote! { &'a str }
+                        },
+                        parser::ArgType::Object { allow_null, iface: None } => {
+                            if *allow_null {
+                                variant_parse.push(quote!{
+                                    let #field_idnt = Some(parser.get_u32()?).filter(|id| *id != 0);
+                                });
+                                quote! { Option<u32> }
+                            } else {
+                                variant_parse.push(quote!{
+                                    let #field_idnt = parser.get_u32()?;
+                                });
+                                quote! { u32 }
+                            }
+                        },
+                        parser::ArgType::Object { allow_null, iface: Some(iface) } => {
+                            let iface_ty = ctx.object(iface);
+                            if *allow_null {
+                                variant_parse.push(quote!{
+                                    let #field_idnt = {
+                                        let id = parser.get_u32()?;
+                                        if id == 0 {
+                                            None
+                                        } else {
+                                            Some(#object_trait::from_id(id))
+                                        }
+                                    };
+                                });
+                                quote! { Option<#iface_ty> }
+                            } else {
+                                variant_parse.push(quote!{
+                                    let #field_idnt = #object_trait::from_id(parser.get_u32()?);
+                                });
+                                quote! { #iface_ty }
+                            }
+                        },
+                        // Objects created by the other side inherit the version of the object
+                        // that announced them. Servers only accept free client ids, like
+                        // libwayland does
+                        parser::ArgType::NewId { iface: Some(iface) } => {
+                            let iface_ty = ctx.object(iface);
+                            let id = match ctx.side {
+                                Side::Client => quote! { parser.get_u32()? },
+                                Side::Server => quote! { reader.check_new_id(parser.get_u32()?)? },
+                            };
+                            variant_parse.push(quote!{
+                                let #field_idnt = reader.#new_object_fn(#id, reader.object_version(self.id));
+                            });
+                            quote! { #iface_ty }
+                        },
+                        // Sent as the interface name, the version and then the new id, the
+                        // receiver has to create the object once it knows what it is
+                        parser::ArgType::NewId { iface: None } => {
+                            variant_parse.push(quote!{
+                                let interface = parser.get_string()?;
+                                let version = parser.get_u32()?;
+                                let #field_idnt = parser.get_u32()?;
+                            });
+                            ev_lifetime = true;
+                            for (name, ty, handler_ty) in
+                                [("interface", quote! { &'a str }, quote! { &str }), ("version", quote! { u32 }, quote! { u32 })]
+                            {
+                                let name = format_ident!("{}", name);
+                                fields.push(quote! { #name });
+                                handler_params.push(quote! { #name: #handler_ty });
+                                ev_fields.push(quote! { #name: #ty });
+                            }
+                            quote! { u32 }
+                        },
+                        parser::ArgType::Array => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = parser.get_array()?;
+                            });
+                            ev_lifetime = true;
+                            quote! { &'a [u32] }
+                        },
+                        parser::ArgType::Fd => {
+                            variant_parse.push(quote!{
+                                let #field_idnt = reader.get_fd()?;
+                            });
+                            quote! { std::os::fd::OwnedFd }
+                        },
+                    };
+                    let handler_type = match &arg.arg_type {
+                        parser::ArgType::String { allow_null: true } => quote! { Option<&str> },
+                        parser::ArgType::String { allow_null: false } => quote! { &str },
+                        parser::ArgType::Array => quote! { &[u32] },
+                        _ => field_type.clone(),
+                    };
+                    fields.push(quote! {
+                        #field_idnt
+                    });
+                    handler_params.push(quote! {
+                        #field_idnt: #handler_type
+                    });
+                    ev_fields.push(quote!{
+                        #field_idnt: #field_type
+                    });
+                }
+                variant_parse.push(quote! {
+                    #in_enum::#ev_idnt { #(#fields,)* }
+                });
+
+                ev_variants.push(quote!{
+                    #ev_idnt {
+                        #(#ev_fields,)*
+                    }
+                });
+            }
+            ev_parse.push(quote!{
+                #i => {
+                    #(#variant_parse)*
+                }
+            });
+
+            let handler_idnt = ident::snake(&ev.name);
+            handler_methods.push(quote! {
+                fn #handler_idnt(&mut self, #peer_idnt: &#peer_ty, #proxy_idnt: &#object_name, #(#handler_params,)*) {}
+            });
+            let pattern = if fields.is_empty() {
+                quote! { #in_enum::#ev_idnt }
+            } else {
+                quote! { #in_enum::#ev_idnt { #(#fields,)* } }
+            };
+            dispatch_arms.push(quote! {
+                #pattern => handler.#handler_idnt(#peer_idnt, self, #(#fields,)*),
+            });
+        }
+        let handler_trait = format_ident!("{}Handler", object_name);
+        let parse_fn = match ctx.side {
+            Side::Client => quote! { parse_event },
+            Side::Server => quote! { parse_request },
+        };
+        // Messages that don't parse are left to the peer, the client logs and drops them and
+        // the server answers with a protocol error
+        let dispatch_body = if dispatch_arms.is_empty() {
+            quote! {
+                #peer_idnt.malformed(event);
+            }
+        } else {
+            quote! {
+                let Some(parsed) = self.#parse_fn(#peer_idnt.reader(), event) else {
+                    #peer_idnt.malformed(event);
+                    return;
+                };
+                match parsed {
+                    #(#dispatch_arms)*
+                }
+            }
+        };
+        let handler_doc = match ctx.side {
+            Side::Client => format!(" Receives the events of `{iface_name}`, every method defaults to doing nothing."),
+            Side::Server => format!(" Receives the requests of `{iface_name}`, every method defaults to doing nothing."),
+        };
+        let handler = quote! {
+            #[doc = #handler_doc]
+            #[allow(unused_variables, clippy::too_many_arguments)]
+            pub trait #handler_trait {
+                #(#handler_methods)*
+            }
+            impl<H: #handler_trait + ?Sized> Dispatch<H> for #object_name {
+                #[allow(unused_variables)]
+                fn dispatch(&self, handler: &mut H, #peer_idnt: &#peer_ty, event: WlEvent<'_>) {
+                    #dispatch_body
+                }
+            }
+        };
+
+        let ev_lifetime = if ev_lifetime { quote! {<'a>} } else { quote! {} };
+        let incoming_fds = incoming.iter().map(|ev| {
+            ev.args.iter().filter(|arg| arg.arg_type == parser::ArgType::Fd).count() as u8
+        });
+
+        let incoming_enum = quote!{
+            #[derive(Debug)]
+            pub enum #in_enum #ev_lifetime {
+                 #(#ev_variants,)*
+            }
+        };
+
+        let enums = o.enums.iter().map(|en| {
+            if en.is_bitfield {
+                gen_bitfield(en)
+            } else {
+                gen_enum(en)
+            }
+        });
+        let interface_desc = gen_interface_desc(ctx, o);
+        // fucking hell
+        let parse_body = if ev_parse.is_empty() {
+            quote! {
+                None
+            }
+        } else {
+            quote! {
+                let parser = event.parser();
+                Some(match event.header.opcode {
+                    #(#ev_parse)*,
+                    _ => return None,
+                })
+            }
+        };
+        let common = quote! {
+            pub struct #object_name {
+                id: u32,
+            }
+            impl ::std::fmt::Display for #object_name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+                }
+            }
+            impl ::std::fmt::Debug for #object_name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
+                }
+            }
+            #outgoing_enum
+            #incoming_enum
+            #(#enums)*
+            #interface_desc
+            impl #object_name {
+                pub const INTERFACE: &'static str = #iface_name;
+                #reqs
+            }
+        };
+        let side_specific = match ctx.side {
+            Side::Client => quote! {
+                #handler
+                impl Object for #object_name {
+                    const INTERFACE: &'static str = #iface_name;
+                    const EVENT_FDS: &'static [u8] = &[#(#incoming_fds,)*];
+                    const DESC: &'static interface::Interface = &INTERFACE_DESC;
+                    type Event<'a> = Event #ev_lifetime;
+                    fn from_id(id: u32) -> Self {
+                        Self { id }
+                    }
+                    fn id(&self) -> u32 {
+                        self.id
+                    }
+                    fn interface(&self) -> &'static str {
+                        Self::INTERFACE
+                    }
+                    #[allow(unused_variables)]
+                    fn parse_event<'a>(&self, reader: &WaylandBuffer<Reader>, event: WlEvent<'a>) -> Option<Self::Event<'a>> {
+                        #parse_body
+                    }
+                }
+            },
+            Side::Server => quote! {
+                #handler
+                impl Resource for #object_name {
+                    const INTERFACE: &'static str = #iface_name;
+                    const REQUEST_FDS: &'static [u8] = &[#(#incoming_fds,)*];
+                    const DESC: &'static interface::Interface = &INTERFACE_DESC;
+                    type Request<'a> = Request #ev_lifetime;
+                    fn from_id(id: u32) -> Self {
+                        Self { id }
+                    }
+                    fn id(&self) -> u32 {
+                        self.id
+                    }
+                    fn interface(&self) -> &'static str {
+                        Self::INTERFACE
+                    }
+                    #[allow(unused_variables)]
+                    fn parse_request<'a>(&self, reader: &WaylandBuffer<Reader>, event: WlEvent<'a>) -> Option<Self::Request<'a>> {
+                        #parse_body
+                    }
+                }
+            },
+        };
+        quote! {
+            pub mod #iface_mod {
+                use super::*;
+                #common
+                #side_specific
+            }
+        }
+    });
+
+    let descs = protocol
+        .interfaces
+        .iter()
+        .filter(|o| ctx.is_generated(&o.name))
+        .map(|o| {
+            let iface_mod = ident::snake(&o.name);
+            quote! { &#iface_mod::INTERFACE_DESC }
+        });
+    let object_trait = ctx.object_trait();
+    let parse_fn = match ctx.side {
+        Side::Client => quote! { parse_event },
+        Side::Server => quote! { parse_request },
+    };
+    let parsers = protocol
+        .interfaces
+        .iter()
+        .filter(|o| ctx.is_generated(&o.name))
+        .map(|o| {
+            let iface_mod = ident::snake(&o.name);
+            let object_name = ident::pascal(&o.name);
+            quote! {
+                interface::Parser {
+                    desc:  &#iface_mod::INTERFACE_DESC,
+                    parse: |reader, message| {
+                        <#iface_mod::#object_name as #object_trait>::from_id(message.header.id)
+                            .#parse_fn(reader, message)
+                            .is_some()
+                    },
+                }
+            }
+        });
+    let mut tokens = interfaces.collect::<TokenStream>();
+    tokens.extend(quote! {
+        /// The descriptions of every interface generated from the protocol.
+        pub static INTERFACES: &[&interface::Interface] = &[#(#descs,)*];
+        /// Parsers of the incoming messages of every interface generated from the protocol.
+        pub static PARSERS: &[interface::Parser] = &[#(#parsers,)*];
+    });
+    tokens
+}
+
+/// The methods sending the `messages` of `iface` and their enum, `Request` on the client
+/// side and `Event` on the server side.
+///
+/// The methods build a message and encode it, so the enum is the single place that knows
+/// how each message looks on the wire, in both directions.
+fn gen_outgoing(
+    ctx: &Context<'_>, iface: &parser::Interface<'_>, messages: &[parser::Message<'_>],
+    enum_name: &Ident,
+) -> (TokenStream, TokenStream) {
+    let object_trait = ctx.object_trait();
+    let new_object_fn = ctx.new_object_fn();
+    let (new_id_fn, destroy_fn) = match ctx.side {
+        Side::Client => (quote! { new_id }, quote! { destroy_object }),
+        Side::Server => (quote! { new_server_id }, quote! { destroy_resource }),
+    };
+    let mut methods = Vec::new();
+    let mut variants = Vec::new();
+    let mut opcode_arms = Vec::new();
+    let mut encode_arms = Vec::new();
+    let mut decode_arms = Vec::new();
+    let mut lifetime = false;
+
+    for (i, r) in messages.iter().enumerate() {
+        let opcode = i as u16;
+        let req_idnt = ident::snake(&r.name);
+        let variant_idnt = ident::pascal(&r.name);
+        let mut params = Vec::new();
+        let mut fields = Vec::new();
+        let mut field_defs = Vec::new();
+        // The values the method puts in the message
+        let mut field_values = Vec::new();
+        let mut method_body = Vec::new();
+        let mut encode_body = Vec::new();
+        let mut decode_body = Vec::new();
+        let mut size = 8usize;
+        let (mut return_ty, mut return_stmnt) = (None, quote! {});
+        let mut generics = quote! {};
+
+        let mut field = |idnt: &Ident, ty: TokenStream, value: TokenStream| {
+            fields.push(idnt.clone());
+            field_defs.push(quote! { #idnt: #ty });
+            if *idnt == value.to_string() {
+                field_values.push(quote! { #idnt });
+            } else {
+                field_values.push(quote! { #idnt: #value });
+            }
+        };
+
+        for (arg, arg_idnt) in r.args.iter().zip(arg_idents(r)) {
+            match &arg.arg_type {
+                parser::ArgType::Int => {
+                    size += 4;
+                    params.push(quote! { #arg_idnt: i32 });
+                    field(&arg_idnt, quote! { i32 }, quote! { #arg_idnt });
+                    encode_body.push(quote! { msg.write_i32(#arg_idnt); });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_i32()?; });
+                }
+                parser::ArgType::Uint => {
+                    size += 4;
+                    params.push(quote! { #arg_idnt: u32 });
+                    field(&arg_idnt, quote! { u32 }, quote! { #arg_idnt });
+                    encode_body.push(quote! { msg.write_u32(#arg_idnt); });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_u32()?; });
+                }
+                parser::ArgType::Enum {
+                    name: en, ..
+                } => {
+                    size += 4;
+                    let en = resolve_enum(ctx, iface, en);
+                    let en_ty = en.ty;
+                    params.push(quote! { #arg_idnt: #en_ty });
+                    // Decoded messages can carry values the xml doesn't know about
+                    if en.is_bitfield {
+                        field(&arg_idnt, quote! { #en_ty }, quote! { #arg_idnt });
+                    } else {
+                        field(
+                            &arg_idnt,
+                            quote! { WEnum<#en_ty> },
+                            quote! { WEnum::Value(#arg_idnt) },
+                        );
+                    }
+                    encode_body.push(quote! { msg.write_u32(#arg_idnt.into()); });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_u32()?.into(); });
+                }
+                parser::ArgType::Fixed => {
+                    size += 4;
+                    params.push(quote! { #arg_idnt: f32 });
+                    field(&arg_idnt, quote! { f32 }, quote! { #arg_idnt });
+                    encode_body.push(quote! { msg.write_fixed(#arg_idnt); });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_fixed()?; });
+                }
+                parser::ArgType::String {
+                    allow_null: true,
+                } => {
+                    size += 54;
+                    lifetime = true;
+                    params.push(quote! { #arg_idnt: Option<&str> });
+                    field(&arg_idnt, quote! { Option<&'a str> }, quote! { #arg_idnt });
+                    encode_body.push(quote! {
+                        match #arg_idnt {
+                            Some(string) => msg.write_string(string),
+                            None => msg.write_null_string(),
+                        };
+                    });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_nullable_string()?; });
+                }
+                parser::ArgType::String {
+                    allow_null: false,
+                } => {
+                    size += 54;
+                    lifetime = true;
+                    params.push(quote! { #arg_idnt: &str });
+                    field(&arg_idnt, quote! { &'a str }, quote! { #arg_idnt });
+                    encode_body.push(quote! { msg.write_string(#arg_idnt); });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_string()?; });
+                }
+                // Without an interface we can't know the type, so the raw id is used
+                parser::ArgType::Object {
+                    allow_null,
+                    iface: None,
+                } => {
+                    size += 4;
+                    if *allow_null {
+                        params.push(quote! { #arg_idnt: Option<u32> });
+                        field(&arg_idnt, quote! { Option<u32> }, quote! { #arg_idnt });
+                        encode_body.push(quote! { msg.write_u32(#arg_idnt.unwrap_or(0)); });
+                        decode_body.push(quote! {
+                            let #arg_idnt = Some(parser.get_u32()?).filter(|id| *id != 0);
+                        });
+                    } else {
+                        params.push(quote! { #arg_idnt: u32 });
+                        field(&arg_idnt, quote! { u32 }, quote! { #arg_idnt });
+                        encode_body.push(quote! { msg.write_u32(#arg_idnt); });
+                        decode_body.push(quote! { let #arg_idnt = parser.get_u32()?; });
+                    }
+                }
+                parser::ArgType::Object {
+                    allow_null,
+                    iface: Some(target),
+                } => {
+                    size += 4;
+                    let iface_ty = ctx.object(target);
+                    if *allow_null {
+                        params.push(quote! { #arg_idnt: Option<&#iface_ty> });
+                        field(
+                            &arg_idnt,
+                            quote! { Option<#iface_ty> },
+                            quote! { #arg_idnt.map(|o| #object_trait::from_id(o.id())) },
+                        );
+                        encode_body
+                            .push(quote! { msg.write_u32(#arg_idnt.map_or(0, |o| o.id())); });
+                        decode_body.push(quote! {
+                            let #arg_idnt = Some(parser.get_u32()?).filter(|id| *id != 0).map(#object_trait::from_id);
+                        });
+                    } else {
+                        params.push(quote! { #arg_idnt: &#iface_ty });
+                        field(
+                            &arg_idnt,
+                            quote! { #iface_ty },
+                            quote! { #object_trait::from_id(#arg_idnt.id()) },
+                        );
+                        encode_body.push(quote! { msg.write_u32(#arg_idnt.id()); });
+                        decode_body.push(
+                            quote! { let #arg_idnt = #object_trait::from_id(parser.get_u32()?); },
+                        );
+                    }
+                }
+                // The interface is picked by the caller, on the wire this is sent
+                // as the interface name, the version and then the new id.
+                parser::ArgType::NewId {
+                    iface: None,
+                } => {
+                    size += 4 + 64 + 4 + 4;
+                    lifetime = true;
+                    generics = quote! { <O: #object_trait> };
+                    return_stmnt = quote! { new_object };
+                    return_ty = Some(quote! { O });
+                    params.push(quote! { version: u32 });
+                    method_body.push(quote! {
+                        let new_id = writer.#new_id_fn();
+                        let new_object: O = writer.#new_object_fn(new_id, version);
+                    });
+                    let interface_idnt = format_ident!("interface");
+                    let version_idnt = format_ident!("version");
+                    field(&interface_idnt, quote! { &'a str }, quote! { O::INTERFACE });
+                    field(&version_idnt, quote! { u32 }, quote! { version });
+                    field(&arg_idnt, quote! { u32 }, quote! { new_id });
+                    encode_body.push(quote! {
+                        msg.write_string(interface);
+                        msg.write_u32(version);
+                        msg.write_u32(#arg_idnt);
+                    });
+                    decode_body.push(quote! {
+                        let interface = parser.get_string()?;
+                        let version = parser.get_u32()?;
+                        let #arg_idnt = parser.get_u32()?;
+                    });
+                }
+                parser::ArgType::NewId {
+                    iface: Some(target),
+                } => {
+                    size += 4;
+                    let new_idnt = ident::snake(&format!("new_{target}"));
+                    let new_ty = ctx.object(target);
+                    return_stmnt = quote! { #new_idnt };
+                    return_ty = Some(new_ty.clone());
+                    method_body.push(quote! {
+                        let new_id = writer.#new_id_fn();
+                        let #new_idnt: #new_ty = writer.#new_object_fn(new_id, writer.object_version(self.id));
+                    });
+                    field(
+                        &arg_idnt,
+                        new_ty.clone(),
+                        quote! { #object_trait::from_id(new_id) },
+                    );
+                    encode_body.push(quote! { msg.write_u32(#arg_idnt.id()); });
+                    decode_body.push(quote! {
+                        let #arg_idnt: #new_ty = reader.#new_object_fn(
+                            reader.check_new_id(parser.get_u32()?)?,
+                            reader.object_version(event.header.id),
+                        );
+                    });
+                }
+                parser::ArgType::Array => {
+                    size += 28;
+                    lifetime = true;
+                    params.push(quote! { #arg_idnt: &[u32] });
+                    field(&arg_idnt, quote! { &'a [u32] }, quote! { #arg_idnt });
+                    encode_body.push(quote! { msg.write_array(#arg_idnt); });
+                    decode_body.push(quote! { let #arg_idnt = parser.get_array()?; });
+                }
+                // Passed on raw like the methods take them, whoever holds the message owns the fd
+                parser::ArgType::Fd => {
+                    params.push(quote! { #arg_idnt: i32 });
+                    field(
+                        &arg_idnt,
+                        quote! { std::os::fd::RawFd },
+                        quote! { #arg_idnt },
+                    );
+                    encode_body.push(quote! { writer.add_fd(#arg_idnt); });
+                    decode_body.push(quote! {
+                        let #arg_idnt = std::os::fd::IntoRawFd::into_raw_fd(reader.get_fd()?);
+                    });
+                }
+            }
+        }
+
+        // Proxies remember where they were created, the caller of the request
+        let track_caller =
+            (ctx.side == Side::Client && return_ty.is_some()).then(|| quote! { #[track_caller] });
+        let return_ty = return_ty.map(|ty| quote! { -> #ty });
+        // Destructors consume the proxy so it can't be used to send anything afterwards
+        let (receiver, destroy) = if r.kind.as_deref() == Some("destructor") {
+            (quote! { self }, quote! { writer.#destroy_fn(&self); })
+        } else {
+            (quote! { &self }, quote! {})
+        };
+        let message = if fields.is_empty() {
+            quote! { #enum_name::#variant_idnt }
+        } else {
+            quote! { #enum_name::#variant_idnt { #(#field_values,)* } }
+        };
+        let pattern = if fields.is_empty() {
+            quote! { Self::#variant_idnt }
+        } else {
+            quote! { Self::#variant_idnt { #(#fields,)* } }
+        };
+
+        methods.push(quote! {
+            #[allow(clippy::too_many_arguments)]
+            #track_caller
+            pub fn #req_idnt #generics (#receiver, writer: &WaylandBuffer<Writer>, #(#params,)*) #return_ty {
+                #(#method_body)*
+                #message.encode(self.id, writer);
+                #destroy
+                #return_stmnt
+            }
+        });
+        variants.push(if fields.is_empty() {
+            quote! { #variant_idnt }
+        } else {
+            quote! { #variant_idnt { #(#field_defs,)* } }
+        });
+        let any = if fields.is_empty() {
+            quote! { Self::#variant_idnt }
+        } else {
+            quote! { Self::#variant_idnt { .. } }
+        };
+        opcode_arms.push(quote! {
+            #any => #opcode,
+        });
+        let msg_mut = if r.args.is_empty() {
+            quote! {}
+        } else {
+            quote! { mut }
+        };
+        let build = if r.args.is_empty() {
+            quote! {}
+        } else {
+            quote! { msg.build(); }
+        };
+        encode_arms.push(quote! {
+            #pattern => {
+                let #msg_mut msg = Message::<#size>::new(sender_id, #opcode);
+                #(#encode_body)*
+                #build
+                writer.write_request(msg.data());
+            }
+        });
+        decode_arms.push(quote! {
+            #opcode => {
+                #(#decode_body)*
+                #pattern
+            }
+        });
+    }
+
+    let lifetime = if lifetime {
+        quote! { <'a> }
+    } else {
+        quote! {}
+    };
+    let opcode_body = if opcode_arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! { match self { #(#opcode_arms)* } }
+    };
+    let encode_body = if encode_arms.is_empty() {
+        quote! { match self {} }
+    } else {
+        quote! { match self { #(#encode_arms)* } }
+    };
+    let decode_body = if decode_arms.is_empty() {
+        quote! { None }
+    } else {
+        quote! {
+            let parser = event.parser();
+            Some(match event.header.opcode {
+                #(#decode_arms)*
+                _ => return None,
+            })
+        }
+    };
+    let message_enum = quote! {
+        #[derive(Debug)]
+        pub enum #enum_name #lifetime {
+            #(#variants,)*
+        }
+        impl<'a> #enum_name #lifetime {
+            pub fn opcode(&self) -> u16 {
+                #opcode_body
+            }
+            /// Serialises the message as sent by object `sender_id` into `writer`, fds included.
+            #[allow(unused_variables)]
+            pub fn encode(self, sender_id: u32, writer: &WaylandBuffer<Writer>) {
+                #encode_body
+            }
+            /// Parses a message received for an object of this interface, `None` for unknown
+            /// opcodes and messages that don't match their description.
+            ///
+            /// Objects with a known interface the message creates are checked with
+            /// `check_new_id` and tracked, other objects in it aren't looked up.
+            #[allow(unused_variables)]
+            pub fn decode(reader: &WaylandBuffer<Reader>, event: WlEvent<'a>) -> Option<Self> {
+                #decode_body
+            }
+        }
+    };
+    (quote! { #(#methods)* }, message_enum)
+}
+
+/// The `INTERFACE_DESC` static describing `iface` at runtime.
+fn gen_interface_desc(ctx: &Context<'_>, iface: &parser::Interface<'_>) -> TokenStream {
+    let message_desc = |msg: &parser::Message<'_>| {
+        let name = &msg.name;
+        let since = msg.since;
+        let destructor = msg.kind.as_deref() == Some("destructor");
+        let args = msg.args.iter().map(|arg| {
+            let name = &arg.name;
+            let (ty, nullable, target, enumeration) = match &arg.arg_type {
+                parser::ArgType::Int => (quote! { Int }, false, None, None),
+                parser::ArgType::Uint => (quote! { Uint }, false, None, None),
+                parser::ArgType::Enum {
+                    name,
+                    signed: true,
+                } => (quote! { Int }, false, None, Some(name)),
+                parser::ArgType::Enum {
+                    name,
+                    signed: false,
+                } => (quote! { Uint }, false, None, Some(name)),
+                parser::ArgType::Fixed => (quote! { Fixed }, false, None, None),
+                parser::ArgType::String {
+                    allow_null,
+                } => (quote! { String }, *allow_null, None, None),
+                parser::ArgType::Object {
+                    allow_null,
+                    iface,
+                } => (quote! { Object }, *allow_null, iface.as_ref(), None),
+                parser::ArgType::NewId {
+                    iface,
+                } => (quote! { NewId }, false, iface.as_ref(), None),
+                parser::ArgType::Array => (quote! { Array }, false, None, None),
+                parser::ArgType::Fd => (quote! { Fd }, false, None, None),
+            };
+            let target = match target {
+                Some(target) => {
+                    let module = ctx.module(target);
+                    quote! { Some(&#module::INTERFACE_DESC) }
+                }
+                None => quote! { None },
+            };
+            let enumeration = match enumeration {
+                Some(en) => quote! { Some(#en) },
+                None => quote! { None },
+            };
+            quote! {
+                interface::ArgDesc {
+                    name: #name,
+                    ty: interface::ArgType::#ty,
+                    nullable: #nullable,
+                    interface: #target,
+                    enumeration: #enumeration,
+                }
+            }
+        });
+        quote! {
+            interface::MessageDesc {
+                name: #name,
+                since: #since,
+                destructor: #destructor,
+                args: &[#(#args,)*],
+            }
+        }
+    };
+    let name = &iface.name;
+    let version = iface.version;
+    let requests = iface.requests.iter().map(message_desc);
+    let events = iface.events.iter().map(message_desc);
+    let enums = iface.enums.iter().map(|en| {
+        let name = &en.name;
+        let bitfield = en.is_bitfield;
+        let entries = en.items.iter().map(|item| {
+            let name = &item.name;
+            let value = item.value;
+            quote! { interface::EnumEntry { name: #name, value: #value } }
+        });
+        quote! {
+            interface::EnumDesc {
+                name: #name,
+                bitfield: #bitfield,
+                entries: &[#(#entries,)*],
+            }
+        }
+    });
+    quote! {
+        pub static INTERFACE_DESC: interface::Interface = interface::Interface {
+            name: #name,
+            version: #version,
+            requests: &[#(#requests,)*],
+            events: &[#(#events,)*],
+            enums: &[#(#enums,)*],
+        };
+    }
+}
+
+/// The identifiers of the arguments of `msg`, clear of the locals and fields the generated
+/// code adds for its new ids.
+fn arg_idents(msg: &parser::Message<'_>) -> Vec<Ident> {
+    let mut reserved = Vec::new();
+    for arg in &msg.args {
+        match &arg.arg_type {
+            parser::ArgType::NewId {
+                iface: None,
+            } => reserved.extend(["interface".to_string(), "version".to_string()]),
+            parser::ArgType::NewId {
+                iface: Some(target),
+            } => reserved.push(ident::unraw(&ident::snake(&format!("new_{target}")))),
+            _ => {}
+        }
+    }
+    ident::args(msg.args.iter().map(|arg| &*arg.name), &reserved)
+}
+
+/// The name the handler methods of `iface` give the receiving object, `toplevel` for
+/// `xdg_toplevel`, falling back to `proxy` when an event argument already has that name.
+fn proxy_name(iface: &parser::Interface<'_>) -> Ident {
+    let name = iface
+        .name
+        .split_once('_')
+        .map_or(&*iface.name, |(_, rest)| rest);
+    let name = match name.rsplit_once("_v") {
+        Some((rest, version)) if version.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => name,
+    };
+    let taken = iface
+        .events
+        .iter()
+        .flat_map(|ev| &ev.args)
+        .any(|arg| arg.name == name);
+    if taken || name.is_empty() {
+        format_ident!("proxy")
+    } else {
+        ident::arg(name)
+    }
+}
+
+struct EnumRef {
+    ty:          TokenStream,
+    is_bitfield: bool,
+}
+
+/// Resolves the `enum="..."` attribute of an argument, either `name` for an enum of the
+/// interface itself or `iface.name` for one defined by another interface.
+///
+/// Enums of interfaces that were imported without their protocol can't be looked up,
+/// those are assumed not to be bitfields.
+fn resolve_enum(ctx: &Context<'_>, iface: &parser::Interface<'_>, name: &str) -> EnumRef {
+    let (iface_name, en_name) = name.split_once('.').unwrap_or((&iface.name, name));
+    let en_idnt = ident::pascal(en_name);
+    let is_bitfield = ctx.is_bitfield(iface_name, en_name);
+    let ty = if iface_name == iface.name {
+        quote! { #en_idnt }
+    } else {
+        let module = ctx.module(iface_name);
+        quote! { #module::#en_idnt }
+    };
+    EnumRef {
+        ty,
+        is_bitfield,
+    }
+}
+
+fn gen_enum(en: &parser::Enum<'_>) -> TokenStream {
+    let en_idnt = ident::pascal(&en.name);
+    let mut variants = Vec::new();
+    let mut matches = Vec::new();
+    let idents = ident::entries(en.items.iter().map(|e| &*e.name), ident::pascal);
+    for (e, e_idnt) in en.items.iter().zip(idents) {
+        let val = e.value;
+        variants.push(quote! {
+            #e_idnt = #val
+        });
+        matches.push(quote! {
+            #val => Ok(Self::#e_idnt)
+        });
+    }
+    quote! {
+        #[repr(u32)]
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub enum #en_idnt {
+            #(#variants,)*
+        }
+        impl PartialEq<#en_idnt> for u32 {
+            fn eq(&self, other: &#en_idnt) -> bool {
+                *self == *other as u32
+            }
+        }
+        impl PartialEq<u32> for #en_idnt {
+            fn eq(&self, other: &u32) -> bool {
+                *self as u32 == *other
+            }
+        }
+        impl Eq for #en_idnt {}
+        impl From<#en_idnt> for u32 {
+            fn from(value: #en_idnt) -> u32 {
+                value as u32
+            }
+        }
+        impl TryFrom<u32> for #en_idnt {
+            type Error = u32;
+            fn try_from(value: u32) -> Result<Self, u32> {
+                match value {
+                    #(#matches,)*
+                    _ => Err(value),
+                }
+            }
+        }
+    }
+}
+
+fn gen_bitfield(en: &parser::Enum<'_>) -> TokenStream {
+    let en_idnt = ident::pascal(&en.name);
+    let en_name = ident::unraw(&en_idnt);
+    let mut consts = Vec::new();
+    let mut names = Vec::new();
+    let mut all = 0u32;
+    let idents = ident::entries(en.items.iter().map(|e| &*e.name), ident::upper);
+    for (e, e_idnt) in en.items.iter().zip(idents) {
+        let e_name = ident::unraw(&e_idnt);
+        let val = e.value;
+        all |= val;
+        consts.push(quote! {
+            pub const #e_idnt: Self = Self(#val);
+        });
+        names.push(quote! {
+            (#e_name, #val)
+        });
+    }
+    quote! {
+        #[repr(transparent)]
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+        pub struct #en_idnt(u32);
+        impl #en_idnt {
+            #(#consts)*
+            const NAMES: &'static [(&'static str, u32)] = &[#(#names,)*];
+
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+            pub const fn all() -> Self {
+                Self(#all)
+            }
+            pub const fn bits(self) -> u32 {
+                self.0
+            }
+            /// Returns `None` if `bits` has flags this protocol version doesn't know about.
+            pub const fn from_bits(bits: u32) -> Option<Self> {
+                if bits & !#all == 0 { Some(Self(bits)) } else { None }
+            }
+            pub const fn from_bits_retain(bits: u32) -> Self {
+                Self(bits)
+            }
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+        }
+        impl ::std::ops::BitOr for #en_idnt {
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+        impl ::std::ops::BitOrAssign for #en_idnt {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+        impl ::std::ops::BitAnd for #en_idnt {
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+        impl ::std::ops::BitAndAssign for #en_idnt {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+        impl ::std::ops::Sub for #en_idnt {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 & !rhs.0)
+            }
+        }
+        impl From<#en_idnt> for u32 {
+            fn from(value: #en_idnt) -> u32 {
+                value.0
+            }
+        }
+        impl From<u32> for #en_idnt {
+            fn from(value: u32) -> Self {
+                Self(value)
+            }
+        }
+        impl ::std::fmt::Debug for #en_idnt {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(concat!(#en_name, "("))?;
+                let mut rest = self.0;
+                let mut first = true;
+                for (name, bits) in Self::NAMES {
+                    let named = if *bits == 0 { self.0 == 0 } else { rest & bits == *bits };
+                    if named {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        f.write_str(name)?;
+                        rest &= !bits;
+                        first = false;
+                    }
+                }
+                if rest != 0 || first {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_fmt(format_args!("{:#x}", rest))?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate_file(xml: &str, side: Side) -> syn::File {
+        let protocol = parser::Parser::new(xml).get_grotocol().unwrap();
+        let tokens = generate_protocol(&protocol, None, &[], side);
+        match syn::parse2::<syn::File>(tokens) {
+            Ok(file) => file,
+            Err(err) => panic!("invalid code for {}: {err}", protocol.name),
+        }
+    }
+
+    #[test]
+    fn bundled_protocols_are_valid_rust() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut paths = vec![root.join("protocols/wayland.xml")];
+        for entry in std::fs::read_dir(root.join("scr_protocols/protocols")).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "xml") {
+                paths.push(path);
+            }
+        }
+        assert!(paths.len() > 1);
+        for path in paths {
+            let xml = std::fs::read_to_string(path).unwrap();
+            generate_file(&xml, Side::Client);
+            generate_file(&xml, Side::Server);
+        }
+    }
+
+    // Names from wayland-protocols that used to produce invalid code (`xdg_toplevel.move`,
+    // `zwp_tablet_tool_v2.type`, `wl_output.transform`'s `90`), and worse ones third party
+    // files could have.
+    const AWKWARD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<protocol name="awkward">
+  <interface name="awk_thing" version="1">
+    <request name="move">
+      <arg name="seat" type="object" interface="awk_thing"/>
+      <arg name="serial" type="uint"/>
+    </request>
+    <request name="impl">
+      <arg name="type" type="uint" enum="type"/>
+      <arg name="self" type="int"/>
+      <arg name="fn" type="string"/>
+      <arg name="writer" type="uint"/>
+      <arg name="msg" type="uint"/>
+    </request>
+    <request name="async" type="destructor">
+    </request>
+    <request name="bind">
+      <arg name="version" type="uint"/>
+      <arg name="interface" type="string"/>
+      <arg name="id" type="new_id"/>
+      <arg name="foo-bar" type="int"/>
+      <arg name="foo_bar" type="int"/>
+      <arg name="new_id" type="uint"/>
+      <arg name="message" type="uint"/>
+    </request>
+    <request name="spawn">
+      <arg name="id" type="new_id" interface="awk_thing"/>
+      <arg name="new_awk_thing" type="uint"/>
+    </request>
+    <event name="type">
+      <arg name="type" type="uint" enum="type"/>
+      <arg name="impl" type="object" interface="awk_thing"/>
+      <arg name="parser" type="uint"/>
+      <arg name="handler" type="int"/>
+    </event>
+    <event name="self">
+      <arg name="super" type="uint" enum="transform"/>
+      <arg name="crate" type="new_id" interface="awk_thing"/>
+    </event>
+    <event name="announce">
+      <arg name="interface" type="uint"/>
+      <arg name="id" type="new_id"/>
+    </event>
+    <enum name="type">
+      <entry name="2d" value="0"/>
+      <entry name="3d" value="1"/>
+      <entry name="self" value="2"/>
+      <entry name="café" value="3"/>
+      <entry name="cafè" value="4"/>
+    </enum>
+    <enum name="transform" bitfield="true">
+      <entry name="90" value="1"/>
+      <entry name="flipped_90" value="2"/>
+      <entry name="[" value="4"/>
+      <entry name="type" value="8"/>
+      <entry name="a-b" value="16"/>
+      <entry name="a_b" value="32"/>
+    </enum>
+  </interface>
+</protocol>
+"#;
+
+    #[test]
+    fn awkward_names_are_valid_rust() {
+        generate_file(AWKWARD, Side::Server);
+        let code = generate_file(AWKWARD, Side::Client)
+            .into_token_stream()
+            .to_string();
+        for expected in [
+            "pub fn r#move",
+            "pub fn r#impl",
+            "pub fn r#async (self",
+            "r#type : WEnum < Type >",
+            "self_ : i32",
+            "writer_ : u32",
+            "fn r#type (",
+            "fn self_ (",
+            "_2d = 0u32",
+            "Caf = 3u32",
+            "pub const _90",
+            "pub const __ :",
+            // Distinct names that sanitize the same, and args named like the generated locals
+            "Caf_2 = 4u32",
+            "pub const A_B_2 :",
+            "version_ : u32 , interface_ : & str , version : u32 , foo_bar : i32 , foo_bar_2 : i32 , new_id_ : u32 , message : u32",
+            "interface_ : u32 , interface : & 'a str , version : u32 , id : u32",
+            "new_awk_thing_ : u32",
+        ] {
+            assert!(code.contains(expected), "missing `{expected}` in {code}");
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Turning protocol names into valid rust identifiers.
+//!
+//! Protocol files are written with C in mind, so names can be rust keywords (`move`, `type`),
+//! start with a digit (`wl_output.transform` has `90`) or, in third party files, contain
+//! anything at all. Every name the generated code uses goes through here.
+
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span};
+
+/// Keywords of every edition, including the reserved ones.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Keywords that can't be raw identifiers, these get a `_` suffix instead.
+const NOT_RAW: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Names the generated functions use for their own locals, arguments with these names
+/// get a `_` suffix so they don't shadow them.
+const LOCALS: &[&str] = &[
+    "client",
+    "conn",
+    "event",
+    "handler",
+    "msg",
+    "new_id",
+    "new_object",
+    "parser",
+    "reader",
+    "sender_id",
+    "writer",
+];
+
+/// Replaces everything that can't be part of an identifier with `_` and makes sure it
+/// doesn't start with a digit.
+pub(crate) fn sanitize(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    // A lone `_` isn't an identifier
+    if out.is_empty() || out == "_" {
+        return "__".to_string();
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn ident(name: String) -> Ident {
+    if !KEYWORDS.contains(&name.as_str()) {
+        Ident::new(&name, Span::call_site())
+    } else if NOT_RAW.contains(&name.as_str()) {
+        Ident::new(&format!("{name}_"), Span::call_site())
+    } else {
+        Ident::new_raw(&name, Span::call_site())
+    }
+}
+
+/// Modules, functions and fields, `type` becomes `r#type`.
+pub(crate) fn snake(name: &str) -> Ident {
+    ident(sanitize(name))
+}
+
+/// Arguments of requests and events, like [`snake`] but also steering clear of the
+/// generated code's locals.
+pub(crate) fn arg(name: &str) -> Ident {
+    let name = sanitize(name);
+    if LOCALS.contains(&name.as_str()) {
+        return Ident::new(&format!("{name}_"), Span::call_site());
+    }
+    ident(name)
+}
+
+/// The arguments of one message, like [`arg`] but also steering clear of `reserved`, the
+/// names the generated code only binds for this message. Names that come out the same, like
+/// `foo-bar` and `foo_bar`, are numbered.
+pub(crate) fn args<'a>(
+    names: impl IntoIterator<Item = &'a str>, reserved: &[String],
+) -> Vec<Ident> {
+    let mut taken: HashSet<String> = reserved.iter().cloned().collect();
+    names
+        .into_iter()
+        .map(|name| {
+            let sanitized = sanitize(name);
+            let ident = if reserved.contains(&sanitized) {
+                Ident::new(&format!("{sanitized}_"), Span::call_site())
+            } else {
+                arg(name)
+            };
+            unique(ident, &mut taken)
+        })
+        .collect()
+}
+
+/// The entries of one enum, converted by [`pascal`] or [`upper`] and numbered like [`args`].
+pub(crate) fn entries<'a>(
+    names: impl IntoIterator<Item = &'a str>, convert: fn(&str) -> Ident,
+) -> Vec<Ident> {
+    let mut taken = HashSet::new();
+    names
+        .into_iter()
+        .map(|name| unique(convert(name), &mut taken))
+        .collect()
+}
+
+/// `ident`, or `ident_2`, `ident_3`... if it's `taken` already.
+fn unique(ident: Ident, taken: &mut HashSet<String>) -> Ident {
+    let name = unraw(&ident);
+    if taken.insert(name.clone()) {
+        return ident;
+    }
+    (2..)
+        .map(|n| format!("{name}_{n}"))
+        .find(|numbered| taken.insert(numbered.clone()))
+        .map(|numbered| Ident::new(&numbered, Span::call_site()))
+        .unwrap()
+}
+
+/// Types and enum variants, `wl_surface` becomes `WlSurface`.
+pub(crate) fn pascal(name: &str) -> Ident {
+    let mut out = String::with_capacity(name.len());
+    let mut capitalize_next = true;
+    for c in sanitize(name).chars() {
+        if c == '_' {
+            capitalize_next = true;
+            continue;
+        }
+        if capitalize_next {
+            out.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            out.push(c);
+        }
+    }
+    // Only underscores, or a leading digit that was prefixed
+    if out.is_empty() {
+        out.push_str("__");
+    } else if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    ident(out)
+}
+
+/// Constants, `top_left` becomes `TOP_LEFT`.
+pub(crate) fn upper(name: &str) -> Ident {
+    ident(sanitize(&name.to_ascii_uppercase()))
+}
+
+/// `name` as written in generated code, without the `r#` of raw identifiers.
+pub(crate) fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    match name.strip_prefix("r#") {
+        Some(name) => name.to_string(),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords_become_raw() {
+        assert_eq!(snake("type").to_string(), "r#type");
+        assert_eq!(snake("move").to_string(), "r#move");
+        assert_eq!(snake("fn").to_string(), "r#fn");
+        assert_eq!(snake("impl").to_string(), "r#impl");
+        assert_eq!(snake("async").to_string(), "r#async");
+        assert_eq!(snake("gen").to_string(), "r#gen");
+        assert_eq!(unraw(&snake("type")), "type");
+    }
+
+    #[test]
+    fn keywords_that_cant_be_raw() {
+        assert_eq!(snake("self").to_string(), "self_");
+        assert_eq!(snake("super").to_string(), "super_");
+        assert_eq!(snake("crate").to_string(), "crate_");
+        assert_eq!(pascal("self").to_string(), "Self_");
+    }
+
+    #[test]
+    fn leading_digits() {
+        assert_eq!(pascal("90").to_string(), "_90");
+        assert_eq!(pascal("flipped_90").to_string(), "Flipped90");
+        assert_eq!(pascal("2d").to_string(), "_2d");
+        assert_eq!(upper("10bit").to_string(), "_10BIT");
+        assert_eq!(snake("3d_mode").to_string(), "_3d_mode");
+    }
+
+    #[test]
+    fn odd_characters() {
+        assert_eq!(snake("café").to_string(), "caf_");
+        assert_eq!(snake("a-b.c").to_string(), "a_b_c");
+        assert_eq!(snake("").to_string(), "__");
+        assert_eq!(snake("_").to_string(), "__");
+        assert_eq!(pascal("[").to_string(), "__");
+        assert_eq!(pascal("__").to_string(), "__");
+        assert_eq!(upper("ünder").to_string(), "_NDER");
+    }
+
+    #[test]
+    fn pascal_case() {
+        assert_eq!(pascal("wl_surface").to_string(), "WlSurface");
+        assert_eq!(pascal("zwp_tablet_tool_v2").to_string(), "ZwpTabletToolV2");
+        assert_eq!(pascal("global_remove").to_string(), "GlobalRemove");
+    }
+
+    #[test]
+    fn args_dont_shadow_locals() {
+        assert_eq!(arg("writer").to_string(), "writer_");
+        assert_eq!(arg("msg").to_string(), "msg_");
+        assert_eq!(arg("serial").to_string(), "serial");
+        assert_eq!(arg("type").to_string(), "r#type");
+        assert_eq!(arg("new_id").to_string(), "new_id_");
+    }
+
+    #[test]
+    fn collisions_are_numbered() {
+        let names = |idents: Vec<Ident>| idents.iter().map(Ident::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            names(args(["foo-bar", "foo_bar", "foo.bar"], &[])),
+            ["foo_bar", "foo_bar_2", "foo_bar_3"]
+        );
+        assert_eq!(names(args(["type", "type"], &[])), ["r#type", "type_2"]);
+        let reserved = ["interface".to_string(), "version".to_string()];
+        assert_eq!(
+            names(args(["version", "version_", "id"], &reserved)),
+            ["version_", "version__2", "id"]
+        );
+        assert_eq!(
+            names(entries(["café", "cafè", "caf"], pascal)),
+            ["Caf", "Caf_2", "Caf_3"]
+        );
+        assert_eq!(names(entries(["a-b", "a_b"], upper)), ["A_B", "A_B_2"]);
+    }
+}
@@ -1,566 +1,331 @@
-#![allow(unused)]
-extern crate proc_macro;
+//! Rust code generation for wayland protocol xml files.
+//!
+//! The [`generate!`](https://docs.rs/scr_scanner_macros) macro expands the code in place,
+//! [`Generator`] writes the same code to a file, typically from a `build.rs`:
+//!
+//! ```no_run
+//! scr_scanner::Generator::new("./protocols/xdg-shell.xml")
+//!     .write_to_out_dir("xdg_shell.rs")
+//!     .unwrap();
+//! ```
+//!
+//! and then in the crate, next to the imports the generated code relies on:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/xdg_shell.rs"));
+//! ```
+//!
+//! Interfaces of the core protocol are looked up in `scratchway::wayland`, interfaces of
+//! other protocols have to be imported with [`Generator::import`] or
+//! [`Generator::import_protocol`].
+//!
+//! With [`Side::Server`] resources for compositors are generated instead of proxies, their
+//! core protocol lives in `scratchway::server::wayland`.
 
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::{ToTokens, format_ident, quote};
+use std::fmt;
+use std::path::{Path, PathBuf};
 
-mod parser;
+use proc_macro2::TokenStream;
 
-#[proc_macro]
-pub fn generate(path: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let mut path = path.into_iter();
+mod codegen;
+mod ident;
+pub mod parser;
 
-    let Some(proc_macro::TokenTree::Literal(path)) = path.next() else {
-        return quote!(compile_error!("Invalid arguements")).into();
-    };
+pub use codegen::Side;
 
-    let path = {
-        let p = path.to_string();
-        let mut begin = 0;
-        let mut end = p.len();
-        if p.starts_with('"') {
-            begin += 1;
-        }
-        if p.starts_with('"') {
-            end -= 1;
-        }
-        let path = p[begin..end].to_string();
-        if let Some(mani_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
-            std::path::PathBuf::from(mani_dir).join(path)
-        } else {
-            std::path::PathBuf::from(path)
-        }
-    };
+pub struct Generator {
+    path:       PathBuf,
+    interfaces: Option<Vec<String>>,
+    imports:    Vec<ImportSpec>,
+    side:       Side,
+}
 
-    if !path.exists() {
-        panic!("Provided path doesn't exists, {}", path.display());
-    }
+enum ImportSpec {
+    Interface {
+        interface: String,
+        module:    String,
+    },
+    Protocol {
+        path:   PathBuf,
+        module: String,
+    },
+}
 
-    if !path.is_file() {
-        panic!("Provided path isn't a file, {}", path.display());
+const CORE_PROTOCOL: &str = include_str!("../../protocols/wayland.xml");
+const CORE_MODULE: &str = "scratchway::wayland";
+const CORE_SERVER_MODULE: &str = "scratchway::server::wayland";
+
+impl Generator {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path:       path.as_ref().to_path_buf(),
+            interfaces: None,
+            imports:    Vec::new(),
+            side:       Side::Client,
+        }
     }
 
-    let name = Ident::new("App", Span::call_site());
-    let file = std::fs::read_to_string(path).unwrap();
-    let mut reader = quick_xml::Reader::from_str(&file);
-    reader.config_mut().trim_text(true);
+    /// Generate code for the given end of the connection, the client by default.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
 
-    let parser = parser::Parser::new(&file);
-    let Ok(protocol) = parser.get_grotocol() else {
-        return quote!(compile_error("Failed to parse protocol")).into();
-    };
+    /// Refer to `interface` as `{module}::{interface}`, e.g. `crate::xdg_shell` for `xdg_toplevel`.
+    pub fn import(mut self, interface: impl Into<String>, module: impl Into<String>) -> Self {
+        self.imports.push(ImportSpec::Interface {
+            interface: interface.into(),
+            module:    module.into(),
+        });
+        self
+    }
 
-    let interfaces = protocol.interfaces.iter().map(|o| {
-        let iface_name = &o.name;
-        if iface_name == "wl_display" || iface_name == "wl_registry" {
-            return quote! {};
-        }
-        let iface_mod = Ident::new(&o.name, Span::call_site());
-        let object_name = Ident::new(&o.name.snake_to_pascal(), Span::call_site());
-        let events_enum = o.events.iter().map(|e| {
-            let ev_idnt = Ident::new(&e.name.snake_to_pascal(), Span::call_site());
-            quote!{
-                #ev_idnt
-            }
+    /// Import every interface of the protocol at `path`, generated in `module`.
+    pub fn import_protocol(mut self, path: impl AsRef<Path>, module: impl Into<String>) -> Self {
+        self.imports.push(ImportSpec::Protocol {
+            path:   path.as_ref().to_path_buf(),
+            module: module.into(),
         });
+        self
+    }
 
-        let reqs = o.requests.iter().enumerate().map(|(i, r)| {
-            let req_idnt = if r.name != "move" { Ident::new(&r.name, Span::call_site()) } else {
-                format_ident!("_{}", r.name)
-            };
-            let mut fn_body = Vec::new();
-            let mut params = Vec::new();
-            let mut size = 8usize;
-            let mut args = Vec::new();
-            let mut log_msg = format!("{{}}.{}(", r.name);
-            let opcode = i as u16;
-            let (mut return_ty, mut return_stmnt)  = (quote! { () }, quote! {});
-            for arg in &r.args {
-                let arg_idnt = Ident::new(&arg.name, Span::call_site());
-                match &arg.arg_type {
-                    parser::ArgType::Int => {
-                        size += 4;
-                        params.push(quote!{
-                            #arg_idnt: i32
-                        });
-                        fn_body.push(quote!{
-                            msg.write_i32(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{}, ");
-                    },
-                    parser::ArgType::Uint => {
-                        size += 4;
-                        params.push(quote!{
-                            #arg_idnt: u32
-                        });
-                        fn_body.push(quote!{
-                            msg.write_u32(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{}, ");
-                    },
-                    parser::ArgType::Enum(_) => {
-                        size += 4;
-                        params.push(quote!{
-                            #arg_idnt: u32
-                        });
-                        fn_body.push(quote!{
-                            msg.write_u32(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{}, ");
-                    },
-                    parser::ArgType::Fixed => {
-                        size += 4;
-                        params.push(quote!{
-                            #arg_idnt: f32
-                        });
-                        fn_body.push(quote!{
-                            msg.write_fixed(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{:.2}, ");
-                    },
-                    parser::ArgType::String { allow_null } => {
-                        size += 54;
-                        params.push(quote!{
-                            #arg_idnt: &str
-                        });
-                        fn_body.push(quote!{
-                            msg.write_string(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("\"{}\", ");
-                    },
-                    parser::ArgType::Object { allow_null, iface } => {
-                        size += 4;
-                        let iface_idnt = Ident::new(&iface.as_ref().unwrap().snake_to_pascal(), Span::call_site());
-                        let mod_idnt = Ident::new(iface.as_ref().unwrap(), Span::call_site());
-                        let arg_id = format_ident!("{}_id", arg.name);
-                        if *allow_null {
-                            params.push(quote! {
-                                #arg_idnt: Option<&#mod_idnt::#iface_idnt>
-                            });
-                            fn_body.push(quote!{
-                                let #arg_id = #arg_idnt.map_or(0, |o| o.id());
-                            })
-                        } else {
-                            params.push(quote! {
-                                #arg_idnt: &#mod_idnt::#iface_idnt
-                            });
-                            fn_body.push(quote!{
-                                let #arg_id = #arg_idnt.id();
-                            })
-                        }
-                        fn_body.push(quote!{
-                            msg.write_u32(#arg_id);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{:?}, ");
-                    },
-                    parser::ArgType::NewId { iface } => {
-                        size += 4;
-                        let new_idnt = format_ident!("new_{}", iface.as_ref().unwrap());
-                        let new_type_ob = format_ident!("{}", iface.as_ref().unwrap().snake_to_pascal());
-                        let iface_mod = format_ident!("{}", iface.as_ref().unwrap());
-                        return_stmnt = quote! {
-                            #new_idnt
-                        };
-                        return_ty = quote! {
-                            #iface_mod::#new_type_ob
-                        };
-                        fn_body.push(quote!{
-                            let new_id = writer.new_id();
-                            let #new_idnt: #return_ty = Object::from_id(new_id);
-                            msg.write_u32(new_id);
-                        });
-                        args.push(quote! {
-                            #new_idnt
-                        });
-                        log_msg.push_str("new {}, ");
-                    },
-                    parser::ArgType::Array => {
-                        size += 28;
-                        params.push(quote!{
-                            #arg_idnt: &[u32]
-                        });
-                        fn_body.push(quote!{
-                            msg.write_array(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{:?}, ");
-                    },
-                    parser::ArgType::Fd => {
-                        params.push(quote!{
-                            #arg_idnt: i32
-                        });
-                        fn_body.push(quote!{
-                            writer.add_fd(#arg_idnt);
-                        });
-                        args.push(quote! {
-                            #arg_idnt
-                        });
-                        log_msg.push_str("{}, ");
-                    },
-                }
-            }
+    /// Only generate the given interfaces instead of the whole protocol.
+    pub fn interfaces<S: Into<String>>(mut self, interfaces: impl IntoIterator<Item = S>) -> Self {
+        self.interfaces = Some(interfaces.into_iter().map(Into::into).collect());
+        self
+    }
 
-            if !r.args.is_empty() {
-                fn_body.push(quote! {
-                    msg.build();
-                });
-            }
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
 
-            let log_msg = {
-                let msg = log_msg.trim_end();
-                let mut end = msg.len();
-                if let Some(',') = msg.chars().last() {
-                    end -= 1;
-                }
-                format!("{})", &msg[..end])
-            };
-            quote!{
-                pub fn #req_idnt(&self, writer: &WaylandBuffer<Writer>, #(#params,)*) -> #return_ty {
-                    let mut msg = Message::<#size>::new(self.id, #opcode);
-                    #(#fn_body)*
-                    writer.write_request(msg.data());
-                    {
-                        log!(WAYLAND, #log_msg, self, #(#args,)*);
-                    }
-                    #return_stmnt
-                }
-            }
-        });
+    pub fn generate(&self) -> Result<TokenStream, Error> {
+        let file =
+            std::fs::read_to_string(&self.path).map_err(|err| Error::Io(self.path.clone(), err))?;
 
-        let mut ev_variants = Vec::<TokenStream>::new();
-        let mut ev_parse = Vec::<TokenStream>::new();
-        let mut ev_lifetime = false;
+        let protocol = parser::Parser::new(&file)
+            .get_grotocol()
+            .map_err(|err| Error::Parse(self.path.clone(), err))?;
 
-        for (i, ev) in o.events.iter().enumerate() {
-            let i = i as u16;
-            let ev_idnt = Ident::new(&ev.name.snake_to_pascal(), Span::call_site());
-            let mut variant_parse = Vec::new();
-            let mut ev_fields: Vec<TokenStream> = Vec::new();
-            let mut log_msg = format!("==> {{}}.{}(", ev.name);
-            let mut args = Vec::new();
-            let ty = quote!{ u32 };
-            if ev.args.is_empty() {
-                ev_variants.push(quote!{
-                    #ev_idnt
-                });
-                log_msg.push_str(")");
-                variant_parse.push(quote!{
-                    {
-                        log!(WAYLAND, #log_msg, self, #(#args,)*);
-                    }
-                    Self::Event::#ev_idnt
-                });
-            } else {
-                let mut fields = Vec::new();
-                for arg in &ev.args {
-                    let field_idnt = Ident::new(&arg.name, Span::call_site());
-                    let field_type = match &arg.arg_type {
-                        parser::ArgType::Int => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_i32();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{}, ");
-                            quote! { i32 }
-                        },
-                        parser::ArgType::Uint => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_u32();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{}, ");
-                            quote! { u32 }
-                        },
-                        parser::ArgType::Enum(_) => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_u32();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{}, ");
-                            quote! { u32 }
-                        },
-                        parser::ArgType::Fixed => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_fixed();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{:.2}, ");
-                            quote! { f32 }
-                        },
-                        parser::ArgType::String { allow_null } => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_string();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("\"{}\", ");
-                            ev_lifetime = true;
-                            quote! { &'a str }
-                        },
-                        parser::ArgType::Object { allow_null, iface } => {
-                            let iface_mod = format_ident!("{}", iface.as_ref().unwrap());
-                            let iface_obj = format_ident!("{}", iface.as_ref().unwrap().snake_to_pascal());
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{:?}, ");
-                            if *allow_null {
-                                variant_parse.push(quote!{
-                                    let #field_idnt = {
-                                        let id = parser.get_u32();
-                                        if id == 0 {
-                                            None
-                                        } else {
-                                            Some(Object::from_id(id))
-                                        }
-                                    };
-                                });
-                                quote! { Option<#iface_mod::#iface_obj> }
-                            } else {
-                                variant_parse.push(quote!{
-                                    let #field_idnt = Object::from_id(parser.get_u32());
-                                });
-                                quote! { #iface_mod::#iface_obj }
-                            }
-                        },
-                        parser::ArgType::NewId { iface } => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_u32();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{}, ");
-                            quote! { u32 }
-                        },
-                        parser::ArgType::Array => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = parser.get_array();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{:?}, ");
-                            ev_lifetime = true;
-                            quote! { &'a [u32] }
-                        },
-                        parser::ArgType::Fd => {
-                            variant_parse.push(quote!{
-                                let #field_idnt = reader.get_fd().unwrap();
-                            });
-                            args.push(quote! {
-                                #field_idnt
-                            });
-                            log_msg.push_str("{:?}, ");
-                            quote! { std::os::fd::OwnedFd }
-                        },
-                    };
-                    fields.push(quote! {
-                        #field_idnt
-                    });
-                    ev_fields.push(quote!{
-                        #field_idnt: #field_type
+        if let Some(wanted) = &self.interfaces {
+            for name in wanted {
+                if !protocol.interfaces.iter().any(|i| i.name == *name) {
+                    return Err(Error::UnknownInterface {
+                        protocol:  protocol.name,
+                        interface: name.clone(),
                     });
                 }
-                let log_msg = {
-                    let msg = log_msg.trim_end();
-                    let mut end = msg.len();
-                    if let Some(',') = msg.chars().last() {
-                        end -= 1;
-                    }
-                    format!("{})", &msg[..end])
-                };
-                variant_parse.push(quote! {
-                    {
-                        log!(WAYLAND, #log_msg, self, #(#args,)*);
-                    }
-                    Self::Event::#ev_idnt { #(#fields,)* }
-                });
-
-                ev_variants.push(quote!{
-                    #ev_idnt {
-                        #(#ev_fields,)*
-                    }
-                });
             }
-            ev_parse.push(quote!{
-                #i => {
-                    #(#variant_parse)*
-                }
-            });
         }
 
-        let ev_lifetime = if ev_lifetime { quote! {<'a>} } else { quote! {} };
+        let imports = self.resolve_imports()?;
+        Ok(codegen::generate_protocol(
+            &protocol,
+            self.interfaces.as_deref(),
+            &imports,
+            self.side,
+        ))
+    }
 
-        let mut event_enum = quote!{
-            #[derive(Debug)]
-            pub enum Event #ev_lifetime {
-                 #(#ev_variants,)*
-            }
+    fn resolve_imports(&self) -> Result<Vec<codegen::Import>, Error> {
+        let core = parser::Parser::new(CORE_PROTOCOL)
+            .get_grotocol()
+            .map_err(|err| Error::Parse("wayland.xml".into(), err))?;
+        let core_module = match self.side {
+            Side::Client => CORE_MODULE,
+            Side::Server => CORE_SERVER_MODULE,
         };
+        let mut imports = codegen::Import::from_protocol(&core, core_module);
 
-        let mut enums = Vec::<TokenStream>::new();
-        for en in &o.enums {
-            let en_idnt = Ident::new(&en.name.snake_to_pascal(), Span::call_site());
-            if en.is_bitfield {
-                for e in &en.items {
-                    let e_idnt = format_ident!("{}_{}", en.name.to_uppercase(), e.name.to_uppercase());
-                    let val = e.value;
-                    enums.push(quote! {
-                        pub const #e_idnt: u32 = #val;
-                    })
+        for spec in &self.imports {
+            match spec {
+                ImportSpec::Interface {
+                    interface,
+                    module,
+                } => {
+                    check_module(module)?;
+                    imports.push(codegen::Import {
+                        interface: interface.clone(),
+                        module:    module.clone(),
+                        bitfields: Vec::new(),
+                    });
                 }
-            } else {
-                let mut variants = Vec::new();
-                for e in &en.items {
-                    let e_idnt = {
-                        if let Some('A'..'z') = e.name.chars().next() {
-                            format_ident!("{}", e.name.snake_to_pascal())
-                        } else {
-                            format_ident!("_{}", e.name.snake_to_pascal())
-                        }
-                    };
-                    let val = e.value;
-                    variants.push(quote! {
-                        #e_idnt = #val
-                    })
+                ImportSpec::Protocol {
+                    path,
+                    module,
+                } => {
+                    check_module(module)?;
+                    let file = std::fs::read_to_string(path)
+                        .map_err(|err| Error::Io(path.clone(), err))?;
+                    let protocol = parser::Parser::new(&file)
+                        .get_grotocol()
+                        .map_err(|err| Error::Parse(path.clone(), err))?;
+                    imports.extend(codegen::Import::from_protocol(&protocol, module));
                 }
-                enums.push(quote! {
-                    #[repr(u32)]
-                    #[derive(Debug, Copy, Clone, PartialEq)]
-                    pub enum #en_idnt {
-                        #(#variants,)*
-                    }
-                    impl PartialEq<#en_idnt> for u32 {
-                        fn eq(&self, other: &#en_idnt) -> bool {
-                            *self == *other as u32
-                        }
-                    }
-                    impl PartialEq<u32> for #en_idnt {
-                        fn eq(&self, other: &u32) -> bool {
-                            *self as u32 == *other
-                        }
-                    }
-                    impl Eq for #en_idnt {}
-                })
             }
         }
-        // fucking hell
-        let parse_body = if ev_parse.is_empty() {
-            quote! {
-                unreachable!();
-            }
-        } else {
-            quote! {
-                let parser = event.parser();
-                match event.header.opcode {
-                    #(#ev_parse)*,
-                    _ => {
-                        unreachable!();
-                    }
-                }
-            }
-        };
-        quote! {
+        Ok(imports)
+    }
 
-            pub mod #iface_mod {
-                use super::*;
-                pub struct #object_name {
-                    id: u32,
-                    interface: &'static str
-                }
-                impl ::std::fmt::Display for #object_name {
-                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                        f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
-                    }
-                }
-                impl ::std::fmt::Debug for #object_name {
-                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                        f.write_fmt(format_args!("{}#{}", Self::INTERFACE, self.id))
-                    }
-                }
-                #event_enum
-                #(#enums)*
-                impl #object_name {
-                    pub const INTERFACE: &'static str = #iface_name;
-                    #(#reqs)*
-                }
-                impl Object for #object_name {
-                    type Event<'a> = Event #ev_lifetime;
-                    fn from_id(id: u32) -> Self {
-                        Self {
-                            id,
-                            interface: Self::INTERFACE
-                        }
-                    }
-                    fn id(&self) -> u32 {
-                        self.id
-                    }
-                    fn interface(&self) -> &'static str {
-                        Self::INTERFACE
-                    }
-                    fn parse_event<'a>(&self, reader: &WaylandBuffer<Reader>, event: WlEvent<'a>) -> Self::Event<'a> {
-                        #parse_body
-                    }
-                }
+    /// The generated code, formatted.
+    pub fn generate_string(&self) -> Result<String, Error> {
+        let tokens = self.generate()?;
+        let file =
+            syn::parse2::<syn::File>(tokens).map_err(|err| Error::Format(err.to_string()))?;
+        Ok(format!(
+            "// Generated by scr-scanner from {}, do not edit.\n\n{}",
+            self.path.display(),
+            prettyplease::unparse(&file)
+        ))
+    }
+
+    /// Writes the formatted code to `$OUT_DIR/file_name`, meant to be called from build scripts.
+    ///
+    /// Also tells cargo to rerun the build script when the protocol file changes.
+    pub fn write_to_out_dir(&self, file_name: impl AsRef<Path>) -> Result<PathBuf, Error> {
+        let out_dir = std::env::var_os("OUT_DIR").ok_or(Error::NoOutDir)?;
+        let out = PathBuf::from(out_dir).join(file_name);
+        let code = self.generate_string()?;
+        std::fs::write(&out, code).map_err(|err| Error::Io(out.clone(), err))?;
+        println!("cargo:rerun-if-changed={}", self.path.display());
+        for spec in &self.imports {
+            if let ImportSpec::Protocol {
+                path, ..
+            } = spec
+            {
+                println!("cargo:rerun-if-changed={}", path.display());
             }
         }
+        Ok(out)
+    }
+}
 
-    });
+fn check_module(module: &str) -> Result<(), Error> {
+    match syn::parse_str::<syn::Path>(module) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(Error::InvalidModule(module.into())),
+    }
+}
 
-    interfaces.collect::<TokenStream>().into()
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    Io(PathBuf, std::io::Error),
+    Parse(PathBuf, parser::ParseError),
+    UnknownInterface {
+        protocol:  String,
+        interface: String,
+    },
+    InvalidModule(String),
+    Format(String),
+    NoOutDir,
 }
 
-trait SnakeToPascal {
-    fn snake_to_pascal(&self) -> String;
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "couldn't access {}: {err}", path.display()),
+            Self::Parse(path, err) => write!(f, "failed to parse {}:{err}", path.display()),
+            Self::UnknownInterface {
+                protocol,
+                interface,
+            } => write!(f, "protocol `{protocol}` has no interface `{interface}`"),
+            Self::InvalidModule(module) => write!(f, "`{module}` isn't a valid module path"),
+            Self::Format(err) => write!(f, "generated code isn't valid rust: {err}"),
+            Self::NoOutDir => f.write_str("OUT_DIR isn't set, is this running in a build script?"),
+        }
+    }
 }
 
-impl<T: AsRef<str>> SnakeToPascal for T {
-    fn snake_to_pascal(&self) -> String {
-        let str = self.as_ref();
-        let mut output = String::with_capacity(str.len());
-        let mut capatilize_next = false;
-        for (i, c) in str.chars().enumerate() {
-            if c == '_' {
-                capatilize_next = true;
-                continue;
-            }
-            if capatilize_next || i == 0 {
-                output.push(c.to_ascii_uppercase());
-                capatilize_next = false;
-            } else {
-                output.push(c)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (protocol file, module) of the protocols in `scr_protocols`, generated like its
+    /// `build.rs` does. Paths are relative to this crate, tests run from there.
+    const PROTOCOLS: &[(&str, &str)] = &[
+        ("../scr_protocols/protocols/xdg-shell.xml", "xdg_shell"),
+        ("../scr_protocols/protocols/viewporter.xml", "viewporter"),
+        (
+            "../scr_protocols/protocols/single-pixel-buffer-v1.xml",
+            "single_pixel_buffer_v1",
+        ),
+        (
+            "../scr_protocols/protocols/wlr-layer-shell-unstable-v1.xml",
+            "wlr_layer_shell_unstable_v1",
+        ),
+        (
+            "../scr_protocols/protocols/wlr-screencopy-unstable-v1.xml",
+            "wlr_screencopy_unstable_v1",
+        ),
+        (
+            "../scr_protocols/protocols/cursor-shape-v1.xml",
+            "cursor_shape_v1",
+        ),
+        ("../scr_protocols/protocols/tablet-v2.xml", "tablet_v2"),
+        (
+            "../scr_protocols/protocols/xdg-decoration-unstable-v1.xml",
+            "xdg_decoration_unstable_v1",
+        ),
+    ];
+
+    /// Compares the output of `generator` with `snapshots/{name}.rs`, or rewrites the file
+    /// when `UPDATE_SNAPSHOTS` is set. Returns a description of the mismatch.
+    fn check_snapshot(generator: &Generator, name: &str) -> Option<String> {
+        let code = match generator.generate_string() {
+            Ok(code) => code,
+            Err(err) => return Some(format!("{name}: {err}")),
+        };
+        let path = Path::new("snapshots").join(format!("{name}.rs"));
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::write(&path, code).unwrap();
+            return None;
+        }
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(expected) => expected,
+            Err(err) => return Some(format!("{}: {err}", path.display())),
+        };
+        let mismatch = code.lines().zip(expected.lines()).position(|(a, b)| a != b);
+        match mismatch {
+            Some(line) => Some(format!(
+                "{}:{}: expected `{}`, generated `{}`",
+                path.display(),
+                line + 1,
+                expected.lines().nth(line).unwrap(),
+                code.lines().nth(line).unwrap()
+            )),
+            None if code != expected => Some(format!(
+                "{}: expected {} lines, generated {}",
+                path.display(),
+                expected.lines().count(),
+                code.lines().count()
+            )),
+            None => None,
+        }
+    }
+
+    /// The generated code of every bundled protocol matches the files in `snapshots/`, so
+    /// changes to the generated API show up in review. After an intended change regenerate
+    /// them with `UPDATE_SNAPSHOTS=1 cargo test -p scr_scanner snapshots`.
+    #[test]
+    fn snapshots() {
+        let mut failures = Vec::new();
+        let core = Generator::new("../protocols/wayland.xml");
+        failures.extend(check_snapshot(&core, "wayland"));
+        failures.extend(check_snapshot(&core.side(Side::Server), "wayland_server"));
+        for (path, module) in PROTOCOLS {
+            let mut generator = Generator::new(path);
+            for (other, other_module) in PROTOCOLS.iter().filter(|(other, _)| other != path) {
+                generator = generator.import_protocol(other, format!("crate::{other_module}"));
             }
+            failures.extend(check_snapshot(&generator, module));
         }
-        output
+        assert!(
+            failures.is_empty(),
+            "generated code changed, rerun with UPDATE_SNAPSHOTS=1 if that's intended:\n{}",
+            failures.join("\n")
+        );
     }
 }
@@ -66,8 +66,8 @@ mod types {
         Int,
         /// 32-bit unsigend integer.
         Uint,
-        /// 32-bit integer referencing a value of a given enum.
-        Enum(String),
+        /// 32-bit integer referencing a value of a given enum, `signed` if it's an `int`.
+        Enum { name: String, signed: bool },
         /// Sigend 24.8 decimal number.
         Fixed,
         /// Length-prefixed null-terimnated string.
@@ -94,6 +94,7 @@ mod types {
     }
 }
 pub struct Parser<'a> {
+    src:    &'a str,
     reader: quick_xml::Reader<&'a [u8]>,
 }
 
@@ -104,7 +105,8 @@ pub enum Error {
     UnexpectedArgType(String),
     UnexpectedEof,
     MissingAttribute(&'static str),
-    XmlError(String),
+    InvalidNumber(String),
+    Xml(String),
     NonUtf8Data(str::Utf8Error),
 }
 
@@ -117,21 +119,38 @@ impl fmt::Display for Error {
             Self::UnexpectedArgType(ty) => write!(f, "unexpected argument type: {ty}"),
             Self::UnexpectedEof => f.write_str("unexpeced end of file"),
             Self::MissingAttribute(attr) => write!(f, "missing attribute: {attr}"),
-            Self::XmlError(error) => write!(f, "xml parsing error: {error}"),
+            Self::InvalidNumber(num) => write!(f, "invalid number: {num}"),
+            Self::Xml(error) => write!(f, "xml parsing error: {error}"),
             Self::NonUtf8Data(utf8_error) => utf8_error.fmt(f),
         }
     }
 }
 
+/// An [`Error`] along with where in the file it happened.
+#[derive(Debug)]
+pub struct ParseError {
+    pub error:  Error,
+    pub line:   usize,
+    pub column: usize,
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.error)
+    }
+}
+
 impl From<quick_xml::Error> for Error {
     fn from(value: quick_xml::Error) -> Self {
-        Self::XmlError(value.to_string())
+        Self::Xml(value.to_string())
     }
 }
 
 impl From<quick_xml::events::attributes::AttrError> for Error {
     fn from(value: quick_xml::events::attributes::AttrError) -> Self {
-        Self::XmlError(value.to_string())
+        Self::Xml(value.to_string())
     }
 }
 
@@ -145,10 +164,30 @@ impl<'a> Parser<'a> {
     pub fn new(str: &'a str) -> Self {
         let mut reader = quick_xml::Reader::from_str(str);
         reader.config_mut().trim_text(true);
-        Self { reader }
+        Self {
+            src: str,
+            reader,
+        }
+    }
+
+    pub fn get_grotocol(mut self) -> Result<Protocol<'a>, ParseError> {
+        self.read_protocol().map_err(|error| {
+            // quick-xml keeps track of where its own errors happened, ours are
+            // found right after reading the offending tag
+            let pos = match error {
+                Error::Xml(_) => self.reader.error_position(),
+                _ => self.reader.buffer_position(),
+            };
+            let (line, column) = line_column(self.src, pos as usize);
+            ParseError {
+                error,
+                line,
+                column,
+            }
+        })
     }
 
-    pub fn get_grotocol(mut self) -> Result<Protocol<'a>, Error> {
+    fn read_protocol(&mut self) -> Result<Protocol<'a>, Error> {
         loop {
             match self.reader.read_event()? {
                 XmlEvent::Eof => return Err(Error::UnexpectedEof),
@@ -200,12 +239,11 @@ impl<'a> Parser<'a> {
                 .ok_or(Error::MissingAttribute("interface.name"))?
                 .unescape_value()?
                 .into_owned(),
-            version: tag
-                .try_get_attribute("version")?
-                .ok_or(Error::MissingAttribute("interface.version"))?
-                .unescape_value()?
-                .parse()
-                .unwrap(),
+            version:     parse_number(
+                tag.try_get_attribute("version")?
+                    .ok_or(Error::MissingAttribute("interface.version"))?
+                    .unescape_value()?,
+            )?,
             description: None,
             requests: Vec::new(),
             events: Vec::new(),
@@ -243,9 +281,9 @@ impl<'a> Parser<'a> {
             match attr.key.as_ref() {
                 b"name" => name = Some(attr.unescape_value()?.into_owned()),
                 b"type" => kind = Some(attr.unescape_value()?.into_owned()),
-                b"since" => since = attr.unescape_value()?.parse().unwrap(),
+                b"since" => since = parse_number(attr.unescape_value()?)?,
                 b"deprecated-since" => {
-                    deprecated_since = Some(attr.unescape_value()?.parse().unwrap())
+                    deprecated_since = Some(parse_number(attr.unescape_value()?)?)
                 }
                 _ => (),
             }
@@ -373,7 +411,10 @@ impl<'a> Parser<'a> {
                 .ok_or(Error::MissingAttribute("arg.type"))?
                 .as_str()
             {
-                "int" | "uint" if enum_ty.is_some() => ArgType::Enum(enum_ty.unwrap()),
+                ty @ ("int" | "uint") if enum_ty.is_some() => ArgType::Enum {
+                    name:   enum_ty.unwrap(),
+                    signed: ty == "int",
+                },
                 "int" => ArgType::Int,
                 "uint" => ArgType::Uint,
                 "fixed" => ArgType::Fixed,
@@ -401,7 +442,7 @@ impl<'a> Parser<'a> {
             match attr.key.as_ref() {
                 b"name" => name = Some(attr.unescape_value()?.into_owned()),
                 b"value" => value = Some(attr.unescape_value()?.into_owned()),
-                b"since" => since = attr.unescape_value()?.parse().unwrap(),
+                b"since" => since = parse_number(attr.unescape_value()?)?,
                 b"summary" => summary = Some(attr.unescape_value()?.into_owned()),
                 _ => (),
             }
@@ -419,13 +460,7 @@ impl<'a> Parser<'a> {
             }
         }
 
-        let value = value.map(|v| {
-            if let Some(v) = v.strip_prefix("0x") {
-                u32::from_str_radix(v, 16).unwrap()
-            } else {
-                v.parse().unwrap()
-            }
-        });
+        let value = value.map(parse_number).transpose()?;
 
         Ok(EnumItem {
             name: name.ok_or(Error::MissingAttribute("enum.entry.name"))?,
@@ -438,3 +473,86 @@ impl<'a> Parser<'a> {
         })
     }
 }
+
+fn parse_number(value: impl AsRef<str>) -> Result<u32, Error> {
+    let value = value.as_ref();
+    let parsed = if let Some(hex) = value.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16)
+    } else {
+        value.parse()
+    };
+    parsed.map_err(|_| Error::InvalidNumber(value.into()))
+}
+
+/// 1-based line and column of the byte offset `pos` in `src`.
+fn line_column(src: &str, pos: usize) -> (usize, usize) {
+    let before = &src.as_bytes()[..pos.min(src.len())];
+    let line = before.iter().filter(|b| **b == b'\n').count() + 1;
+    let line_start = before
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |i| i + 1);
+    (line, pos.min(src.len()) - line_start + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(xml: &str) -> ParseError {
+        match Parser::new(xml).get_grotocol() {
+            Ok(protocol) => panic!("{} parsed", protocol.name),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn line_column() {
+        let src = "ab\ncd\n\nef";
+        assert_eq!(super::line_column(src, 0), (1, 1));
+        assert_eq!(super::line_column(src, 2), (1, 3));
+        assert_eq!(super::line_column(src, 3), (2, 1));
+        assert_eq!(super::line_column(src, 7), (4, 1));
+        assert_eq!(super::line_column(src, 100), (4, 3));
+    }
+
+    #[test]
+    fn errors_point_after_the_offending_tag() {
+        let err = parse_error(
+            "<protocol name=\"p\">\n  <interface name=\"i\" version=\"1\">\n    <request>\n",
+        );
+        assert!(
+            matches!(err.error, Error::MissingAttribute("message.name")),
+            "{err}"
+        );
+        assert_eq!((err.line, err.column), (3, 14));
+
+        let err = parse_error("<protocol name=\"p\">\n  <interface name=\"i\" version=\"x1\">");
+        assert!(
+            matches!(&err.error, Error::InvalidNumber(num) if num == "x1"),
+            "{err}"
+        );
+        assert_eq!((err.line, err.column), (2, 36));
+
+        let err = parse_error("<protocol name=\"p\">\n\n  <enum name=\"e\">");
+        assert!(
+            matches!(&err.error, Error::UnexpectedTag(tag) if tag == "enum"),
+            "{err}"
+        );
+        assert_eq!((err.line, err.column), (3, 18));
+        assert_eq!(err.to_string(), "3:18: unexpected tag: enum");
+    }
+
+    #[test]
+    fn xml_errors_point_at_the_error() {
+        let err = parse_error(
+            "<protocol name=\"p\">\n  <interface name=\"i\" version=\"1\">\n  </protocol>",
+        );
+        assert!(matches!(err.error, Error::Xml(_)), "{err}");
+        assert_eq!((err.line, err.column), (3, 3));
+
+        let err = parse_error("<protocol name=\"p\">\n  <interface name=\"i\" version=\"1\">\n");
+        assert!(matches!(err.error, Error::UnexpectedEof), "{err}");
+        assert_eq!(err.line, 3);
+    }
+}
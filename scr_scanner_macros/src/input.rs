@@ -0,0 +1,344 @@
+//! Parsing of the `generate!` arguments.
+//!
+//! Either a single path literal, `generate!("./protocols/foo.xml")`, or comma separated
+//! settings, `generate!(path = "./protocols/foo.xml", interfaces = ["foo_manager"])`.
+//!
+//! Interfaces of other protocols are mapped to the module they're generated in with
+//! `imports = ["xdg_toplevel" = crate::xdg_shell]` or, for a whole protocol,
+//! `protocols = ["./protocols/xdg-shell.xml" = crate::xdg_shell]`.
+
+use proc_macro2::{Delimiter, Literal, Span, TokenStream, TokenTree};
+use quote::quote_spanned;
+
+pub struct Settings {
+    pub path:       String,
+    pub path_span:  Span,
+    /// Only generate these interfaces, all of them if `None`.
+    pub interfaces: Option<Vec<(String, Span)>>,
+    /// Interface name to module path.
+    pub imports:    Vec<Mapping>,
+    /// Protocol file to module path.
+    pub protocols:  Vec<Mapping>,
+}
+
+pub struct Mapping {
+    pub key:      String,
+    pub key_span: Span,
+    pub module:   String,
+}
+
+pub struct Error {
+    span: Span,
+    msg:  String,
+}
+
+impl Error {
+    pub fn new(span: Span, msg: impl Into<String>) -> Self {
+        Self {
+            span,
+            msg: msg.into(),
+        }
+    }
+
+    pub fn into_compile_error(self) -> TokenStream {
+        let msg = self.msg;
+        quote_spanned!(self.span=> compile_error!(#msg);)
+    }
+}
+
+pub fn parse(input: TokenStream) -> Result<Settings, Error> {
+    let mut tokens = input.into_iter().peekable();
+
+    if let Some(TokenTree::Literal(lit)) = tokens.peek() {
+        let lit = lit.clone();
+        tokens.next();
+        if let Some(extra) = tokens.next() {
+            return Err(Error::new(
+                extra.span(),
+                "unexpected token after the protocol path",
+            ));
+        }
+        return Ok(Settings {
+            path:       string_literal(&lit)?,
+            path_span:  lit.span(),
+            interfaces: None,
+            imports:    Vec::new(),
+            protocols:  Vec::new(),
+        });
+    }
+
+    let mut path = None;
+    let mut interfaces = None;
+    let mut imports = Vec::new();
+    let mut protocols = Vec::new();
+    while let Some(token) = tokens.next() {
+        let TokenTree::Ident(key) = token else {
+            return Err(Error::new(
+                token.span(),
+                "expected a setting name, e.g. `path`",
+            ));
+        };
+        match tokens.next() {
+            Some(TokenTree::Punct(eq)) if eq.as_char() == '=' => (),
+            Some(other) => return Err(Error::new(other.span(), "expected `=`")),
+            None => return Err(Error::new(key.span(), format!("missing value for `{key}`"))),
+        }
+        let Some(value) = tokens.next() else {
+            return Err(Error::new(key.span(), format!("missing value for `{key}`")));
+        };
+        match key.to_string().as_str() {
+            "path" => {
+                let TokenTree::Literal(lit) = value else {
+                    return Err(Error::new(value.span(), "expected a string literal"));
+                };
+                path = Some((string_literal(&lit)?, lit.span()));
+            }
+            "interfaces" => {
+                let TokenTree::Group(group) = value else {
+                    return Err(Error::new(
+                        value.span(),
+                        "expected a list, e.g. `[\"wl_foo\"]`",
+                    ));
+                };
+                if group.delimiter() != Delimiter::Bracket {
+                    return Err(Error::new(
+                        group.span(),
+                        "expected a list, e.g. `[\"wl_foo\"]`",
+                    ));
+                }
+                interfaces = Some(string_list(group.stream())?);
+            }
+            "imports" | "protocols" => {
+                let example = "expected a list, e.g. `[\"wl_foo\" = crate::foo]`";
+                let TokenTree::Group(group) = value else {
+                    return Err(Error::new(value.span(), example));
+                };
+                if group.delimiter() != Delimiter::Bracket {
+                    return Err(Error::new(group.span(), example));
+                }
+                let mappings = mapping_list(group.stream())?;
+                if key == "imports" {
+                    imports.extend(mappings);
+                } else {
+                    protocols.extend(mappings);
+                }
+            }
+            other => {
+                return Err(Error::new(
+                    key.span(),
+                    format!(
+                        "unknown setting `{other}`, expected `path`, `interfaces`, `imports` or `protocols`"
+                    ),
+                ));
+            }
+        }
+        match tokens.next() {
+            Some(TokenTree::Punct(comma)) if comma.as_char() == ',' => (),
+            Some(other) => return Err(Error::new(other.span(), "expected `,`")),
+            None => break,
+        }
+    }
+
+    let Some((path, path_span)) = path else {
+        return Err(Error::new(
+            Span::call_site(),
+            "missing protocol path, e.g. `generate!(path = \"./protocols/foo.xml\")`",
+        ));
+    };
+    Ok(Settings {
+        path,
+        path_span,
+        interfaces,
+        imports,
+        protocols,
+    })
+}
+
+/// `"key" = some::module, ...`
+fn mapping_list(stream: TokenStream) -> Result<Vec<Mapping>, Error> {
+    let mut items = Vec::new();
+    let mut tokens = stream.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let TokenTree::Literal(lit) = token else {
+            return Err(Error::new(token.span(), "expected a string literal"));
+        };
+        let key = string_literal(&lit)?;
+        match tokens.next() {
+            Some(TokenTree::Punct(eq)) if eq.as_char() == '=' => (),
+            Some(other) => return Err(Error::new(other.span(), "expected `=`")),
+            None => {
+                return Err(Error::new(
+                    lit.span(),
+                    format!("missing module for `{key}`"),
+                ));
+            }
+        }
+        let mut module = TokenStream::new();
+        while let Some(token) =
+            tokens.next_if(|t| !matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+        {
+            module.extend([token]);
+        }
+        if module.is_empty() {
+            return Err(Error::new(
+                lit.span(),
+                format!("missing module for `{key}`"),
+            ));
+        }
+        items.push(Mapping {
+            key,
+            key_span: lit.span(),
+            module: module.to_string(),
+        });
+        tokens.next();
+    }
+    Ok(items)
+}
+
+fn string_list(stream: TokenStream) -> Result<Vec<(String, Span)>, Error> {
+    let mut items = Vec::new();
+    let mut tokens = stream.into_iter();
+    while let Some(token) = tokens.next() {
+        let TokenTree::Literal(lit) = token else {
+            return Err(Error::new(token.span(), "expected a string literal"));
+        };
+        items.push((string_literal(&lit)?, lit.span()));
+        match tokens.next() {
+            Some(TokenTree::Punct(comma)) if comma.as_char() == ',' => (),
+            Some(other) => return Err(Error::new(other.span(), "expected `,`")),
+            None => break,
+        }
+    }
+    Ok(items)
+}
+
+fn string_literal(lit: &Literal) -> Result<String, Error> {
+    let repr = lit.to_string();
+    match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(s) if !s.contains('\\') => Ok(s.to_string()),
+        Some(_) => Err(Error::new(
+            lit.span(),
+            "escape sequences aren't supported here",
+        )),
+        None => Err(Error::new(lit.span(), "expected a string literal")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The message and the text the span of the error `input` gives covers.
+    fn parse_error(input: &str) -> (String, String) {
+        match parse(input.parse().unwrap()) {
+            Ok(settings) => panic!("{input} parsed as {}", settings.path),
+            Err(err) => (err.msg, err.span.source_text().unwrap_or_default()),
+        }
+    }
+
+    #[test]
+    fn settings() {
+        let settings = parse(
+            r#"path = "a.xml", interfaces = ["wl_foo", "wl_bar"], imports = ["wl_baz" = crate::baz]"#
+                .parse()
+                .unwrap(),
+        )
+        .ok()
+        .unwrap();
+        assert_eq!(settings.path, "a.xml");
+        let interfaces: Vec<_> = settings
+            .interfaces
+            .iter()
+            .flatten()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(interfaces, ["wl_foo", "wl_bar"]);
+        assert_eq!(settings.imports[0].module, "crate :: baz");
+        assert_eq!(
+            settings.imports[0].key_span.source_text().unwrap(),
+            r#""wl_baz""#
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_setting() {
+        for (input, msg, at) in [
+            (
+                r#""a.xml" "b.xml""#,
+                "unexpected token after the protocol path",
+                r#""b.xml""#,
+            ),
+            (r#"path "a.xml""#, "expected `=`", r#""a.xml""#),
+            (r#"path = a"#, "expected a string literal", "a"),
+            (
+                r#"path = "a\n.xml""#,
+                "escape sequences aren't supported here",
+                r#""a\n.xml""#,
+            ),
+            (r#"path = "a.xml" interfaces"#, "expected `,`", "interfaces"),
+            (
+                r#"interfaces ="#,
+                "missing value for `interfaces`",
+                "interfaces",
+            ),
+            (
+                r#"interfaces = ("wl_foo")"#,
+                "expected a list, e.g. `[\"wl_foo\"]`",
+                r#"("wl_foo")"#,
+            ),
+            (
+                r#"interfaces = ["wl_foo" "wl_bar"]"#,
+                "expected `,`",
+                r#""wl_bar""#,
+            ),
+            (
+                r#"imports = ["wl_foo" = ]"#,
+                "missing module for `wl_foo`",
+                r#""wl_foo""#,
+            ),
+            (
+                r#"imports = [wl_foo = crate::foo]"#,
+                "expected a string literal",
+                "wl_foo",
+            ),
+            (
+                r#"path = "a.xml", colour = "blue""#,
+                "unknown setting `colour`, expected `path`, `interfaces`, `imports` or `protocols`",
+                "colour",
+            ),
+        ] {
+            assert_eq!(
+                parse_error(input),
+                (msg.to_string(), at.to_string()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn missing_path() {
+        let (msg, _) = parse_error(r#"interfaces = ["wl_foo"]"#);
+        assert_eq!(
+            msg,
+            "missing protocol path, e.g. `generate!(path = \"./protocols/foo.xml\")`"
+        );
+    }
+
+    #[test]
+    fn errors_become_spanned_compile_errors() {
+        let input: TokenStream = "path = 1".parse().unwrap();
+        let err = parse(input).err().unwrap();
+        let span = err.span;
+        let tokens: Vec<_> = err.into_compile_error().into_iter().collect();
+        assert_eq!(
+            tokens.iter().map(ToString::to_string).collect::<String>(),
+            "compile_error!(\"expected a string literal\");"
+        );
+        assert!(
+            tokens
+                .iter()
+                .all(|token| token.span().start() == span.start())
+        );
+        assert_eq!(span.start().column, 7);
+    }
+}
@@ -0,0 +1,104 @@
+extern crate proc_macro;
+
+use proc_macro2::TokenStream;
+
+mod input;
+
+/// Generates the client side code of a protocol file.
+///
+/// ```ignore
+/// scr_scanner_macros::generate!("./protocols/xdg-shell.xml");
+/// scr_scanner_macros::generate!(path = "./protocols/xdg-shell.xml", interfaces = ["xdg_wm_base"]);
+/// scr_scanner_macros::generate!(
+///     path = "./protocols/xdg-decoration-unstable-v1.xml",
+///     protocols = ["./protocols/xdg-shell.xml" = crate::xdg_shell],
+/// );
+/// ```
+///
+/// Paths are relative to the crate's manifest directory. Interfaces of the core protocol
+/// resolve to `scratchway::wayland`, others have to be mapped with `imports` or `protocols`.
+///
+/// Mistakes are compile errors pointing at the setting that caused them:
+///
+/// ```compile_fail
+/// scr_scanner_macros::generate!(path = "../protocols/wayland.xml", colour = "blue");
+/// ```
+///
+/// ```compile_fail
+/// scr_scanner_macros::generate!(path = "../protocols/wayland.xml", interfaces = ["wl_nope"]);
+/// ```
+///
+/// ```compile_fail
+/// scr_scanner_macros::generate!("../protocols/does-not-exist.xml");
+/// ```
+#[proc_macro]
+pub fn generate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match expand(input.into(), scr_scanner::Side::Client) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Generates the server side code of a protocol file, resources that receive requests and
+/// send events.
+///
+/// Takes the same settings as [`generate!`], the core protocol resolves to
+/// `scratchway::server::wayland`.
+///
+/// ```ignore
+/// scr_scanner_macros::generate_server!("./protocols/xdg-shell.xml");
+/// ```
+#[proc_macro]
+pub fn generate_server(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match expand(input.into(), scr_scanner::Side::Server) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn expand(input: TokenStream, side: scr_scanner::Side) -> Result<TokenStream, input::Error> {
+    let settings = input::parse(input)?;
+
+    let mani_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(std::path::PathBuf::from);
+    let resolve = |path: &str| match &mani_dir {
+        Some(dir) => dir.join(path),
+        None => std::path::PathBuf::from(path),
+    };
+
+    let mut generator = scr_scanner::Generator::new(resolve(&settings.path)).side(side);
+    if let Some(interfaces) = &settings.interfaces {
+        generator = generator.interfaces(interfaces.iter().map(|(name, _)| name.as_str()));
+    }
+    for import in &settings.imports {
+        generator = generator.import(&import.key, &import.module);
+    }
+    for protocol in &settings.protocols {
+        generator = generator.import_protocol(resolve(&protocol.key), &protocol.module);
+    }
+
+    generator.generate().map_err(|err| {
+        let span = match &err {
+            scr_scanner::Error::UnknownInterface {
+                interface, ..
+            } => settings
+                .interfaces
+                .iter()
+                .flatten()
+                .find(|(name, _)| name == interface)
+                .map_or(settings.path_span, |(_, span)| *span),
+            scr_scanner::Error::InvalidModule(module) => settings
+                .imports
+                .iter()
+                .chain(&settings.protocols)
+                .find(|m| m.module == *module)
+                .map_or(settings.path_span, |m| m.key_span),
+            scr_scanner::Error::Io(path, _) | scr_scanner::Error::Parse(path, _) => settings
+                .protocols
+                .iter()
+                .find(|m| resolve(&m.key) == *path)
+                .map_or(settings.path_span, |m| m.key_span),
+            _ => settings.path_span,
+        };
+        input::Error::new(span, err.to_string())
+    })
+}
@@ -0,0 +1,294 @@
+//! Turning the messages of one client into readable lines, using the interface
+//! descriptions the scanner generates.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+use std::os::fd::RawFd;
+
+use scratchway::events::{EventDataParser, Fixed};
+use scratchway::interface::{ArgType, Interface, MessageDesc};
+use scratchway::server::SERVER_ID_START;
+use scratchway::trace::Direction;
+
+/// Every interface scr-trace knows.
+pub fn known_interfaces() -> Vec<&'static Interface> {
+    [
+        scratchway::wayland::INTERFACES,
+        &scr_protocols::ALL.concat(),
+    ]
+    .concat()
+}
+
+/// The objects of one connection.
+#[derive(Debug)]
+pub struct Decoder {
+    interfaces: Vec<&'static Interface>,
+    /// `None` for objects of interfaces we don't know.
+    objects:    HashMap<u32, Option<&'static Interface>>,
+}
+
+impl Decoder {
+    pub fn new(interfaces: Vec<&'static Interface>) -> Self {
+        let display = interfaces
+            .iter()
+            .copied()
+            .find(|iface| iface.name == "wl_display");
+        Self {
+            interfaces,
+            objects: HashMap::from([(1, display)]),
+        }
+    }
+
+    fn interface(&self, name: &str) -> Option<&'static Interface> {
+        self.interfaces
+            .iter()
+            .copied()
+            .find(|iface| iface.name == name)
+    }
+
+    /// Describes `message`, a whole message with its header, taking its fds from `fds`. Sent
+    /// messages are requests of the client, received ones events.
+    pub fn decode(
+        &mut self, direction: Direction, message: &[u8], fds: &mut VecDeque<RawFd>,
+    ) -> String {
+        let args = EventDataParser::new(message);
+        let (Some(id), Some(word)) = (args.get_u32(), args.get_u32()) else {
+            return format!("truncated message {message:02x?}");
+        };
+        let opcode = (word & 0xffff) as u16;
+
+        let iface = match self.objects.get(&id) {
+            Some(Some(iface)) => *iface,
+            Some(None) => return format!("unknown#{id}.{opcode}({} bytes)", message.len()),
+            None => return format!("invalid#{id}.{opcode}({} bytes)", message.len()),
+        };
+        let desc = match direction {
+            Direction::Sent => iface.request(opcode),
+            Direction::Received => iface.event(opcode),
+        };
+        let Some(desc) = desc else {
+            return format!(
+                "{}#{id}.<opcode {opcode}>({} bytes)",
+                iface.name,
+                message.len()
+            );
+        };
+
+        let mut line = format!("{}#{id}.{}(", iface.name, desc.name);
+        match self.args(iface, desc, &args, fds, &mut line) {
+            Some(()) if args.remaining() == 0 => line.push(')'),
+            Some(()) => write!(line, ") + {} bytes", args.remaining()).unwrap(),
+            None => line.push_str("<malformed>)"),
+        }
+
+        if desc.destructor && (direction == Direction::Received || id >= SERVER_ID_START) {
+            self.objects.remove(&id);
+        }
+        if iface.name == "wl_display"
+            && desc.name == "delete_id"
+            && let Some(deleted) = message.get(8..12)
+        {
+            self.objects
+                .remove(&u32::from_ne_bytes(deleted.try_into().unwrap()));
+        }
+        line
+    }
+
+    fn args(
+        &mut self, iface: &Interface, desc: &MessageDesc, args: &EventDataParser<'_>,
+        fds: &mut VecDeque<RawFd>, line: &mut String,
+    ) -> Option<()> {
+        for (i, arg) in desc.args.iter().enumerate() {
+            if i > 0 {
+                line.push_str(", ");
+            }
+            write!(line, "{}: ", arg.name).unwrap();
+            match arg.ty {
+                ArgType::Int | ArgType::Uint => {
+                    let value = args.get_u32()?;
+                    let enumeration = arg.enumeration.and_then(|name| match name.split_once('.') {
+                        Some((other, name)) => self.interface(other)?.enumeration(name),
+                        None => iface.enumeration(name),
+                    });
+                    // Ints that aren't a value of their enum stay signed
+                    let signed = matches!(arg.ty, ArgType::Int);
+                    let enumeration = enumeration.filter(|en| {
+                        !signed
+                            || en.bitfield
+                            || en.entries.iter().any(|entry| entry.value == value)
+                    });
+                    match enumeration {
+                        Some(en) => write!(line, "{}", en.format(value)).unwrap(),
+                        None if signed => write!(line, "{}", value as i32).unwrap(),
+                        None => write!(line, "{value}").unwrap(),
+                    }
+                }
+                ArgType::Fixed => write!(line, "{}", Fixed(args.get_i32()?)).unwrap(),
+                ArgType::String => match args.get_nullable_string()? {
+                    Some(string) => write!(line, "{string:?}").unwrap(),
+                    None => line.push_str("nil"),
+                },
+                ArgType::Object => match args.get_u32()? {
+                    0 => line.push_str("nil"),
+                    id => match self.objects.get(&id) {
+                        Some(Some(iface)) => write!(line, "{}#{id}", iface.name).unwrap(),
+                        _ => write!(line, "unknown#{id}").unwrap(),
+                    },
+                },
+                ArgType::NewId => {
+                    let created = match arg.interface {
+                        Some(created) => Some(created),
+                        // Untyped, `wl_registry.bind`
+                        None => {
+                            let name = args.get_nullable_string()?.unwrap_or_default();
+                            let version = args.get_u32()?;
+                            write!(line, "{name:?} v{version} ").unwrap();
+                            self.interface(name)
+                        }
+                    };
+                    let id = args.get_u32()?;
+                    let name = created.map_or("unknown", |iface| iface.name);
+                    write!(line, "new {name}#{id}").unwrap();
+                    self.objects.insert(id, created);
+                }
+                ArgType::Array => {
+                    let array = args.get_bytes()?;
+                    write!(line, "array[{}]", array.len()).unwrap();
+                }
+                ArgType::Fd => match fds.pop_front() {
+                    Some(fd) => write!(line, "fd {fd}").unwrap(),
+                    None => line.push_str("fd <missing>"),
+                },
+            }
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: u32, opcode: u16, args: &[&[u8]]) -> Vec<u8> {
+        let body = args.concat();
+        let size = (8 + body.len()) as u32;
+        [
+            &id.to_ne_bytes()[..],
+            &(size << 16 | opcode as u32).to_ne_bytes(),
+            &body,
+        ]
+        .concat()
+    }
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut out = ((s.len() + 1) as u32).to_ne_bytes().to_vec();
+        out.extend_from_slice(s.as_bytes());
+        out.resize(4 + (s.len() + 1).next_multiple_of(4), 0);
+        out
+    }
+
+    #[test]
+    fn messages_are_annotated() {
+        let mut decoder = Decoder::new(known_interfaces());
+        let mut fds = VecDeque::new();
+
+        let get_registry = message(1, 1, &[&2u32.to_ne_bytes()]);
+        assert_eq!(
+            decoder.decode(Direction::Sent, &get_registry, &mut fds),
+            "wl_display#1.get_registry(registry: new wl_registry#2)"
+        );
+
+        let bind = message(
+            2,
+            0,
+            &[
+                &1u32.to_ne_bytes(),
+                &string("wl_seat"),
+                &7u32.to_ne_bytes(),
+                &3u32.to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            decoder.decode(Direction::Sent, &bind, &mut fds),
+            r#"wl_registry#2.bind(name: 1, id: "wl_seat" v7 new wl_seat#3)"#
+        );
+
+        let capabilities = message(3, 0, &[&3u32.to_ne_bytes()]);
+        assert_eq!(
+            decoder.decode(Direction::Received, &capabilities, &mut fds),
+            "wl_seat#3.capabilities(capabilities: pointer|keyboard)"
+        );
+
+        let get_keyboard = message(3, 1, &[&4u32.to_ne_bytes()]);
+        decoder.decode(Direction::Sent, &get_keyboard, &mut fds);
+        fds.push_back(12);
+        let keymap = message(4, 0, &[&1u32.to_ne_bytes(), &100u32.to_ne_bytes()]);
+        assert_eq!(
+            decoder.decode(Direction::Received, &keymap, &mut fds),
+            "wl_keyboard#4.keymap(format: xkb_v1, fd: fd 12, size: 100)"
+        );
+    }
+
+    #[test]
+    fn nulls_and_unknown_objects() {
+        let mut decoder = Decoder::new(known_interfaces());
+        let mut fds = VecDeque::new();
+        decoder.objects.insert(5, decoder.interface("wl_surface"));
+
+        let attach = message(
+            5,
+            1,
+            &[
+                &0u32.to_ne_bytes(),
+                &0u32.to_ne_bytes(),
+                &0u32.to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            decoder.decode(Direction::Sent, &attach, &mut fds),
+            "wl_surface#5.attach(buffer: nil, x: 0, y: 0)"
+        );
+        assert_eq!(
+            decoder.decode(Direction::Sent, &message(9, 0, &[]), &mut fds),
+            "invalid#9.0(8 bytes)"
+        );
+
+        // wl_surface.set_buffer_transform takes an int of wl_output.transform
+        let set_buffer_transform = message(5, 7, &[&5u32.to_ne_bytes()]);
+        assert_eq!(
+            decoder.decode(Direction::Sent, &set_buffer_transform, &mut fds),
+            "wl_surface#5.set_buffer_transform(transform: flipped_90)"
+        );
+        let set_buffer_transform = message(5, 7, &[&(-1i32).to_ne_bytes()]);
+        assert_eq!(
+            decoder.decode(Direction::Sent, &set_buffer_transform, &mut fds),
+            "wl_surface#5.set_buffer_transform(transform: -1)"
+        );
+
+        let truncated = message(5, 1, &[&0u32.to_ne_bytes()]);
+        assert_eq!(
+            decoder.decode(Direction::Sent, &truncated, &mut fds),
+            "wl_surface#5.attach(buffer: nil, x: <malformed>)"
+        );
+
+        // wl_pointer.motion(1234, 10.5, -4.25), fixed values read like `WAYLAND_DEBUG` prints them
+        decoder.objects.insert(6, decoder.interface("wl_pointer"));
+        let motion = message(
+            6,
+            2,
+            &[
+                &1234u32.to_ne_bytes(),
+                &(10 * 256 + 128i32).to_ne_bytes(),
+                &(-4 * 256 - 64i32).to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            decoder.decode(Direction::Received, &motion, &mut fds),
+            "wl_pointer#6.motion(time: 1234, surface_x: 10.50000000, surface_y: -4.25000000)"
+        );
+
+        let delete_id = message(1, 1, &[&5u32.to_ne_bytes()]);
+        decoder.decode(Direction::Received, &delete_id, &mut fds);
+        assert!(!decoder.objects.contains_key(&5));
+    }
+}
@@ -0,0 +1,365 @@
+//! A proxy between clients and the compositor printing every message in both directions,
+//! decoded with the descriptions of the core protocol and those in `scr_protocols`.
+//!
+//! Usage: scr-trace [--socket NAME] [-- command args...]
+//!
+//! It listens on a new `wayland-N` and forwards to the compositor `WAYLAND_DISPLAY` points at.
+//! Unlike `WAYLAND_DEBUG` it works on any client, whatever library it's built with. With a
+//! command it's spawned with `WAYLAND_DISPLAY` set to the proxy and its exit status is returned.
+
+mod decode;
+mod wire;
+
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::io;
+use std::os::fd::{AsRawFd, OwnedFd};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::time::Instant;
+
+use scratchway::events::Header;
+use scratchway::interface::Interface;
+use scratchway::server::ListeningSocket;
+use scratchway::trace::Direction;
+
+use crate::decode::Decoder;
+
+const USAGE: &str = "Usage: scr-trace [--socket NAME] [-- command args...]";
+
+/// Reading from a side stops while this much of what it sent waits for the other side.
+const MAX_UNSENT: usize = 1 << 20;
+
+/// A client and its own connection to the compositor.
+struct Session {
+    id:       u32,
+    client:   UnixStream,
+    server:   UnixStream,
+    decoder:  Decoder,
+    requests: Pending,
+    events:   Pending,
+}
+
+/// What came in one direction and wasn't passed on yet or isn't a whole message yet.
+#[derive(Default)]
+struct Pending {
+    data:       Vec<u8>,
+    fds:        VecDeque<i32>,
+    unsent:     Vec<u8>,
+    unsent_fds: Vec<OwnedFd>,
+}
+
+impl Pending {
+    /// Passes on as much as `to` takes without blocking, `false` once it hung up.
+    fn flush(&mut self, to: &UnixStream) -> io::Result<bool> {
+        while !self.unsent.is_empty() {
+            // Fds need data to go along with, more than fit one send go ahead of the rest
+            let len = if self.unsent_fds.len() > wire::MAX_FDS {
+                1
+            } else {
+                self.unsent.len()
+            };
+            match wire::send(to, &self.unsent[..len], &self.unsent_fds) {
+                Ok((len, fds)) => {
+                    self.unsent.drain(..len);
+                    self.unsent_fds.drain(..fds);
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == io::ErrorKind::BrokenPipe => return Ok(false),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl Session {
+    /// What to poll the socket `incoming` comes from for: more of it while the other side
+    /// keeps up, and room for what goes the other way if some of it is waiting.
+    fn poll_events(&self, incoming: Direction) -> libc::c_short {
+        let (incoming, outgoing) = match incoming {
+            Direction::Sent => (&self.requests, &self.events),
+            Direction::Received => (&self.events, &self.requests),
+        };
+        let mut events = 0;
+        if incoming.unsent.len() < MAX_UNSENT {
+            events |= libc::POLLIN;
+        }
+        if !outgoing.unsent.is_empty() {
+            events |= libc::POLLOUT;
+        }
+        events
+    }
+
+    /// Passes on what `direction` couldn't send before, `false` once the other side hung up.
+    fn flush(&mut self, direction: Direction) -> io::Result<bool> {
+        match direction {
+            Direction::Sent => self.requests.flush(&self.server),
+            Direction::Received => self.events.flush(&self.client),
+        }
+    }
+
+    /// Forwards what `direction` has to say, `false` once either side hung up.
+    fn forward(&mut self, direction: Direction, start: Instant) -> io::Result<bool> {
+        let (from, to, pending) = match direction {
+            Direction::Sent => (&self.client, &self.server, &mut self.requests),
+            Direction::Received => (&self.server, &self.client, &mut self.events),
+        };
+        let mut buf = [0; 4096];
+        let mut fds: Vec<OwnedFd> = Vec::new();
+        let len = match wire::recv(from, &mut buf, &mut fds) {
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(true),
+            len => len?,
+        };
+        if len == 0 {
+            return Ok(false);
+        }
+        // The numbers are only for printing, they're closed once they're passed on
+        pending.fds.extend(fds.iter().map(AsRawFd::as_raw_fd));
+        pending.data.extend_from_slice(&buf[..len]);
+        pending.unsent.extend_from_slice(&buf[..len]);
+        pending.unsent_fds.extend(fds);
+        if !pending.flush(to)? {
+            return Ok(false);
+        }
+
+        let arrow = match direction {
+            Direction::Sent => "->",
+            Direction::Received => "<-",
+        };
+        let mut consumed = 0;
+        while let Some(header) = pending.data.get(consumed..consumed + Header::HEADER_SIZE) {
+            let size = (Header::from_slice(header).size as usize).max(Header::HEADER_SIZE);
+            let Some(message) = pending.data.get(consumed..consumed + size) else {
+                break;
+            };
+            let line = self.decoder.decode(direction, message, &mut pending.fds);
+            let time = start.elapsed().as_secs_f64() * 1000.0;
+            println!("[{time:10.3}] client#{} {arrow} {line}", self.id);
+            consumed += size;
+        }
+        pending.data.drain(..consumed);
+        Ok(true)
+    }
+}
+
+fn main() -> ExitCode {
+    let mut socket = None;
+    let mut command = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--socket" => match args.next() {
+                Some(name) => socket = Some(name),
+                None => {
+                    eprintln!("{arg} requires a value\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--" => command.extend(args.by_ref()),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => {
+                eprintln!("unexpected argument: {arg}\n{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let runtime_dir = PathBuf::from(std::env::var_os("XDG_RUNTIME_DIR").unwrap_or("/tmp/".into()));
+    let upstream =
+        runtime_dir.join(std::env::var_os("WAYLAND_DISPLAY").unwrap_or("wayland-0".into()));
+    let listener = match socket {
+        Some(name) => ListeningSocket::bind(name),
+        None => ListeningSocket::bind_auto(),
+    };
+    let listener = match listener {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("couldn't create the socket: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let name: OsString = listener.name().to_owned();
+    eprintln!(
+        "WAYLAND_DISPLAY={} -> {}",
+        name.to_string_lossy(),
+        upstream.display()
+    );
+
+    let mut child = match command.split_first() {
+        Some((program, args)) => match Command::new(program)
+            .args(args)
+            .env("WAYLAND_DISPLAY", &name)
+            .spawn()
+        {
+            Ok(child) => Some(child),
+            Err(err) => {
+                eprintln!("couldn't run {program}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let interfaces = decode::known_interfaces();
+    let start = Instant::now();
+    let mut sessions: Vec<Session> = Vec::new();
+    let mut next_id = 0;
+    loop {
+        if let Some(child) = &mut child
+            && let Ok(Some(status)) = child.try_wait()
+        {
+            return ExitCode::from(status.code().unwrap_or(1).clamp(0, 255) as u8);
+        }
+
+        let mut pollfds = vec![pollfd(listener.as_raw_fd(), libc::POLLIN)];
+        for session in &sessions {
+            let client = session.poll_events(Direction::Sent);
+            pollfds.push(pollfd(session.client.as_raw_fd(), client));
+            let server = session.poll_events(Direction::Received);
+            pollfds.push(pollfd(session.server.as_raw_fd(), server));
+        }
+        // Wakes up now and then to notice the child exiting
+        let ready = unsafe { libc::poll(pollfds.as_mut_ptr(), pollfds.len() as libc::nfds_t, 100) };
+        if ready < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            eprintln!("poll: {err}");
+            return ExitCode::FAILURE;
+        }
+
+        let mut gone = Vec::new();
+        for (i, session) in sessions.iter_mut().enumerate() {
+            // Each socket is written to by one direction and read from by the other
+            let ready = [
+                (
+                    Direction::Received,
+                    Direction::Sent,
+                    pollfds[1 + i * 2].revents,
+                ),
+                (
+                    Direction::Sent,
+                    Direction::Received,
+                    pollfds[2 + i * 2].revents,
+                ),
+            ];
+            for (outgoing, incoming, revents) in ready {
+                let mut forwarded = Ok(true);
+                if revents & libc::POLLOUT != 0 {
+                    forwarded = session.flush(outgoing);
+                }
+                if revents & !libc::POLLOUT != 0 && matches!(forwarded, Ok(true)) {
+                    forwarded = session.forward(incoming, start);
+                }
+                match forwarded {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        gone.push(session.id);
+                        break;
+                    }
+                    Err(err) => {
+                        eprintln!("client#{}: {err}", session.id);
+                        gone.push(session.id);
+                        break;
+                    }
+                }
+            }
+        }
+        for id in &gone {
+            println!("client#{id} disconnected");
+        }
+        sessions.retain(|session| !gone.contains(&session.id));
+
+        if pollfds[0].revents != 0 {
+            loop {
+                match listener.accept() {
+                    Ok(Some(client)) => {
+                        match connect(next_id + 1, client, &upstream, &interfaces) {
+                            Ok(session) => {
+                                next_id += 1;
+                                println!("client#{} connected", session.id);
+                                sessions.push(session);
+                            }
+                            Err(err) => {
+                                eprintln!("couldn't connect to {}: {err}", upstream.display())
+                            }
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(err) => {
+                        eprintln!("accept: {err}");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn connect(
+    id: u32, client: UnixStream, upstream: &Path, interfaces: &[&'static Interface],
+) -> io::Result<Session> {
+    let server = UnixStream::connect(upstream)?;
+    // Neither side can hold up the other, what a socket doesn't take waits in `Pending`
+    client.set_nonblocking(true)?;
+    server.set_nonblocking(true)?;
+    Ok(Session {
+        id,
+        client,
+        server,
+        decoder: Decoder::new(interfaces.to_vec()),
+        requests: Pending::default(),
+        events: Pending::default(),
+    })
+}
+
+fn pollfd(fd: i32, events: libc::c_short) -> libc::pollfd {
+    libc::pollfd {
+        fd,
+        events,
+        revents: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    #[test]
+    fn unsent_data_waits_for_the_socket() {
+        let (to, mut peer) = UnixStream::pair().unwrap();
+        to.set_nonblocking(true).unwrap();
+        let sent: Vec<u8> = (0..MAX_UNSENT).map(|i| i as u8).collect();
+        let mut pending = Pending {
+            unsent: sent.clone(),
+            ..Pending::default()
+        };
+        // More than the socket buffer holds
+        assert!(pending.flush(&to).unwrap());
+        assert!(!pending.unsent.is_empty());
+
+        let mut received = Vec::new();
+        let mut buf = [0; 4096];
+        while !pending.unsent.is_empty() {
+            let len = peer.read(&mut buf).unwrap();
+            received.extend_from_slice(&buf[..len]);
+            assert!(pending.flush(&to).unwrap());
+        }
+        drop(to);
+        peer.read_to_end(&mut received).unwrap();
+        assert_eq!(received, sent);
+
+        let (to, peer) = UnixStream::pair().unwrap();
+        drop(peer);
+        pending.unsent.push(0);
+        assert!(!pending.flush(&to).unwrap());
+    }
+}
@@ -0,0 +1,123 @@
+//! Moving bytes and fds between sockets.
+
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::os::unix::net::UnixStream;
+
+/// libwayland never sends more fds with one message.
+pub const MAX_FDS: usize = 28;
+
+/// Room for a `cmsghdr` and `MAX_FDS` fds, as u64s for the alignment.
+type ControlBuffer = [u64; 32];
+
+/// Reads what's there into `buf`, fds that came with it are appended to `fds`.
+///
+/// Returns 0 once the peer hung up.
+pub fn recv(socket: &UnixStream, buf: &mut [u8], fds: &mut Vec<OwnedFd>) -> io::Result<usize> {
+    let mut control: ControlBuffer = [0; 32];
+    let mut iov = libc::iovec {
+        iov_base: buf.as_mut_ptr().cast(),
+        iov_len:  buf.len(),
+    };
+    unsafe {
+        let mut msghdr: libc::msghdr = core::mem::zeroed();
+        msghdr.msg_iov = &raw mut iov;
+        msghdr.msg_iovlen = 1;
+        msghdr.msg_control = control.as_mut_ptr().cast();
+        msghdr.msg_controllen = size_of::<ControlBuffer>();
+
+        let len = libc::recvmsg(socket.as_raw_fd(), &raw mut msghdr, libc::MSG_CMSG_CLOEXEC);
+        if len < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut cmsg = libc::CMSG_FIRSTHDR(&raw const msghdr);
+        while !cmsg.is_null() {
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                let data = libc::CMSG_DATA(cmsg).cast::<RawFd>();
+                let count = ((*cmsg).cmsg_len - libc::CMSG_LEN(0) as usize) / size_of::<RawFd>();
+                for i in 0..count {
+                    fds.push(OwnedFd::from_raw_fd(data.add(i).read_unaligned()));
+                }
+            }
+            cmsg = libc::CMSG_NXTHDR(&raw const msghdr, cmsg);
+        }
+        Ok(len as usize)
+    }
+}
+
+/// Writes as much of `data` as `socket` takes in one go, up to `MAX_FDS` of `fds` go along
+/// with it. Returns how many bytes and fds went out.
+///
+/// Fds can't be sent without data, `data` mustn't be empty.
+pub fn send(socket: &UnixStream, data: &[u8], fds: &[OwnedFd]) -> io::Result<(usize, usize)> {
+    let now = &fds[..fds.len().min(MAX_FDS)];
+    loop {
+        let mut control: ControlBuffer = [0; 32];
+        let mut iov = libc::iovec {
+            iov_base: data.as_ptr().cast_mut().cast(),
+            iov_len:  data.len(),
+        };
+        let len = unsafe {
+            let mut msghdr: libc::msghdr = core::mem::zeroed();
+            msghdr.msg_iov = &raw mut iov;
+            msghdr.msg_iovlen = 1;
+            if !now.is_empty() {
+                let fds_len = (now.len() * size_of::<RawFd>()) as u32;
+                msghdr.msg_control = control.as_mut_ptr().cast();
+                msghdr.msg_controllen = libc::CMSG_SPACE(fds_len) as usize;
+                let cmsg = libc::CMSG_FIRSTHDR(&raw const msghdr);
+                (*cmsg).cmsg_level = libc::SOL_SOCKET;
+                (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+                (*cmsg).cmsg_len = libc::CMSG_LEN(fds_len) as usize;
+                let out = libc::CMSG_DATA(cmsg).cast::<RawFd>();
+                for (i, fd) in now.iter().enumerate() {
+                    out.add(i).write_unaligned(fd.as_raw_fd());
+                }
+            }
+            libc::sendmsg(socket.as_raw_fd(), &raw const msghdr, libc::MSG_NOSIGNAL)
+        };
+        if len < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err);
+        }
+        return Ok((len as usize, now.len()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek, Write};
+
+    use super::*;
+
+    #[test]
+    fn fds_are_passed_along() {
+        let (a, b) = UnixStream::pair().unwrap();
+        let mut file = std::fs::File::from(memfd());
+        file.write_all(b"hello").unwrap();
+        assert_eq!(send(&a, &[1, 2, 3, 4], &[file.into()]).unwrap(), (4, 1));
+
+        let mut buf = [0; 16];
+        let mut fds = Vec::new();
+        assert_eq!(recv(&b, &mut buf, &mut fds).unwrap(), 4);
+        assert_eq!(buf[..4], [1, 2, 3, 4]);
+        let mut file = std::fs::File::from(fds.pop().unwrap());
+        let mut text = String::new();
+        file.rewind().unwrap();
+        file.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "hello");
+
+        drop(a);
+        assert_eq!(recv(&b, &mut buf, &mut fds).unwrap(), 0);
+    }
+
+    fn memfd() -> OwnedFd {
+        let fd = unsafe { libc::memfd_create(c"scr-trace-test".as_ptr(), libc::MFD_CLOEXEC) };
+        assert!(fd >= 0);
+        unsafe { OwnedFd::from_raw_fd(fd) }
+    }
+}
@@ -1,9 +1,20 @@
+use crate::debug::{self, Side};
 use crate::events::*;
+use crate::interface::Interface;
+use crate::leaks::LeakReport;
 use crate::log;
+use crate::logger::{Level, Logger, LoggerSlot, StderrLogger};
+use crate::stats::{Counters, Stats};
+use crate::trace::{Direction, Tracer};
+use crate::validate;
 use crate::wayland::wl_display;
-use std::sync::RwLock;
+use std::collections::HashMap;
+use std::panic::Location;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Instant;
 use std::{
-    cell::Cell,
     io,
     marker::PhantomData,
     os::{
@@ -14,23 +25,34 @@ use std::{
 
 use crate::utils::{Bucket, syscall};
 
-pub static DEBUG: std::sync::LazyLock<bool> = std::sync::LazyLock::new(|| unsafe {
-    let env = libc::getenv(c"WAYLAND_DEBUG".as_ptr()).cast_const();
-    !env.is_null() && libc::strcmp(env, c"1".as_ptr().cast()) == 0
-});
+/// Whether `WAYLAND_DEBUG` is `1` or contains `client`, see [`crate::debug`].
+pub static DEBUG: std::sync::LazyLock<bool> = std::sync::LazyLock::new(|| Side::Client.enabled());
 
 pub static TRACE: std::sync::LazyLock<bool> = std::sync::LazyLock::new(|| unsafe {
     let env = libc::getenv(c"SCR_TRACE".as_ptr()).cast_const();
     !env.is_null() && libc::strcmp(env, c"1".as_ptr().cast()) == 0
 });
 
-static IDCOUNTER: IdCounter = IdCounter::new();
+/// Where the wire traffic of every connection is recorded, see [`crate::trace`].
+pub static TRACE_FILE: std::sync::LazyLock<Option<std::path::PathBuf>> =
+    std::sync::LazyLock::new(|| {
+        std::env::var_os("SCR_TRACE_FILE")
+            .filter(|path| !path.is_empty())
+            .map(Into::into)
+    });
+
+/// Whether connections log the objects they leaked when they're dropped, see [`crate::leaks`].
+pub static LEAK_REPORT: std::sync::LazyLock<bool> =
+    std::sync::LazyLock::new(|| std::env::var_os("SCR_LEAK_REPORT").is_some_and(|v| v == "1"));
 
 #[derive(Debug)]
 pub struct Connection {
-    pub(crate) socket: UnixStream,
-    pub(crate) reader: WaylandBuffer<Reader>,
-    pub(crate) writer: WaylandBuffer<Writer>,
+    pub(crate) socket:  UnixStream,
+    pub(crate) reader:  WaylandBuffer<Reader>,
+    pub(crate) writer:  WaylandBuffer<Writer>,
+    pub(crate) objects: Arc<ObjectMap>,
+    // Whether the leaks are logged when the connection is dropped
+    leak_report:        AtomicBool,
 }
 
 impl Connection {
@@ -43,11 +65,62 @@ impl Connection {
             "connected to wayland socket at {:?}",
             socket.peer_addr().unwrap()
         );
-        Ok(Self {
-            reader: WaylandBuffer::<Reader>::new(socket.as_raw_fd()), // Thanks Rust
-            writer: WaylandBuffer::<Writer>::new(socket.as_raw_fd()),
+        Ok(Self::from_fd(socket.into()))
+    }
+
+    /// Talks to the compositor over an already connected socket, e.g. one inherited through
+    /// `WAYLAND_SOCKET` or one end of a `socketpair`.
+    pub fn from_fd(fd: OwnedFd) -> Self {
+        let socket = UnixStream::from(fd);
+        let objects = Arc::new(ObjectMap::new());
+        objects.insert(
+            ObjectInfo {
+                id:        1,
+                interface: wl_display::WlDisplay::INTERFACE,
+                version:   1,
+            },
+            wl_display::WlDisplay::DESC,
+        );
+        let conn = Self {
+            reader: WaylandBuffer::<Reader>::new(socket.as_raw_fd(), objects.clone()) // Thanks Rust
+                .side(Side::Client),
+            writer: WaylandBuffer::<Writer>::new(socket.as_raw_fd(), objects.clone())
+                .side(Side::Client),
+            objects,
             socket,
-        })
+            leak_report: AtomicBool::new(*LEAK_REPORT),
+        };
+        if let Some(path) = &*TRACE_FILE
+            && let Err(err) = conn.trace_to(path)
+        {
+            log!(
+                WARNING,
+                "Couldn't open the trace file {}: {}",
+                path.display(),
+                err
+            );
+        }
+        conn
+    }
+
+    /// Records every message sent and received from now on to `path`, see [`crate::trace`].
+    pub fn trace_to(&self, path: impl AsRef<std::path::Path>) -> io::Result<()> {
+        self.set_tracer(Tracer::to_file(path)?);
+        Ok(())
+    }
+
+    pub fn set_tracer(&self, tracer: Tracer) {
+        let tracer = Some(Arc::new(tracer));
+        *self.reader.tracer.write().unwrap() = tracer.clone();
+        *self.writer.tracer.write().unwrap() = tracer;
+    }
+
+    /// Hands the messages of this connection to `logger` instead of the one of the process,
+    /// see [`crate::logger`].
+    pub fn set_logger(&self, logger: impl Logger + 'static) {
+        let logger: Arc<dyn Logger> = Arc::new(logger);
+        self.reader.logger.set(logger.clone());
+        self.writer.logger.set(logger);
     }
 
     pub fn display_fd(&self) -> RawFd {
@@ -59,15 +132,52 @@ impl Connection {
     }
 
     pub fn dispatch_events<S: State>(&self, state: &mut S) -> io::Result<()> {
-        let read = self.read_events()?;
+        self.read_events()?;
+        let start = Instant::now();
         let data = self.reader.data.read().unwrap();
-        let events = EventIter::new(&data[..read]);
+        let events = EventIter::new(&data[..self.reader.messages_end()]);
         for event in events {
-            state.handle_event(self, event);
+            if self.track_deletion(&event) {
+                state.handle_event(self, event);
+            }
         }
+        self.reader.stats.dispatched(start.elapsed());
         Ok(())
     }
 
+    /// Forgets objects the compositor acknowledged the destruction of with `wl_display.delete_id`
+    /// and drops events sent to objects we've already destroyed.
+    ///
+    /// Returns whether the event should be handed to the user.
+    fn track_deletion(&self, event: &WlEvent<'_>) -> bool {
+        let zombie = self.objects.zombie_event_fds(event.header.id).is_some();
+        self.reader.received(event, zombie);
+        if event.header.id == 1
+            && event.header.opcode == 1
+            && let Some(id) = event.parser().get_u32()
+        {
+            self.objects.remove(id);
+        }
+        if let Some(event_fds) = self.objects.zombie_event_fds(event.header.id) {
+            // The fds still arrive, they have to be taken out so later events get the right ones
+            let fds = event_fds
+                .get(event.header.opcode as usize)
+                .copied()
+                .unwrap_or(0);
+            for _ in 0..fds {
+                self.reader.get_fd();
+            }
+            log!(
+                TRACE,
+                "Discarded event for destroyed object {}, opcode: {}",
+                event.header.id,
+                event.header.opcode
+            );
+            return false;
+        }
+        true
+    }
+
     fn read_events(&self) -> io::Result<usize> {
         self.writer.send()?;
         self.reader.recv()
@@ -76,16 +186,21 @@ impl Connection {
     pub fn roundtrip(&self, state: &mut impl State) -> std::io::Result<()> {
         let display = self.display();
         let wl_callback = display.sync(&self.writer);
-        let read = self.read_events()?;
+        self.read_events()?;
+        let start = Instant::now();
         let data = self.reader.data.read().unwrap();
-        let events = EventIter::new(&data[..read]);
+        let events = EventIter::new(&data[..self.reader.messages_end()]);
         for event in events {
+            // The rest of the read is still handled, the callback's `delete_id` is among it
             if wl_callback.id() == event.header.id {
-                wl_callback.parse_event(&self.reader, event); // just for debugs
-                break;
+                self.reader.received(&event, false);
+                continue;
+            }
+            if self.track_deletion(&event) {
+                state.handle_event(self, event);
             }
-            state.handle_event(self, event);
         }
+        self.reader.stats.dispatched(start.elapsed());
         Ok(())
     }
 
@@ -102,38 +217,268 @@ impl Connection {
     pub fn flush(&self) -> std::io::Result<()> {
         self.writer().send()
     }
+
+    pub fn object_info(&self, id: u32) -> Option<ObjectInfo> {
+        self.objects.get(id)
+    }
+
+    /// A snapshot of the connection's counters, see [`crate::stats`].
+    pub fn stats(&self) -> Stats {
+        let mut messages: Vec<_> = self
+            .writer
+            .stats
+            .messages(Direction::Sent)
+            .chain(self.reader.stats.messages(Direction::Received))
+            .collect();
+        messages.sort_by_key(|msg| {
+            (
+                msg.interface,
+                msg.direction == Direction::Received,
+                msg.opcode,
+            )
+        });
+        Stats {
+            sent: self.writer.stats.traffic(),
+            received: self.reader.stats.traffic(),
+            flushes: self.writer.stats.transfers(),
+            reads: self.reader.stats.transfers(),
+            reassembled_messages: self.reader.stats.reassembled_messages(),
+            dispatch_time: self.reader.stats.dispatch_time(),
+            messages,
+        }
+    }
+
+    /// Sets every counter back to zero.
+    pub fn reset_stats(&self) {
+        self.writer.stats.reset();
+        self.reader.stats.reset();
+    }
+
+    /// Every object that wasn't destroyed, ordered by id, the display included.
+    pub fn live_objects(&self) -> impl Iterator<Item = ObjectInfo> + use<> {
+        self.objects.live().into_iter().map(|(info, ..)| info)
+    }
+
+    /// The live objects that could have been destroyed, see [`crate::leaks`].
+    pub fn leaks(&self) -> LeakReport {
+        LeakReport::new(self.objects.live())
+    }
+
+    /// Logs [`Self::leaks`] as a warning when the connection is dropped, off unless
+    /// `SCR_LEAK_REPORT=1`.
+    pub fn set_leak_report(&self, enabled: bool) {
+        self.leak_report.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Hands `event` to the generated handler trait of `O` if it was sent to an `O`,
+    /// otherwise gives it back so it can be tried against another interface.
+    ///
+    /// ```ignore
+    /// impl State for App {
+    ///     fn handle_event(&mut self, conn: &Connection, event: WlEvent<'_>) {
+    ///         let Some(event) = conn.dispatch::<WlPointer, _>(self, event) else { return };
+    ///         let Some(event) = conn.dispatch::<XdgToplevel, _>(self, event) else { return };
+    ///         log!(WARNING, "Unhandled event {:?}", event.header);
+    ///     }
+    /// }
+    /// ```
+    pub fn dispatch<'a, O: Dispatch<H>, H: ?Sized>(
+        &self, handler: &mut H, event: WlEvent<'a>,
+    ) -> Option<WlEvent<'a>> {
+        match self.objects.get(event.header.id) {
+            Some(info) if info.interface == O::INTERFACE => {
+                O::from_id(event.header.id).dispatch(handler, self, event);
+                None
+            }
+            _ => Some(event),
+        }
+    }
+
+    /// Logs and drops an event that doesn't match the description of its object, what the
+    /// generated [`Dispatch`] implementations do with events they can't parse.
+    pub fn malformed(&self, event: WlEvent<'_>) {
+        let interface = self
+            .objects
+            .get(event.header.id)
+            .map_or("unknown", |info| info.interface);
+        log!(
+            ERR,
+            "Malformed event {interface}#{}, opcode: {}, {} bytes, discarding it",
+            event.header.id,
+            event.header.opcode,
+            event.header.size
+        );
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        if self.leak_report.load(Ordering::Relaxed) {
+            let leaks = self.leaks();
+            if !leaks.is_empty() {
+                log!(WARNING, "{leaks}");
+            }
+        }
+    }
 }
 
 pub trait State {
     fn handle_event(&mut self, conn: &Connection, event: WlEvent<'_>);
 }
 
+/// What the connection knows about a live object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectInfo {
+    pub id:        u32,
+    pub interface: &'static str,
+    pub version:   u32,
+}
+
+/// Objects created through this connection, shared between the reader and the writer
+/// so objects created by both requests and events end up in the same place.
 #[derive(Debug)]
-pub(crate) struct IdCounter {
-    pub(crate) current: Cell<u32>,
+pub(crate) struct ObjectMap {
+    objects:        RwLock<HashMap<u32, ObjectEntry>>,
+    // Ids of objects the client creates, 1 is always the display
+    next_client_id: AtomicU32,
+    // Only used on the server side, ids of objects the server creates
+    next_server_id: AtomicU32,
 }
 
-impl IdCounter {
-    pub(crate) const fn new() -> Self {
+#[derive(Debug)]
+struct ObjectEntry {
+    info:    ObjectInfo,
+    desc:    &'static Interface,
+    // Set once a destructor was sent, until the compositor confirms with `delete_id`
+    zombie:  Option<&'static [u8]>,
+    // Where the object was created, only kept in debug builds
+    created: Option<&'static Location<'static>>,
+}
+
+impl ObjectMap {
+    pub(crate) fn new() -> Self {
         Self {
-            current: Cell::new(1),
+            objects:        RwLock::new(HashMap::new()),
+            next_client_id: AtomicU32::new(2),
+            next_server_id: AtomicU32::new(crate::server::SERVER_ID_START),
         }
     }
-    pub(crate) const fn get_new(&self) -> u32 {
-        let new = self.current.get() + 1;
-        self.current.replace(new);
-        new
+
+    pub(crate) fn next_client_id(&self) -> u32 {
+        self.next_client_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) fn next_server_id(&self) -> u32 {
+        self.next_server_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) fn insert(&self, info: ObjectInfo, desc: &'static Interface) {
+        self.insert_created(info, desc, None);
+    }
+
+    /// Like [`Self::insert`], remembering that the object was created at `created`.
+    pub(crate) fn insert_created(
+        &self, info: ObjectInfo, desc: &'static Interface,
+        created: Option<&'static Location<'static>>,
+    ) {
+        let entry = ObjectEntry {
+            info,
+            desc,
+            zombie: None,
+            created,
+        };
+        self.objects.write().unwrap().insert(info.id, entry);
+    }
+
+    pub(crate) fn get(&self, id: u32) -> Option<ObjectInfo> {
+        self.objects
+            .read()
+            .unwrap()
+            .get(&id)
+            .map(|entry| entry.info)
+    }
+
+    /// Every live object, ordered by id.
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn all(&self) -> Vec<ObjectInfo> {
+        let mut all: Vec<_> = self
+            .objects
+            .read()
+            .unwrap()
+            .values()
+            .map(|entry| entry.info)
+            .collect();
+        all.sort_by_key(|info| info.id);
+        all
+    }
+
+    /// Objects that weren't destroyed, ordered by id, with their interface and where they were
+    /// created.
+    pub(crate) fn live(
+        &self,
+    ) -> Vec<(
+        ObjectInfo,
+        &'static Interface,
+        Option<&'static Location<'static>>,
+    )> {
+        let objects = self.objects.read().unwrap();
+        let mut live: Vec<_> = objects
+            .values()
+            .filter(|entry| entry.zombie.is_none())
+            .map(|entry| (entry.info, entry.desc, entry.created))
+            .collect();
+        live.sort_by_key(|(info, ..)| info.id);
+        live
+    }
+
+    /// The description of `id`'s interface.
+    pub(crate) fn desc(&self, id: u32) -> Option<&'static Interface> {
+        self.objects
+            .read()
+            .unwrap()
+            .get(&id)
+            .map(|entry| entry.desc)
+    }
+
+    /// The description of the interface called `name`, if a live object has it.
+    pub(crate) fn desc_named(&self, name: &str) -> Option<&'static Interface> {
+        let objects = self.objects.read().unwrap();
+        objects
+            .values()
+            .map(|entry| entry.desc)
+            .find(|desc| desc.name == name)
+    }
+
+    pub(crate) fn remove(&self, id: u32) -> Option<ObjectInfo> {
+        self.objects
+            .write()
+            .unwrap()
+            .remove(&id)
+            .map(|entry| entry.info)
+    }
+
+    pub(crate) fn mark_zombie(&self, id: u32, event_fds: &'static [u8]) {
+        if let Some(entry) = self.objects.write().unwrap().get_mut(&id) {
+            entry.zombie = Some(event_fds);
+        }
     }
-}
 
-unsafe impl Sync for IdCounter {}
+    /// The fd count of each event of `id` if it was destroyed.
+    pub(crate) fn zombie_event_fds(&self, id: u32) -> Option<&'static [u8]> {
+        self.objects
+            .read()
+            .unwrap()
+            .get(&id)
+            .and_then(|entry| entry.zombie)
+    }
+}
 
 #[derive(Debug)]
 pub struct Reader;
 #[derive(Debug)]
 pub struct Writer;
 
-const MAX_BUFFER_SIZE: usize = 4096;
+pub(crate) const MAX_BUFFER_SIZE: usize = 4096;
 
 //  TODO: find better way to handle interior mutability
 #[derive(Debug)]
@@ -141,28 +486,158 @@ pub struct WaylandBuffer<T> {
     pub(crate) data: RwLock<Bucket<u8, MAX_BUFFER_SIZE>>,
     pub(crate) fds: RwLock<Bucket<OwnedFd, 8>>,
     pub(crate) display_fd: RawFd,
-    _ghost: PhantomData<T>,
+    pub(crate) objects:    Arc<ObjectMap>,
+    pub(crate) tracer:     RwLock<Option<Arc<Tracer>>>,
+    pub(crate) logger:     LoggerSlot,
+    pub(crate) stats:      Counters,
+    side:                  Side,
+    // Whether `WAYLAND_DEBUG` asks for the messages of `side`
+    debug:                 bool,
+    // Whether sent messages are checked, see `crate::validate`
+    validate:              bool,
+    // How many of `fds` belong to the messages already in `data`
+    queued_fds:            AtomicUsize,
+    // Why a message couldn't be queued, every later send fails with it
+    failed:                OnceLock<io::Error>,
+    // Where the whole messages of the last read end, up to `read_end` follows the start of a
+    // message the next read completes
+    messages_end:          AtomicUsize,
+    read_end:              AtomicUsize,
+    _ghost:                PhantomData<T>,
+}
+
+impl<T> WaylandBuffer<T> {
+    /// Creates a proxy for `id` and tracks it in the connection's object map.
+    ///
+    /// In debug builds the caller is kept for [`Connection::leaks`].
+    #[track_caller]
+    pub fn new_object<O: Object>(&self, id: u32, version: u32) -> O {
+        // `Location::caller` has to be called here, passed on it would point into std
+        let created = if cfg!(debug_assertions) {
+            Some(Location::caller())
+        } else {
+            None
+        };
+        let info = ObjectInfo {
+            id,
+            interface: O::INTERFACE,
+            version,
+        };
+        self.objects.insert_created(info, O::DESC, created);
+        O::from_id(id)
+    }
+
+    /// Which end of the connection the buffer is on, messages are described accordingly.
+    pub(crate) fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self.debug = side.enabled();
+        self.validate = side == Side::Client && *validate::ENABLED;
+        self
+    }
+
+    /// Calls `f` with the logger of the messages going through this buffer, if it wants them.
+    ///
+    /// Without a logger set the default one only gets them when `WAYLAND_DEBUG` asks for this side.
+    fn message_logger(&self, f: impl FnOnce(&dyn Logger)) {
+        match self.logger.get() {
+            Some(logger) if logger.enabled(Level::Wayland) => f(&*logger),
+            Some(_) => {}
+            None if self.debug => f(&StderrLogger),
+            None => {}
+        }
+    }
+
+    /// The version `id` was created with, objects we don't know about are assumed to be version 1.
+    pub fn object_version(&self, id: u32) -> u32 {
+        self.objects.get(id).map_or(1, |info| info.version)
+    }
+
+    /// `id` if the other end may create an object with it: not 0, a client id below
+    /// [`SERVER_ID_START`](crate::server::SERVER_ID_START) when the other end is a client and
+    /// a server id otherwise, and not in use. libwayland fails messages with any other new id.
+    pub fn check_new_id(&self, id: u32) -> Option<u32> {
+        let from_server = id >= crate::server::SERVER_ID_START;
+        let peer_is_server = self.side == Side::Client;
+        (id != 0 && from_server == peer_is_server && self.objects.get(id).is_none()).then_some(id)
+    }
 }
 
 impl WaylandBuffer<Reader> {
-    fn new(display_fd: RawFd) -> WaylandBuffer<Reader> {
+    pub(crate) fn new(display_fd: RawFd, objects: Arc<ObjectMap>) -> WaylandBuffer<Reader> {
         Self {
             data: RwLock::new(Bucket::full()),
             fds: RwLock::new(Bucket::new()),
             display_fd,
+            objects,
+            tracer: RwLock::new(None),
+            logger: LoggerSlot::default(),
+            stats: Counters::default(),
+            side: Side::Client,
+            debug: false,
+            validate: false,
+            queued_fds: AtomicUsize::new(0),
+            failed: OnceLock::new(),
+            messages_end: AtomicUsize::new(0),
+            read_end: AtomicUsize::new(0),
             _ghost: PhantomData,
         }
     }
 
     pub fn get_fd(&self) -> Option<OwnedFd> {
-        self.fds.write().unwrap().pop()
+        self.fds.write().unwrap().take_first()
     }
 
-    fn recv(&self) -> std::io::Result<usize> {
+    /// Counts and logs `message` before it's handled, while its fds are still the first ones in
+    /// the buffer.
+    pub(crate) fn received(&self, message: &WlEvent<'_>, discarded: bool) {
+        self.stats
+            .message(&self.objects, self.side, false, message.header);
+        self.message_logger(|logger| {
+            let count = debug::fd_count(&self.objects, self.side, false, message.header);
+            let fds = self.fds.read().unwrap();
+            let fds: Vec<RawFd> = fds
+                .as_slice()
+                .iter()
+                .take(count)
+                .map(AsRawFd::as_raw_fd)
+                .collect();
+            let (header, data) = (message.header, message.data);
+            debug::log(
+                logger,
+                &self.objects,
+                self.side,
+                Direction::Received,
+                discarded,
+                header,
+                data,
+                &fds,
+            );
+        });
+    }
+
+    /// The whole messages of the last [`recv`](Self::recv), at the start of `data`.
+    pub(crate) fn messages_end(&self) -> usize {
+        self.messages_end.load(Ordering::Relaxed)
+    }
+
+    /// Reads from the socket, returns how many bytes arrived and 0 once the other end hung up.
+    ///
+    /// A message the last read ended in the middle of is moved to the start of `data` and
+    /// completed by this one, [`messages_end`](Self::messages_end) is where the whole messages
+    /// end.
+    pub(crate) fn recv(&self) -> std::io::Result<usize> {
         let mut buf = [0u8; 56];
         let mut fds = self.fds.write().unwrap();
-        fds.clear();
         let mut data = self.data.write().unwrap();
+        let start = self.messages_end.swap(0, Ordering::Relaxed);
+        let end = self.read_end.swap(0, Ordering::Relaxed);
+        let kept = end - start;
+        data.as_slice_mut().copy_within(start..end, 0);
+        // The fds that weren't taken belong to the kept message
+        if kept == 0 {
+            fds.clear();
+        }
+        let kept_fds = fds.len();
         unsafe {
             let mut msg_name: libc::sockaddr_un = core::mem::zeroed();
             let mut msghdr: libc::msghdr = core::mem::zeroed();
@@ -171,8 +646,8 @@ impl WaylandBuffer<Reader> {
             msghdr.msg_namelen = size_of::<libc::sockaddr_un>() as u32;
 
             let mut iov = libc::iovec {
-                iov_base: data.as_mut_ptr().cast(),
-                iov_len: data.len(),
+                iov_base: data[kept..].as_mut_ptr().cast(),
+                iov_len:  data.len() - kept,
             };
 
             msghdr.msg_iov = (&raw mut iov).cast();
@@ -198,37 +673,131 @@ impl WaylandBuffer<Reader> {
                         data.len() / size_of::<i32>(),
                     );
                     for fd in raw_fds {
-                        fds.push(OwnedFd::from_raw_fd(*fd));
+                        let fd = OwnedFd::from_raw_fd(*fd);
+                        // Only more fds than the kept messages took overflow it, those are closed
+                        if fds.can_fit(1) {
+                            fds.push(fd);
+                        }
                     }
                     log!(TRACE, "Recived ancillay data: {:?}", cmsghdr);
                 }
             }
             log!(TRACE, "Recieved {} bytes from fd {}", len, self.display_fd);
-            Ok(len as usize)
+            let len = len as usize;
+            self.stats.transfer(len, fds.len() - kept_fds);
+            if let Some(tracer) = &*self.tracer.read().unwrap() {
+                tracer.record(
+                    Direction::Received,
+                    &data[kept..kept + len],
+                    fds.len() - kept_fds,
+                );
+            }
+            let messages_end = whole_messages(&data[..kept + len]);
+            if kept > 0 && messages_end > 0 {
+                self.stats.reassembled();
+            }
+            self.messages_end.store(messages_end, Ordering::Relaxed);
+            self.read_end.store(kept + len, Ordering::Relaxed);
+            Ok(len)
         }
     }
 }
 
 impl WaylandBuffer<Writer> {
-    fn new(display_fd: RawFd) -> WaylandBuffer<Writer> {
+    pub(crate) fn new(display_fd: RawFd, objects: Arc<ObjectMap>) -> WaylandBuffer<Writer> {
         Self {
             data: RwLock::new(Bucket::new()),
             fds: RwLock::new(Bucket::new()),
             display_fd,
+            objects,
+            tracer: RwLock::new(None),
+            logger: LoggerSlot::default(),
+            stats: Counters::default(),
+            side: Side::Client,
+            debug: false,
+            validate: false,
+            queued_fds: AtomicUsize::new(0),
+            failed: OnceLock::new(),
+            messages_end: AtomicUsize::new(0),
+            read_end: AtomicUsize::new(0),
             _ghost: PhantomData::<Writer>,
         }
     }
 
     pub fn new_id(&self) -> u32 {
-        IDCOUNTER.get_new()
+        self.objects.next_client_id()
+    }
+
+    /// Marks `object` as destroyed, its id stays reserved until the compositor sends `delete_id`.
+    pub fn destroy_object<O: Object>(&self, object: &O) {
+        self.objects.mark_zombie(object.id(), O::EVENT_FDS);
     }
 
     pub fn write_request(&self, msg: &[u8]) {
+        // The fds of the message were added while encoding it
+        let fds = self
+            .fds
+            .read()
+            .unwrap()
+            .len()
+            .saturating_sub(self.queued_fds.load(Ordering::Relaxed));
+        if self.validate {
+            let header = Header::from_slice(&msg[..Header::HEADER_SIZE]);
+            validate::report(
+                &self.objects,
+                self.side,
+                header,
+                &msg[Header::HEADER_SIZE..],
+                fds,
+            );
+        }
+        #[cfg(debug_assertions)]
+        {
+            let id = Header::from_slice(&msg[..Header::HEADER_SIZE]).id;
+            debug_assert!(
+                self.objects.zombie_event_fds(id).is_none(),
+                "Sending a request on destroyed object {id}"
+            );
+        }
         if !self.data.read().unwrap().can_fit(msg.len()) {
             log!(TRACE, "Buffer can't fit additional {} bytes", msg.len());
-            self.send().unwrap();
+            if let Err(err) = self.send() {
+                return self.fail(err);
+            }
+            if !self.data.read().unwrap().can_fit(msg.len()) {
+                let err = format!("a {} byte message doesn't fit the buffer", msg.len());
+                return self.fail(io::Error::new(io::ErrorKind::InvalidInput, err));
+            }
         }
         self.data.write().unwrap().extend_from_slice(msg);
+        self.queued_fds
+            .store(self.fds.read().unwrap().len(), Ordering::Relaxed);
+        let header = Header::from_slice(&msg[..Header::HEADER_SIZE]);
+        self.stats.message(&self.objects, self.side, true, header);
+        self.message_logger(|logger| {
+            let count = debug::fd_count(&self.objects, self.side, true, header);
+            let fds = self.fds.read().unwrap();
+            let fds: Vec<RawFd> = fds.as_slice().iter().map(AsRawFd::as_raw_fd).collect();
+            let fds = &fds[fds.len().saturating_sub(count)..];
+            let body = &msg[Header::HEADER_SIZE..];
+            debug::log(
+                logger,
+                &self.objects,
+                self.side,
+                Direction::Sent,
+                false,
+                header,
+                body,
+                fds,
+            );
+        });
+    }
+
+    /// Drops the message that couldn't be queued because of `err`, the connection is broken
+    /// from here on and the next flush reports it.
+    fn fail(&self, err: io::Error) {
+        log!(ERR, "Couldn't queue a message: {err}");
+        let _ = self.failed.set(err);
     }
 
     pub fn add_fd(&self, fd: RawFd) {
@@ -239,7 +808,10 @@ impl WaylandBuffer<Writer> {
         log!(TRACE, "Added fd {} to pool", fd,);
     }
 
-    fn send(&self) -> std::io::Result<()> {
+    pub(crate) fn send(&self) -> std::io::Result<()> {
+        if let Some(err) = self.failed.get() {
+            return Err(io::Error::new(err.kind(), err.to_string()));
+        }
         let mut data = self.data.write().unwrap();
         let mut fds = self.fds.write().unwrap();
         if data.empty() {
@@ -247,6 +819,7 @@ impl WaylandBuffer<Writer> {
         }
 
         let flags = libc::MSG_NOSIGNAL;
+        let sent_fds = fds.len();
         let len = if fds.empty() {
             let len = unsafe {
                 syscall!(libc::send(
@@ -292,10 +865,15 @@ impl WaylandBuffer<Writer> {
 
                 let len = syscall!(libc::sendmsg(self.display_fd, &raw const msghdr, flags))?;
                 fds.clear();
+                self.queued_fds.store(0, Ordering::Relaxed);
                 len as usize
             }
         };
 
+        if let Some(tracer) = &*self.tracer.read().unwrap() {
+            tracer.record(Direction::Sent, &data[..len], sent_fds);
+        }
+        self.stats.transfer(len, sent_fds);
         let data_len = data.len();
         log!(
             TRACE,
@@ -313,6 +891,11 @@ impl WaylandBuffer<Writer> {
 }
 
 pub trait Object {
+    const INTERFACE: &'static str;
+    /// The number of fds carried by each event, indexed by opcode.
+    const EVENT_FDS: &'static [u8];
+    /// Names and signatures of the interface's messages.
+    const DESC: &'static crate::interface::Interface;
     type Event<'a>;
     fn from_id(id: u32) -> Self;
 
@@ -320,7 +903,195 @@ pub trait Object {
 
     fn interface(&self) -> &'static str;
 
+    /// `None` for unknown opcodes and events that don't match their description.
     fn parse_event<'a>(
         &self, reader: &WaylandBuffer<Reader>, event: crate::events::WlEvent<'a>,
-    ) -> Self::Event<'a>;
+    ) -> Option<Self::Event<'a>>;
+}
+
+/// Routes the events of an object to the methods of its generated `{Interface}Handler` trait,
+/// implemented by the scanner for every handler of the interface.
+pub trait Dispatch<H: ?Sized>: Object {
+    fn dispatch(&self, handler: &mut H, conn: &Connection, event: crate::events::WlEvent<'_>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wayland::*;
+
+    fn buffers() -> (WaylandBuffer<Writer>, WaylandBuffer<Reader>) {
+        let objects = Arc::new(ObjectMap::new());
+        (
+            WaylandBuffer::<Writer>::new(-1, objects.clone()),
+            WaylandBuffer::<Reader>::new(-1, objects),
+        )
+    }
+
+    /// The single message queued on `writer`.
+    fn sent(writer: &WaylandBuffer<Writer>) -> Vec<u8> {
+        writer.data.read().unwrap().as_slice().to_vec()
+    }
+
+    fn message(data: &[u8]) -> WlEvent<'_> {
+        EventIter::new(data).next().unwrap()
+    }
+
+    #[test]
+    fn request_round_trip() {
+        let (writer, reader) = buffers();
+        wl_surface::Request::Attach {
+            buffer: Some(Object::from_id(7)),
+            x:      -3,
+            y:      4,
+        }
+        .encode(5, &writer);
+        let data = sent(&writer);
+        let msg = message(&data);
+        assert_eq!(
+            (msg.header.id, msg.header.opcode, msg.header.size),
+            (5, 1, 20)
+        );
+        match wl_surface::Request::decode(&reader, msg) {
+            Some(wl_surface::Request::Attach {
+                buffer,
+                x,
+                y,
+            }) => {
+                assert_eq!(buffer.map(|b| b.id()), Some(7));
+                assert_eq!((x, y), (-3, 4));
+            }
+            other => panic!("decoded {other:?}"),
+        }
+    }
+
+    #[test]
+    fn null_strings_round_trip() {
+        for mime_type in [None, Some(""), Some("text/plain")] {
+            let (writer, reader) = buffers();
+            wl_data_offer::Request::Accept {
+                serial: 1,
+                mime_type,
+            }
+            .encode(5, &writer);
+            let data = sent(&writer);
+            match wl_data_offer::Request::decode(&reader, message(&data)) {
+                Some(wl_data_offer::Request::Accept {
+                    mime_type: decoded,
+                    ..
+                }) => {
+                    assert_eq!(decoded, mime_type)
+                }
+                other => panic!("decoded {other:?}"),
+            }
+        }
+        // Strings that aren't nullable can't be null, wl_data_offer.receive(nil, fd)
+        let (_, reader) = buffers();
+        reader
+            .fds
+            .write()
+            .unwrap()
+            .push(std::fs::File::open("/dev/null").unwrap().into());
+        let mut msg = Message::<12>::new(5, 1);
+        msg.write_null_string();
+        msg.build();
+        assert!(wl_data_offer::Request::decode(&reader, message(msg.data())).is_none());
+    }
+
+    #[test]
+    fn request_round_trip_generic_new_id() {
+        let (writer, reader) = buffers();
+        let request = wl_registry::Request::Bind {
+            name:      3,
+            interface: "wl_compositor",
+            version:   6,
+            id:        9,
+        };
+        assert_eq!(request.opcode(), 0);
+        request.encode(2, &writer);
+        let data = sent(&writer);
+        match wl_registry::Request::decode(&reader, message(&data)) {
+            Some(wl_registry::Request::Bind {
+                name,
+                interface,
+                version,
+                id,
+            }) => assert_eq!((name, interface, version, id), (3, "wl_compositor", 6, 9)),
+            other => panic!("decoded {other:?}"),
+        }
+    }
+
+    #[test]
+    fn request_decode_unknown_opcode() {
+        let (writer, reader) = buffers();
+        wl_surface::Request::Destroy.encode(5, &writer);
+        let mut data = sent(&writer);
+        data[4] = 42;
+        assert!(wl_surface::Request::decode(&reader, message(&data)).is_none());
+    }
+
+    #[test]
+    fn failed_writes_are_reported_by_the_next_send() {
+        // The buffer is sent once it's full, which fails without a socket
+        let (writer, _) = buffers();
+        let mut msg = Message::<12>::new(1, 0);
+        msg.write_u32(2);
+        msg.build();
+        for _ in 0..=MAX_BUFFER_SIZE / msg.data().len() {
+            writer.write_request(msg.data());
+        }
+        let err = writer.send().unwrap_err().to_string();
+        assert!(err.starts_with("Bad file descriptor"), "{err}");
+
+        let (writer, _) = buffers();
+        let mut msg = Message::<8>::new(1, 0);
+        msg.write_bytes(&[0; MAX_BUFFER_SIZE]);
+        msg.build();
+        writer.write_request(msg.data());
+        assert_eq!(
+            writer.send().unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn decoded_new_ids_are_checked_and_tracked() {
+        let (_, reader) = buffers();
+        let reader = reader.side(Side::Server);
+        reader.objects.insert(
+            ObjectInfo {
+                id:        3,
+                interface: "wl_compositor",
+                version:   6,
+            },
+            wl_compositor::WlCompositor::DESC,
+        );
+        let create_surface = |id| {
+            let mut msg = Message::<12>::new(3, 0);
+            msg.write_u32(id);
+            msg.build();
+            msg.data().to_vec()
+        };
+        for id in [0, 3, crate::server::SERVER_ID_START] {
+            let data = create_surface(id);
+            let decoded = wl_compositor::Request::decode(&reader, message(&data));
+            assert!(decoded.is_none(), "{id} was accepted");
+        }
+        let data = create_surface(4);
+        match wl_compositor::Request::decode(&reader, message(&data)) {
+            Some(wl_compositor::Request::CreateSurface {
+                id,
+            }) => assert_eq!(id.id(), 4),
+            other => panic!("decoded {other:?}"),
+        }
+        let info = reader.objects.get(4).unwrap();
+        assert_eq!((info.interface, info.version), ("wl_surface", 6));
+        assert!(wl_compositor::Request::decode(&reader, message(&data)).is_none());
+
+        // wl_shm.create_pool without its fd
+        let mut msg = Message::<16>::new(3, 0);
+        msg.write_u32(5).write_i32(4096);
+        msg.build();
+        assert!(wl_shm::Request::decode(&reader, message(msg.data())).is_none());
+    }
 }
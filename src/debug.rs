@@ -0,0 +1,364 @@
+//! `WAYLAND_DEBUG` output, in the format libwayland prints it so the same tools and diffing
+//! scripts work on both:
+//!
+//! ```text
+//! [1234567.890]  -> wl_display@1.get_registry(new id wl_registry@2)
+//! [1234567.912] wl_registry@2.global(1, "wl_compositor", 6)
+//! [1234568.020] discarded wl_pointer@9.motion(12345, 10.00000000, 4.50000000)
+//! ```
+//!
+//! `WAYLAND_DEBUG=client` prints what a [`Connection`](crate::connection::Connection) sends
+//! and receives, `WAYLAND_DEBUG=server` what a [`Client`](crate::server::Client) of a server
+//! does and `WAYLAND_DEBUG=1` both. Messages are described from the raw bytes and the
+//! [`Interface`] descriptions, the generated code doesn't take part, and handed to the
+//! [`Logger`] as [`Level::Wayland`] records.
+
+use std::fmt::Write;
+use std::os::fd::RawFd;
+use std::sync::LazyLock;
+
+use crate::connection::ObjectMap;
+use crate::events::Header;
+use crate::events::{EventDataParser, Fixed};
+use crate::interface::{ArgType, Interface, MessageDesc};
+use crate::logger::{Level, Logger, Message, Record};
+use crate::trace::Direction;
+
+static WAYLAND_DEBUG: LazyLock<Option<String>> =
+    LazyLock::new(|| std::env::var("WAYLAND_DEBUG").ok());
+
+/// Which end of the connection a buffer belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Side {
+    Client,
+    Server,
+}
+
+impl Side {
+    /// Whether `WAYLAND_DEBUG` asks for the messages of this side, matched like libwayland
+    /// does, anywhere in the value.
+    pub(crate) fn enabled(self) -> bool {
+        let name = match self {
+            Self::Client => "client",
+            Self::Server => "server",
+        };
+        WAYLAND_DEBUG
+            .as_deref()
+            .is_some_and(|debug| debug.contains(name) || debug.contains('1'))
+    }
+
+    /// The messages this side sends are requests for clients and events for servers.
+    pub(crate) fn message(
+        self, interface: &Interface, sent: bool, opcode: u16,
+    ) -> Option<&'static MessageDesc> {
+        if (self == Self::Client) == sent {
+            interface.request(opcode)
+        } else {
+            interface.event(opcode)
+        }
+    }
+}
+
+/// Hands the message `header` introduces to `logger`, `body` are its arguments and `fds` the
+/// fds it carries.
+///
+/// Messages to objects we don't know about are left out, libwayland fails on those before
+/// getting to print them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn log(
+    logger: &dyn Logger, objects: &ObjectMap, side: Side, direction: Direction, discarded: bool,
+    header: Header, body: &[u8], fds: &[RawFd],
+) {
+    let sent = direction == Direction::Sent;
+    let Some((interface, desc, args)) = describe(objects, side, sent, header, body, fds) else {
+        return;
+    };
+    let message = Message {
+        direction,
+        discarded,
+        object_id: header.id,
+        interface: interface.name,
+        opcode: header.opcode,
+        name: desc.name,
+        args: &args,
+    };
+    logger.log(&Record {
+        level:   Level::Wayland,
+        text:    format_args!("{}@{}.{}({})", interface.name, header.id, desc.name, args),
+        message: Some(&message),
+    });
+}
+
+/// The number of fds `message` carries, so they can be picked out of a buffer.
+pub(crate) fn fd_count(objects: &ObjectMap, side: Side, sent: bool, header: Header) -> usize {
+    objects
+        .desc(header.id)
+        .and_then(|interface| side.message(interface, sent, header.opcode))
+        .map_or(0, |desc| {
+            desc.args.iter().filter(|arg| arg.ty == ArgType::Fd).count()
+        })
+}
+
+/// The interface and description of a message, and its arguments as libwayland prints them.
+fn describe(
+    objects: &ObjectMap, side: Side, sent: bool, header: Header, body: &[u8], fds: &[RawFd],
+) -> Option<(&'static Interface, &'static MessageDesc, String)> {
+    let interface = objects.desc(header.id)?;
+    let desc = side.message(interface, sent, header.opcode)?;
+    let args = EventDataParser::new(body);
+    let mut fds = fds.iter();
+
+    let mut line = String::new();
+    for (i, arg) in desc.args.iter().enumerate() {
+        if i > 0 {
+            line.push_str(", ");
+        }
+        match arg.ty {
+            ArgType::Int => write!(line, "{}", args.get_i32()?).unwrap(),
+            ArgType::Uint => write!(line, "{}", args.get_u32()?).unwrap(),
+            ArgType::Fixed => write!(line, "{}", Fixed(args.get_i32()?)).unwrap(),
+            ArgType::String => match args.get_nullable_string()? {
+                Some(string) => write!(line, "\"{string}\"").unwrap(),
+                None => line.push_str("nil"),
+            },
+            ArgType::Object => match args.get_u32()? {
+                0 => line.push_str("nil"),
+                id => {
+                    let name = objects
+                        .get(id)
+                        .map(|info| info.interface)
+                        .or(arg.interface.map(|interface| interface.name))
+                        .unwrap_or("[unknown]");
+                    write!(line, "{name}@{id}").unwrap()
+                }
+            },
+            ArgType::NewId => {
+                // Untyped ids are preceded by the interface name and version, libwayland's
+                // signature spells them out as separate arguments
+                if arg.interface.is_none() {
+                    match args.get_nullable_string()? {
+                        Some(string) => write!(line, "\"{string}\", ").unwrap(),
+                        None => line.push_str("nil, "),
+                    }
+                    write!(line, "{}, ", args.get_u32()?).unwrap();
+                }
+                let name = arg
+                    .interface
+                    .map_or("[unknown]", |interface| interface.name);
+                match args.get_u32()? {
+                    0 => write!(line, "new id {name}@nil").unwrap(),
+                    id => write!(line, "new id {name}@{id}").unwrap(),
+                }
+            }
+            ArgType::Array => write!(line, "array[{}]", args.get_bytes()?.len()).unwrap(),
+            ArgType::Fd => write!(line, "fd {}", fds.next().copied().unwrap_or(-1)).unwrap(),
+        }
+    }
+    Some((interface, desc, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::{Object, ObjectInfo};
+    use crate::wayland::*;
+
+    fn message(id: u32, opcode: u16, args: &[&[u8]]) -> Vec<u8> {
+        let body = args.concat();
+        let size = (8 + body.len()) as u32;
+        [
+            &id.to_ne_bytes()[..],
+            &(size << 16 | opcode as u32).to_ne_bytes(),
+            &body,
+        ]
+        .concat()
+    }
+
+    fn format(
+        objects: &ObjectMap, side: Side, sent: bool, message: &[u8], fds: &[RawFd],
+    ) -> Option<String> {
+        let header = Header::from_slice(&message[..Header::HEADER_SIZE]);
+        let body = &message[Header::HEADER_SIZE..];
+        let (interface, desc, args) = describe(objects, side, sent, header, body, fds)?;
+        Some(format!(
+            "{}@{}.{}({args})",
+            interface.name, header.id, desc.name
+        ))
+    }
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut out = ((s.len() + 1) as u32).to_ne_bytes().to_vec();
+        out.extend_from_slice(s.as_bytes());
+        out.resize(4 + (s.len() + 1).next_multiple_of(4), 0);
+        out
+    }
+
+    fn objects() -> ObjectMap {
+        let objects = ObjectMap::new();
+        for (id, interface, desc) in [
+            (
+                1,
+                wl_display::WlDisplay::INTERFACE,
+                wl_display::WlDisplay::DESC,
+            ),
+            (
+                2,
+                wl_registry::WlRegistry::INTERFACE,
+                wl_registry::WlRegistry::DESC,
+            ),
+            (
+                5,
+                wl_surface::WlSurface::INTERFACE,
+                wl_surface::WlSurface::DESC,
+            ),
+            (
+                6,
+                wl_pointer::WlPointer::INTERFACE,
+                wl_pointer::WlPointer::DESC,
+            ),
+            (
+                7,
+                wl_keyboard::WlKeyboard::INTERFACE,
+                wl_keyboard::WlKeyboard::DESC,
+            ),
+            (8, wl_buffer::WlBuffer::INTERFACE, wl_buffer::WlBuffer::DESC),
+        ] {
+            let info = ObjectInfo {
+                id,
+                interface,
+                version: 1,
+            };
+            objects.insert(info, desc);
+        }
+        objects
+    }
+
+    #[test]
+    fn requests_and_new_ids() {
+        let objects = objects();
+        let get_registry = message(1, 1, &[&2u32.to_ne_bytes()]);
+        assert_eq!(
+            format(&objects, Side::Client, true, &get_registry, &[]).unwrap(),
+            "wl_display@1.get_registry(new id wl_registry@2)"
+        );
+
+        let bind = message(
+            2,
+            0,
+            &[
+                &1u32.to_ne_bytes(),
+                &string("wl_seat"),
+                &7u32.to_ne_bytes(),
+                &3u32.to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            format(&objects, Side::Client, true, &bind, &[]).unwrap(),
+            r#"wl_registry@2.bind(1, "wl_seat", 7, new id [unknown]@3)"#
+        );
+
+        // The same bytes are an event on the other side
+        let global = message(
+            2,
+            0,
+            &[&1u32.to_ne_bytes(), &string("wl_seat"), &7u32.to_ne_bytes()],
+        );
+        assert_eq!(
+            format(&objects, Side::Client, false, &global, &[]).unwrap(),
+            r#"wl_registry@2.global(1, "wl_seat", 7)"#
+        );
+        assert_eq!(
+            format(&objects, Side::Server, true, &global, &[]).unwrap(),
+            r#"wl_registry@2.global(1, "wl_seat", 7)"#
+        );
+    }
+
+    #[test]
+    fn nil_fixed_fd_and_array() {
+        let objects = objects();
+        let attach = message(
+            5,
+            1,
+            &[
+                &0u32.to_ne_bytes(),
+                &0u32.to_ne_bytes(),
+                &(-2i32).to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            format(&objects, Side::Server, false, &attach, &[]).unwrap(),
+            "wl_surface@5.attach(nil, 0, -2)"
+        );
+        let attach = message(
+            5,
+            1,
+            &[
+                &8u32.to_ne_bytes(),
+                &0u32.to_ne_bytes(),
+                &0u32.to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            format(&objects, Side::Client, true, &attach, &[]).unwrap(),
+            "wl_surface@5.attach(wl_buffer@8, 0, 0)"
+        );
+
+        let motion = message(
+            6,
+            2,
+            &[
+                &1234u32.to_ne_bytes(),
+                &(10 * 256 + 128i32).to_ne_bytes(),
+                &(-(4 * 256 + 64i32)).to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            format(&objects, Side::Client, false, &motion, &[]).unwrap(),
+            "wl_pointer@6.motion(1234, 10.50000000, -4.25000000)"
+        );
+
+        let keymap = message(7, 0, &[&1u32.to_ne_bytes(), &100u32.to_ne_bytes()]);
+        assert_eq!(
+            format(&objects, Side::Client, false, &keymap, &[12]).unwrap(),
+            "wl_keyboard@7.keymap(1, fd 12, 100)"
+        );
+        let header = Header::from_slice(&keymap[..Header::HEADER_SIZE]);
+        assert_eq!(fd_count(&objects, Side::Client, false, header), 1);
+
+        let enter = message(
+            7,
+            1,
+            &[
+                &9u32.to_ne_bytes(),
+                &5u32.to_ne_bytes(),
+                &8u32.to_ne_bytes(),
+                &[1, 0, 0, 0, 2, 0, 0, 0],
+            ],
+        );
+        assert_eq!(
+            format(&objects, Side::Client, false, &enter, &[]).unwrap(),
+            "wl_keyboard@7.enter(9, wl_surface@5, array[8])"
+        );
+
+        let error = message(
+            1,
+            0,
+            &[
+                &0u32.to_ne_bytes(),
+                &1u32.to_ne_bytes(),
+                &0u32.to_ne_bytes(),
+            ],
+        );
+        assert_eq!(
+            format(&objects, Side::Client, false, &error, &[]).unwrap(),
+            "wl_display@1.error(nil, 1, nil)"
+        );
+    }
+
+    #[test]
+    fn unknown_objects_are_skipped() {
+        let objects = objects();
+        assert!(format(&objects, Side::Client, false, &message(42, 0, &[]), &[]).is_none());
+        assert!(format(&objects, Side::Client, false, &message(1, 9, &[]), &[]).is_none());
+    }
+}
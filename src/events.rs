@@ -1,5 +1,7 @@
 use std::cell::Cell;
+use std::fmt;
 
+use crate::connection::MAX_BUFFER_SIZE;
 use crate::log;
 
 #[derive(Debug, Clone, Copy)]
@@ -23,11 +25,12 @@ impl<'a> Iterator for EventIter<'a> {
         let header = &self.buf[0..Header::HEADER_SIZE];
         let header = Header::from_slice(header);
 
-        // FIXME: Implement some mechanism to keep old data there
+        // The reader keeps the start of a message for the next read, see `whole_messages`, so
+        // this is only reached by buffers that weren't read from the socket
         if self.buf.len() < header.size as usize {
-            eprintln!(
-                "[\x1b[32mERROR\x1b[0m]: Recieived buffer is less than advertised size in the header: {:?},
-                discarding the entire buffer",
+            log!(
+                ERR,
+                "Recieived buffer is less than advertised size in the header: {:?}, discarding the entire buffer",
                 header
             );
             return None;
@@ -53,6 +56,24 @@ impl<'a> Iterator for EventIter<'a> {
     }
 }
 
+/// How many bytes at the start of `data` are whole messages, the rest is the start of a message
+/// the next read completes. A malformed header, which no read would complete, ends `data` as a
+/// whole so [`EventIter`] gets to it.
+pub(crate) fn whole_messages(data: &[u8]) -> usize {
+    let mut end = 0;
+    while let Some(header) = data.get(end..end + Header::HEADER_SIZE) {
+        let size = Header::from_slice(header).size as usize;
+        if !(Header::HEADER_SIZE..=MAX_BUFFER_SIZE).contains(&size) {
+            return data.len();
+        }
+        if data.len() - end < size {
+            break;
+        }
+        end += size;
+    }
+    end
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct WlEvent<'a> {
     pub header: Header,
@@ -90,6 +111,62 @@ impl Header {
     }
 }
 
+/// A 24.8 fixed point number as it's on the wire, displayed like libwayland's debug output
+/// does with `%d.%08d`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixed(pub i32);
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 390625 is 1e8 / 256
+        let Self(fixed) = *self;
+        if fixed >= 0 {
+            write!(f, "{}.{:08}", fixed / 256, 390625 * (fixed % 256))
+        } else {
+            write!(f, "-{}.{:08}", fixed / -256, -390625 * (fixed % 256))
+        }
+    }
+}
+
+/// A value of a non-bitfield enum received in an event.
+///
+/// Compositors can send values added in protocol versions newer than the
+/// xml we generated from, those end up in `Unknown` instead of being lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WEnum<T> {
+    Value(T),
+    Unknown(u32),
+}
+
+impl<T> WEnum<T> {
+    pub fn into_result(self) -> Result<T, u32> {
+        match self {
+            Self::Value(value) => Ok(value),
+            Self::Unknown(value) => Err(value),
+        }
+    }
+}
+
+impl<T: TryFrom<u32, Error = u32>> From<u32> for WEnum<T> {
+    fn from(value: u32) -> Self {
+        match T::try_from(value) {
+            Ok(value) => Self::Value(value),
+            Err(value) => Self::Unknown(value),
+        }
+    }
+}
+
+impl<T: Into<u32>> From<WEnum<T>> for u32 {
+    fn from(value: WEnum<T>) -> u32 {
+        match value {
+            WEnum::Value(value) => value.into(),
+            WEnum::Unknown(value) => value,
+        }
+    }
+}
+
+/// Reads the arguments of a message in order, every getter gives `None` once the data runs
+/// out or doesn't hold what was asked for, so malformed messages can't take anything down.
 // #[derive(Debug, Clone, Copy)]
 pub struct EventDataParser<'a> {
     pub data: &'a [u8],
@@ -104,83 +181,89 @@ impl<'a> EventDataParser<'a> {
         }
     }
 
-    pub fn get_u16(&self) -> u16 {
+    /// The next `len` bytes, the cursor moves past their padding.
+    fn take(&self, len: usize) -> Option<&'a [u8]> {
         let idx = self.idx.get();
-        let data = &self.data[idx..];
-        let num = u16::from_ne_bytes([data[0], data[1]]);
-        self.idx.replace(idx + core::mem::size_of::<u16>());
-        num
+        let data: &'a [u8] = self.data;
+        let padded = data.get(idx..idx.checked_add(roundup(len, 4))?)?;
+        self.idx.replace(idx + padded.len());
+        Some(&padded[..len])
     }
 
-    pub fn get_fixed(&self) -> f32 {
-        let idx = self.idx.get();
-        let data = &self.data[idx..];
-        let num = i32::from_ne_bytes([data[0], data[1], data[2], data[3]]) as f32;
-        self.idx.replace(idx + core::mem::size_of::<i32>());
-        num / 256.0
+    /// The bytes after the arguments read so far.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.idx.get()
     }
 
-    pub fn get_u32(&self) -> u32 {
+    pub fn get_u16(&self) -> Option<u16> {
         let idx = self.idx.get();
-        let data = &self.data[idx..];
-        // let num = u32::from_ne_bytes(data[0..4]);
-        let num = u32::from_ne_bytes([data[0], data[1], data[2], data[3]]);
-        self.idx.replace(idx + core::mem::size_of::<u32>());
-        num
+        let bytes = self.data.get(idx..idx.checked_add(2)?)?;
+        self.idx.replace(idx + core::mem::size_of::<u16>());
+        Some(u16::from_ne_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn get_fixed(&self) -> Option<f32> {
+        Some(self.get_i32()? as f32 / 256.0)
+    }
+
+    pub fn get_u32(&self) -> Option<u32> {
+        let bytes = self.take(size_of::<u32>())?;
+        Some(u32::from_ne_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// `None` if the string is null, isn't nul terminated or isn't UTF-8.
+    pub fn get_string(&self) -> Option<&'a str> {
+        self.get_nullable_string()?
     }
 
-    pub fn get_string<'b>(&'a self) -> &'b str {
-        let str_len = self.get_u32() as usize;
-        if str_len == 0 { // Test this
-            return "";
+    /// A string of a nullable argument, `Some(None)` if it's null.
+    pub fn get_nullable_string(&self) -> Option<Option<&'a str>> {
+        let str_len = self.get_u32()? as usize;
+        if str_len == 0 {
+            return Some(None);
         }
-        let idx = self.idx.get();
-        let data = &self.data[idx..];
-        let padded_len = roundup(str_len, 4);
-        // Null terminator not included
-        let str = &data[..str_len - 1];
-        self.idx.replace(idx + padded_len);
-
-        // FIXME: This should be removed once migrating to a dipatcher model is done
-        // SAFETY: the reference behind message is valid for as long
-        // as the event.data is valid, Rust just can't know it
-        unsafe {
-            let len = str.len();
-            let ptr = str.as_ptr();
-            let slice = core::slice::from_raw_parts(ptr, len);
-            core::str::from_utf8_unchecked(slice)
+        let (nul, string) = self.take(str_len)?.split_last()?;
+        if *nul != 0 {
+            return None;
         }
+        core::str::from_utf8(string).ok().map(Some)
     }
 
-    pub fn get_array<'b>(&'a self) -> &'b [u32] {
-        let array_len = self.get_u32() as usize;
-        let idx = self.idx.get();
-        let data = &self.data[idx..];
-        let array = unsafe {
-            let ptr = data[..array_len].as_ptr().cast();
-            core::slice::from_raw_parts(ptr, array_len / size_of::<u32>())
-        };
-        self.idx.replace(idx + array_len);
-        array
+    /// An array as the bytes on the wire, `None` if it's longer than the message.
+    pub fn get_bytes(&self) -> Option<&'a [u8]> {
+        let array_len = self.get_u32()? as usize;
+        self.take(array_len)
     }
 
-    pub fn get_i32(&self) -> i32 {
-        let idx = self.idx.get();
-        let data = &self.data[idx..];
-        let num = i32::from_ne_bytes([data[0], data[1], data[2], data[3]]);
-        self.idx.replace(idx + core::mem::size_of::<u32>());
-        num
+    /// `None` if the array is longer than the message or the message isn't 32 bit aligned,
+    /// a trailing partial element is left out.
+    pub fn get_array(&self) -> Option<&'a [u32]> {
+        let bytes = self.get_bytes()?;
+        let array_len = bytes.len();
+        let bytes = &bytes[..array_len - array_len % size_of::<u32>()];
+        // SAFETY: any bit pattern is a valid u32, align_to only hands out aligned elements
+        let (prefix, array, _) = unsafe { bytes.align_to::<u32>() };
+        prefix.is_empty().then_some(array)
+    }
+
+    pub fn get_i32(&self) -> Option<i32> {
+        let bytes = self.take(size_of::<i32>())?;
+        Some(i32::from_ne_bytes(bytes.try_into().unwrap()))
     }
 }
 
 fn roundup(value: usize, mul: usize) -> usize {
-    (((value - 1) / mul) + 1) * mul
+    value.next_multiple_of(mul)
 }
 
+/// A message being built, kept in `S` bytes on the stack unless strings or arrays make it
+/// longer.
 #[derive(Debug, Clone)]
 pub struct Message<const S: usize> {
-    buf: [u8; S],
-    len: usize,
+    buf:     [u8; S],
+    // Takes over once the message outgrows `buf`
+    spilled: Vec<u8>,
+    len:     usize,
 }
 
 impl<const S: usize> Message<S> {
@@ -191,62 +274,412 @@ impl<const S: usize> Message<S> {
     }
 
     pub fn build(&mut self) {
-        self.buf[6..8].copy_from_slice(&(self.len as u16).to_ne_bytes());
+        let size = (self.len as u16).to_ne_bytes();
+        self.data_mut()[6..8].copy_from_slice(&size);
     }
 
     fn empty() -> Self {
         Self {
-            buf: [0; S],
-            len: 0,
+            buf:     [0; S],
+            spilled: Vec::new(),
+            len:     0,
         }
     }
 
+    fn extend(&mut self, bytes: &[u8]) {
+        if self.spilled.is_empty() && self.len + bytes.len() <= S {
+            self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        } else {
+            if self.spilled.is_empty() {
+                self.spilled.extend_from_slice(&self.buf[..self.len]);
+            }
+            self.spilled.extend_from_slice(bytes);
+        }
+        self.len += bytes.len();
+    }
+
     pub fn write_i32(&mut self, value: i32) -> &mut Self {
-        const SIZE: usize = size_of::<i32>();
-        self.buf[self.len..self.len + SIZE].copy_from_slice(&value.to_ne_bytes());
-        self.len += SIZE;
+        self.extend(&value.to_ne_bytes());
         self
     }
 
     pub fn write_u32(&mut self, value: u32) -> &mut Self {
-        const SIZE: usize = size_of::<u32>();
-        self.buf[self.len..self.len + SIZE].copy_from_slice(&value.to_ne_bytes());
-        self.len += SIZE;
+        self.extend(&value.to_ne_bytes());
         self
     }
 
-    // TODO: Does this actually work??
+    /// Writes `value` as a 24.8 fixed point number.
     pub fn write_fixed(&mut self, value: f32) -> &mut Self {
-        let wl_fixed = f32::to_bits((value * 256.0).round());
-        self.write_u32(wl_fixed);
-        self
+        self.write_i32((value * 256.0).round() as i32)
     }
 
     pub fn write_u16(&mut self, value: u16) -> &mut Self {
-        const SIZE: usize = size_of::<u16>();
-        self.buf[self.len..self.len + SIZE].copy_from_slice(&value.to_ne_bytes());
-        self.len += SIZE;
+        self.extend(&value.to_ne_bytes());
         self
     }
 
+    /// Writes `str` with its null terminator, padded to 32 bits. An empty string is still a
+    /// string, nulls are written with [`write_null_string`](Self::write_null_string).
     pub fn write_string(&mut self, str: impl AsRef<str>) -> &mut Self {
         let str = str.as_ref();
-        if str.is_empty() { // TODO: test this
-            self.write_u32(0);
-            return self;
-        }
         // null included
         self.write_u32((str.len() + 1) as u32);
-        self.buf[self.len..str.len() + self.len].copy_from_slice(str.as_bytes());
-        self.len += roundup(str.len() + 1, 4);
+        self.extend(str.as_bytes());
+        let padding = roundup(str.len() + 1, 4) - str.len();
+        self.extend(&[0; 4][..padding]);
+        self
+    }
+
+    /// Writes the null string of nullable string arguments.
+    pub fn write_null_string(&mut self) -> &mut Self {
+        self.write_u32(0)
+    }
+
+    pub fn write_array(&mut self, array: &[u32]) -> &mut Self {
+        // Length in bytes
+        self.write_u32(size_of_val(array) as u32);
+        for &value in array {
+            self.write_u32(value);
+        }
+        self
+    }
+
+    /// Writes an array of any element type as the bytes on the wire, padded to 32 bits.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.write_u32(bytes.len() as u32);
+        self.extend(bytes);
+        let padding = roundup(bytes.len(), 4) - bytes.len();
+        self.extend(&[0; 4][..padding]);
         self
     }
 
     pub fn data_mut(&mut self) -> &mut [u8] {
-        &mut self.buf[..self.len]
+        if self.spilled.is_empty() {
+            &mut self.buf[..self.len]
+        } else {
+            &mut self.spilled
+        }
     }
 
     pub fn data(&self) -> &[u8] {
-        &self.buf[..self.len]
+        if self.spilled.is_empty() {
+            &self.buf[..self.len]
+        } else {
+            &self.spilled
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// xorshift64, enough to vary the values and keep failures reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+
+        /// Mostly short strings, so lengths around multiples of 4 come up often.
+        fn string(&mut self) -> String {
+            let len = if self.below(2) == 0 {
+                self.below(9)
+            } else {
+                self.below(120)
+            };
+            (0..len)
+                .map(|_| ['a', 'z', '0', ' ', 'é', '✓'][self.below(6)])
+                .collect()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Value {
+        Int(i32),
+        Uint(u32),
+        Fixed(i32),
+        String(Option<String>),
+        Array(Vec<u32>),
+        Fd,
+    }
+
+    /// The libwayland signature of every message of the core protocol and `scr_protocols`.
+    fn signatures() -> Vec<(String, String)> {
+        let mut all = Vec::new();
+        for iface in crate::wayland::INTERFACES {
+            for msg in iface.requests.iter().chain(iface.events) {
+                all.push((format!("{}.{}", iface.name, msg.name), msg.signature()));
+            }
+        }
+        for iface in scr_protocols::ALL.concat() {
+            for msg in iface.requests.iter().chain(iface.events) {
+                all.push((format!("{}.{}", iface.name, msg.name), msg.signature()));
+            }
+        }
+        all
+    }
+
+    /// Random arguments for `signature`.
+    fn values(rng: &mut Rng, signature: &str) -> Vec<Value> {
+        let mut values = Vec::new();
+        let mut nullable = false;
+        for ty in signature.chars().skip_while(char::is_ascii_digit) {
+            let value = match ty {
+                '?' => {
+                    nullable = true;
+                    continue;
+                }
+                'i' => Value::Int(rng.next() as i32),
+                'u' | 'n' => Value::Uint(rng.next() as u32),
+                'o' if nullable && rng.below(4) == 0 => Value::Uint(0),
+                'o' => Value::Uint(rng.next() as u32 | 1),
+                // Anything in 24.8 fits an f32 exactly
+                'f' => Value::Fixed((rng.next() as i32) >> 8),
+                's' if nullable && rng.below(4) == 0 => Value::String(None),
+                's' => Value::String(Some(rng.string())),
+                'a' => Value::Array((0..rng.below(40)).map(|_| rng.next() as u32).collect()),
+                'h' => Value::Fd,
+                _ => panic!("unexpected {ty} in {signature}"),
+            };
+            values.push(value);
+            nullable = false;
+        }
+        values
+    }
+
+    /// The size of a message with `values` according to the spec.
+    fn wire_size(values: &[Value]) -> usize {
+        let arg = |value: &Value| match value {
+            Value::String(None) => 4,
+            Value::String(Some(string)) => 4 + (string.len() + 1).next_multiple_of(4),
+            Value::Array(array) => 4 + array.len() * 4,
+            Value::Fd => 0,
+            _ => 4,
+        };
+        Header::HEADER_SIZE + values.iter().map(arg).sum::<usize>()
+    }
+
+    fn encode(id: u32, opcode: u16, values: &[Value]) -> Vec<u8> {
+        // Small so most messages outgrow it
+        let mut msg = Message::<16>::new(id, opcode);
+        for value in values {
+            match value {
+                Value::Int(value) => msg.write_i32(*value),
+                Value::Uint(value) => msg.write_u32(*value),
+                Value::Fixed(raw) => msg.write_fixed(*raw as f32 / 256.0),
+                Value::String(Some(string)) => msg.write_string(string),
+                Value::String(None) => msg.write_null_string(),
+                Value::Array(array) => msg.write_array(array),
+                Value::Fd => &mut msg,
+            };
+        }
+        msg.build();
+        msg.data().to_vec()
+    }
+
+    fn decode(parser: &EventDataParser<'_>, expected: &[Value]) -> Vec<Value> {
+        expected
+            .iter()
+            .map(|value| match value {
+                Value::Int(_) => Value::Int(parser.get_i32().unwrap()),
+                Value::Uint(_) => Value::Uint(parser.get_u32().unwrap()),
+                Value::Fixed(_) => Value::Fixed((parser.get_fixed().unwrap() * 256.0) as i32),
+                Value::String(_) => {
+                    Value::String(parser.get_nullable_string().unwrap().map(str::to_string))
+                }
+                Value::Array(_) => Value::Array(parser.get_array().unwrap().to_vec()),
+                Value::Fd => Value::Fd,
+            })
+            .collect()
+    }
+
+    /// `bytes` copied to a 32 bit aligned buffer, like the ones messages are received in.
+    fn aligned(bytes: &[u8]) -> Vec<u32> {
+        bytes
+            .chunks(4)
+            .map(|word| u32::from_ne_bytes(word.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn every_signature_round_trips() {
+        let signatures = signatures();
+        assert!(signatures.len() > 250);
+        let mut rng = Rng(0x5c7a_7c4a_11ed_0001);
+        for (name, signature) in &signatures {
+            for _ in 0..32 {
+                let values = values(&mut rng, signature);
+                let opcode = rng.below(8) as u16;
+                let data = encode(7, opcode, &values);
+                assert_eq!(
+                    data.len(),
+                    wire_size(&values),
+                    "{name} ({signature}) {values:?}"
+                );
+                assert_eq!(data.len() % 4, 0, "{name} isn't 32 bit aligned");
+
+                let words = aligned(&data);
+                let bytes =
+                    unsafe { core::slice::from_raw_parts(words.as_ptr().cast(), data.len()) };
+                let mut iter = EventIter::new(bytes);
+                let msg = iter.next().unwrap();
+                assert!(iter.next().is_none());
+                assert_eq!((msg.header.id, msg.header.opcode), (7, opcode));
+                assert_eq!(msg.header.size as usize, data.len());
+                let parser = msg.parser();
+                assert_eq!(decode(&parser, &values), values, "{name} ({signature})");
+                assert_eq!(parser.idx.get(), msg.data.len(), "{name} left bytes unread");
+            }
+        }
+    }
+
+    #[test]
+    fn strings_are_terminated_and_padded() {
+        let mut msg = Message::<8>::new(1, 0);
+        msg.write_string("")
+            .write_string("abc")
+            .write_string("abcd")
+            .write_null_string();
+        msg.build();
+        // The opcode and size of the header, then the strings
+        let mut expected = vec![0, 0, 40, 0];
+        expected.extend([1, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend([4, 0, 0, 0, b'a', b'b', b'c', 0]);
+        expected.extend([5, 0, 0, 0, b'a', b'b', b'c', b'd', 0, 0, 0, 0]);
+        expected.extend([0, 0, 0, 0]);
+        assert_eq!(msg.data()[4..], expected[..]);
+    }
+
+    #[test]
+    fn fixed_point_is_24_8() {
+        let mut msg = Message::<20>::new(1, 0);
+        msg.write_fixed(1.5).write_fixed(-0.25).write_fixed(100.0);
+        let words = aligned(&msg.data()[8..]);
+        assert_eq!(words, [384, (-64i32) as u32, 25600]);
+    }
+
+    #[test]
+    fn empty_arrays_and_roundup() {
+        assert_eq!(roundup(0, 4), 0);
+        assert_eq!(roundup(5, 4), 8);
+        let data = aligned(&[0u32.to_ne_bytes(), 42u32.to_ne_bytes()].concat());
+        let bytes = unsafe { core::slice::from_raw_parts(data.as_ptr().cast(), 8) };
+        let parser = EventDataParser::new(bytes);
+        assert!(parser.get_array().unwrap().is_empty());
+        assert_eq!(parser.get_u32(), Some(42));
+    }
+
+    #[test]
+    fn byte_arrays_are_padded() {
+        let mut msg = Message::<8>::new(1, 0);
+        msg.write_bytes(&[1, 2, 3]).write_u32(42);
+        msg.build();
+        assert_eq!(msg.data()[8..16], [3, 0, 0, 0, 1, 2, 3, 0]);
+        let parser = EventDataParser::new(&msg.data()[8..]);
+        assert_eq!(parser.get_bytes(), Some(&[1, 2, 3][..]));
+        assert_eq!(parser.remaining(), 4);
+        assert_eq!(parser.get_u32(), Some(42));
+        assert_eq!((parser.get_u32(), parser.remaining()), (None, 0));
+    }
+
+    #[test]
+    fn malformed_arguments_are_rejected() {
+        let parse = |words: &[u32], get: fn(&EventDataParser<'_>) -> bool| {
+            let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_ne_bytes()).collect();
+            let data = aligned(&bytes);
+            let bytes = unsafe { core::slice::from_raw_parts(data.as_ptr().cast(), bytes.len()) };
+            get(&EventDataParser::new(bytes))
+        };
+        let word = |bytes: &[u8; 4]| u32::from_ne_bytes(*bytes);
+        assert!(parse(&[], |p| p.get_u32().is_none()));
+        assert!(parse(&[], |p| p.get_i32().is_none() && p.get_fixed().is_none()));
+        // Lengths past the end
+        assert!(parse(&[9, word(b"abcd")], |p| p.get_string().is_none()));
+        assert!(parse(&[u32::MAX], |p| p.get_string().is_none()));
+        assert!(parse(&[8, 1], |p| p.get_array().is_none()));
+        // Missing padding
+        assert!(parse(&[2, 0], |p| p.get_string().is_some()));
+        assert!(parse(&[5, word(b"abcd")], |p| p.get_string().is_none()));
+        // Not nul terminated, not UTF-8
+        assert!(parse(&[4, word(b"abcd")], |p| p.get_string().is_none()));
+        assert!(parse(&[4, word(b"ab\xff\0")], |p| p.get_string().is_none()));
+        assert!(parse(&[4, word(b"ab\xc3\0")], |p| p.get_string().is_none()));
+        assert!(parse(&[3, word(b"ab\0\0")], |p| p.get_string() == Some("ab")));
+        // Null, only nullable strings can be
+        assert!(parse(&[0], |p| p.get_string().is_none()));
+        assert!(parse(&[0], |p| p.get_nullable_string() == Some(None)));
+        assert!(parse(&[3, word(b"ab\0\0")], |p| p.get_nullable_string()
+            == Some(Some("ab"))));
+    }
+
+    // The first crash `decode_events` found: a `wl_display.error` header without a body
+    #[test]
+    fn truncated_events_dont_parse() {
+        use std::os::unix::net::UnixStream;
+
+        use crate::connection::{Connection, Object};
+        use crate::wayland::wl_display::{Event, WlDisplay};
+
+        let (socket, _compositor) = UnixStream::pair().unwrap();
+        let conn = Connection::from_fd(socket.into());
+        let display = WlDisplay::from_id(1);
+        let words = aligned(&[1u32.to_ne_bytes(), (8u32 << 16).to_ne_bytes()].concat());
+        let bytes = unsafe { core::slice::from_raw_parts(words.as_ptr().cast(), 8) };
+        let event = EventIter::new(bytes).next().unwrap();
+        assert_eq!((event.header.id, event.header.size), (1, 8));
+        assert!(display.parse_event(conn.reader(), event).is_none());
+
+        // wl_display.error(wl_display@1, 3, "oops") cut off in the string
+        let error = |opcode| {
+            let mut msg = Message::<32>::new(1, opcode);
+            msg.write_u32(1).write_u32(3).write_string("oops");
+            msg.build();
+            msg.data().to_vec()
+        };
+        let data = error(0);
+        let event = WlEvent {
+            header: Header::new(1, 0, 20),
+            data:   &data[8..20],
+        };
+        assert!(display.parse_event(conn.reader(), event).is_none());
+        let event = EventIter::new(&data).next().unwrap();
+        assert!(matches!(
+            display.parse_event(conn.reader(), event),
+            Some(Event::Error {
+                code: 3,
+                message: "oops",
+                ..
+            })
+        ));
+        let data = error(7);
+        let event = EventIter::new(&data).next().unwrap();
+        assert!(
+            display.parse_event(conn.reader(), event).is_none(),
+            "unknown opcode"
+        );
+    }
+
+    #[test]
+    fn whole_messages_leave_out_a_partial_one() {
+        let header = |size: u32| [1u32.to_ne_bytes(), (size << 16).to_ne_bytes()].concat();
+        assert_eq!(whole_messages(&[]), 0);
+        assert_eq!(
+            whole_messages(&[header(8), header(12), vec![0; 4]].concat()),
+            20
+        );
+        assert_eq!(whole_messages(&[header(8), header(12)].concat()), 8);
+        assert_eq!(whole_messages(&header(8)[..6]), 0);
+        // Malformed sizes aren't waited for
+        assert_eq!(whole_messages(&[header(8), header(4)].concat()), 16);
+        assert_eq!(whole_messages(&header(8192)), 8);
     }
 }
@@ -0,0 +1,244 @@
+//! Static descriptions of protocol interfaces, the counterpart of libwayland's `wl_interface`.
+//!
+//! The scanner emits a `static INTERFACE_DESC: Interface` in every interface module, also
+//! reachable as [`Object::DESC`](crate::connection::Object::DESC), so messages can be
+//! inspected without knowing the generated types. Every generated protocol also has an
+//! `INTERFACES` list of them and a `PARSERS` table of [`Parser`]s.
+
+use crate::connection::{Reader, WaylandBuffer};
+use crate::events::WlEvent;
+
+/// Parses the incoming messages of one interface, events on the client side and requests on the
+/// server side, for code that picks the interface at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser {
+    pub desc:  &'static Interface,
+    /// Whether the message is one of `desc` with valid arguments.
+    pub parse: fn(&WaylandBuffer<Reader>, WlEvent<'_>) -> bool,
+}
+
+/// An interface and all of its messages.
+#[derive(Debug)]
+pub struct Interface {
+    pub name:     &'static str,
+    pub version:  u32,
+    /// Indexed by opcode.
+    pub requests: &'static [MessageDesc],
+    /// Indexed by opcode.
+    pub events:   &'static [MessageDesc],
+    pub enums:    &'static [EnumDesc],
+}
+
+impl Interface {
+    pub fn request(&self, opcode: u16) -> Option<&'static MessageDesc> {
+        self.requests.get(opcode as usize)
+    }
+
+    pub fn event(&self, opcode: u16) -> Option<&'static MessageDesc> {
+        self.events.get(opcode as usize)
+    }
+
+    /// An enum of this interface by its name without the interface, `transform` for
+    /// `wl_output.transform`.
+    pub fn enumeration(&self, name: &str) -> Option<&'static EnumDesc> {
+        self.enums.iter().find(|en| en.name == name)
+    }
+}
+
+impl PartialEq for Interface {
+    /// Interfaces are identified by their name, there's only one description per name.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Interface {}
+
+/// A request or an event.
+#[derive(Debug)]
+pub struct MessageDesc {
+    pub name:       &'static str,
+    /// The interface version the message was added in.
+    pub since:      u32,
+    pub destructor: bool,
+    pub args:       &'static [ArgDesc],
+}
+
+#[derive(Debug)]
+pub struct ArgDesc {
+    pub name:        &'static str,
+    pub ty:          ArgType,
+    /// Whether a null object or string is allowed.
+    pub nullable:    bool,
+    /// The interface of object and new_id arguments, `None` if any interface is allowed.
+    pub interface:   Option<&'static Interface>,
+    /// The `enum` attribute as written in the protocol, e.g. `wl_output.transform`.
+    pub enumeration: Option<&'static str>,
+}
+
+#[derive(Debug)]
+pub struct EnumDesc {
+    pub name:     &'static str,
+    pub bitfield: bool,
+    pub entries:  &'static [EnumEntry],
+}
+
+#[derive(Debug)]
+pub struct EnumEntry {
+    pub name:  &'static str,
+    pub value: u32,
+}
+
+impl EnumDesc {
+    /// The entry named `value`, or for bitfields the set flags joined by `|`, e.g.
+    /// `top|left`. Values without a name are given as numbers.
+    pub fn format(&self, value: u32) -> String {
+        if !self.bitfield {
+            return match self.entries.iter().find(|entry| entry.value == value) {
+                Some(entry) => entry.name.to_string(),
+                None => value.to_string(),
+            };
+        }
+        let mut names = Vec::new();
+        let mut rest = value;
+        for entry in self.entries {
+            if entry.value != 0 && value & entry.value == entry.value {
+                names.push(entry.name.to_string());
+                rest &= !entry.value;
+            }
+        }
+        if rest != 0 || names.is_empty() {
+            match self.entries.iter().find(|entry| entry.value == 0) {
+                Some(entry) if value == 0 => names.push(entry.name.to_string()),
+                _ => names.push(format!("{rest:#x}")),
+            }
+        }
+        names.join("|")
+    }
+}
+
+/// How an argument is laid out on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgType {
+    Int,
+    Uint,
+    Fixed,
+    String,
+    Object,
+    /// A new_id without an interface is sent as the interface name, the version and then the id.
+    NewId,
+    Array,
+    /// Sent in the ancillary data, takes no space in the message itself.
+    Fd,
+}
+
+impl ArgType {
+    /// The type character libwayland uses in message signatures.
+    pub const fn signature_char(self) -> char {
+        match self {
+            Self::Int => 'i',
+            Self::Uint => 'u',
+            Self::Fixed => 'f',
+            Self::String => 's',
+            Self::Object => 'o',
+            Self::NewId => 'n',
+            Self::Array => 'a',
+            Self::Fd => 'h',
+        }
+    }
+}
+
+impl MessageDesc {
+    /// The message's libwayland signature, e.g. `2?os` for a nullable object and a string
+    /// added in version 2.
+    pub fn signature(&self) -> String {
+        let mut sig = String::new();
+        if self.since > 1 {
+            sig.push_str(&self.since.to_string());
+        }
+        for arg in self.args {
+            if arg.nullable {
+                sig.push('?');
+            }
+            if arg.ty == ArgType::NewId && arg.interface.is_none() {
+                sig.push_str("sun");
+            } else {
+                sig.push(arg.ty.signature_char());
+            }
+        }
+        sig
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixStream;
+
+    use crate::connection::{Connection, Object};
+    use crate::events::{EventIter, Header, Message, WlEvent};
+    use crate::wayland::*;
+
+    #[test]
+    fn signatures_match_libwayland() {
+        let registry = &wl_registry::INTERFACE_DESC;
+        assert_eq!(registry.request(0).unwrap().signature(), "usun");
+        assert_eq!(registry.event(0).unwrap().signature(), "usu");
+
+        let surface = wl_surface::WlSurface::DESC;
+        assert_eq!(surface.name, "wl_surface");
+        assert_eq!(surface.request(1).unwrap().signature(), "?oii");
+        assert!(surface.request(0).unwrap().destructor);
+        assert_eq!(
+            surface.request(1).unwrap().args[0].interface,
+            Some(&wl_buffer::INTERFACE_DESC)
+        );
+        assert_eq!(surface.event(0).unwrap().name, "enter");
+        assert!(surface.event(99).is_none());
+
+        let keymap = wl_keyboard::INTERFACE_DESC.event(0).unwrap();
+        assert_eq!(keymap.signature(), "uhu");
+        assert_eq!(keymap.args[0].enumeration, Some("keymap_format"));
+    }
+
+    #[test]
+    fn enums_are_described() {
+        assert!(INTERFACES.contains(&&wl_seat::INTERFACE_DESC));
+        assert_eq!(INTERFACES.len(), 23);
+
+        let transform = wl_output::INTERFACE_DESC.enumeration("transform").unwrap();
+        assert!(!transform.bitfield);
+        assert_eq!(transform.format(1), "90");
+        assert_eq!(transform.format(42), "42");
+
+        let capability = wl_seat::INTERFACE_DESC.enumeration("capability").unwrap();
+        assert!(capability.bitfield);
+        assert_eq!(capability.format(3), "pointer|keyboard");
+        assert_eq!(capability.format(0x11), "pointer|0x10");
+        assert_eq!(capability.format(0), "0x0");
+    }
+
+    #[test]
+    fn parsers_follow_the_interfaces() {
+        let descs: Vec<_> = PARSERS.iter().map(|parser| parser.desc.name).collect();
+        let names: Vec<_> = INTERFACES.iter().map(|desc| desc.name).collect();
+        assert_eq!(descs, names);
+
+        let (socket, _peer) = UnixStream::pair().unwrap();
+        let conn = Connection::from_fd(socket.into());
+        let callback = PARSERS
+            .iter()
+            .find(|parser| parser.desc.name == "wl_callback")
+            .unwrap();
+        // wl_callback.done(7) and the same without its argument
+        let mut msg = Message::<16>::new(3, 0);
+        msg.write_u32(7);
+        msg.build();
+        let event = EventIter::new(msg.data()).next().unwrap();
+        assert!((callback.parse)(conn.reader(), event));
+        let event = WlEvent {
+            header: Header::new(3, 0, 8),
+            data:   &[],
+        };
+        assert!(!(callback.parse)(conn.reader(), event));
+    }
+}
@@ -0,0 +1,163 @@
+//! Objects a connection created and never destroyed.
+//!
+//! [`Connection::leaks`](crate::connection::Connection::leaks) lists the live objects of
+//! interfaces that can be destroyed, by a destructor request like `wl_buffer.destroy` or a
+//! destructor event like `wl_callback.done`. Objects that live as long as the connection,
+//! e.g. `wl_registry` or `wl_compositor`, are left out. In debug builds every leak comes with
+//! the call that created it:
+//!
+//! ```text
+//! 3 objects were never destroyed:
+//!   wl_buffer: 2
+//!     wl_buffer@9, created at examples/simplewindow.rs:470:34
+//!     wl_buffer@14, created at examples/simplewindow.rs:470:34
+//!   wl_callback: 1
+//!     wl_callback@15, created at examples/simplewindow.rs:528:40
+//! ```
+//!
+//! With `SCR_LEAK_REPORT=1`, or after
+//! [`Connection::set_leak_report`](crate::connection::Connection::set_leak_report), the report
+//! is logged as a warning when the connection is dropped.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::Location;
+
+use crate::connection::ObjectInfo;
+use crate::interface::Interface;
+
+#[derive(Debug, Clone, Default)]
+pub struct LeakReport {
+    leaks: Vec<Leak>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Leak {
+    pub object:  ObjectInfo,
+    /// The call that created the object, only known in debug builds and for objects created
+    /// by requests.
+    pub created: Option<&'static Location<'static>>,
+}
+
+impl LeakReport {
+    pub(crate) fn new(
+        live: Vec<(
+            ObjectInfo,
+            &'static Interface,
+            Option<&'static Location<'static>>,
+        )>,
+    ) -> Self {
+        let leaks = live
+            .into_iter()
+            .filter(|(_, desc, _)| destructible(desc))
+            .map(|(object, _, created)| Leak {
+                object,
+                created,
+            })
+            .collect();
+        Self {
+            leaks,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaks.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaks.len()
+    }
+
+    /// Ordered by id.
+    pub fn leaks(&self) -> &[Leak] {
+        &self.leaks
+    }
+
+    /// The leaks of every interface, ordered by interface name.
+    pub fn by_interface(&self) -> BTreeMap<&'static str, Vec<&Leak>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for leak in &self.leaks {
+            groups.entry(leak.object.interface).or_default().push(leak);
+        }
+        groups
+    }
+}
+
+fn destructible(desc: &Interface) -> bool {
+    desc.requests
+        .iter()
+        .chain(desc.events)
+        .any(|msg| msg.destructor)
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.len() {
+            0 => return f.write_str("no objects were leaked"),
+            1 => f.write_str("1 object was never destroyed:")?,
+            n => write!(f, "{n} objects were never destroyed:")?,
+        }
+        for (interface, leaks) in self.by_interface() {
+            write!(f, "\n  {interface}: {}", leaks.len())?;
+            for leak in leaks {
+                write!(f, "\n    {interface}@{}", leak.object.id)?;
+                if let Some(created) = leak.created {
+                    write!(f, ", created at {created}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixStream;
+
+    use crate::connection::{Connection, Object};
+    use crate::wayland::*;
+
+    #[test]
+    #[cfg_attr(
+        not(debug_assertions),
+        ignore = "call sites are only kept in debug builds"
+    )]
+    fn leaks_are_grouped_with_their_call_site() {
+        let (socket, _compositor) = UnixStream::pair().unwrap();
+        let conn = Connection::from_fd(socket.into());
+        let registry = conn.display().get_registry(conn.writer());
+        let compositor: wl_compositor::WlCompositor = registry.bind(conn.writer(), 1, 4);
+        let kept = compositor.create_surface(conn.writer());
+        let line = line!() - 1;
+        compositor
+            .create_surface(conn.writer())
+            .destroy(conn.writer());
+        let frame = kept.frame(conn.writer());
+
+        let live: Vec<_> = conn.live_objects().map(|info| info.id).collect();
+        assert_eq!(
+            live,
+            [1, registry.id(), compositor.id(), kept.id(), frame.id()]
+        );
+
+        let leaks = conn.leaks();
+        assert_eq!(leaks.len(), 2);
+        let groups = leaks.by_interface();
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            ["wl_callback", "wl_surface"]
+        );
+        let created = groups["wl_surface"][0].created.unwrap();
+        assert_eq!((created.file(), created.line()), (file!(), line));
+        assert_eq!(
+            leaks.to_string(),
+            format!(
+                "2 objects were never destroyed:\n  wl_callback: 1\n    wl_callback@{}, created at {}\n  \
+                 wl_surface: 1\n    wl_surface@{}, created at {created}",
+                frame.id(),
+                groups["wl_callback"][0].created.unwrap(),
+                kept.id()
+            )
+        );
+    }
+}
@@ -1,11 +1,21 @@
 #![allow(internal_features)]
 
 pub mod connection;
+mod debug;
 pub mod events;
+pub mod interface;
+pub mod leaks;
+pub mod logger;
+pub mod server;
+pub mod stats;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod trace;
+mod validate;
 pub mod wayland;
 
 pub mod prelude {
-    pub use crate::connection::{Connection, Object, State};
+    pub use crate::connection::{Connection, Dispatch, Object, ObjectInfo, State};
     pub use crate::events::WlEvent;
 }
 
@@ -0,0 +1,340 @@
+//! Where the library's log output goes.
+//!
+//! Everything is handed to a [`Logger`] as a [`Record`]: the lines of the [`log!`](crate::log)
+//! macro, and every message sent and received as a [`Level::Wayland`] record with its parts
+//! broken out in a [`Message`]. Until [`set_logger`] installs another one records go to
+//! [`StderrLogger`], printing what `SCR_TRACE` and `WAYLAND_DEBUG` ask for.
+//! [`Connection::set_logger`](crate::connection::Connection::set_logger) picks the logger of
+//! one connection's messages.
+//!
+//! ```ignore
+//! struct Journal;
+//!
+//! impl Logger for Journal {
+//!     fn enabled(&self, level: Level) -> bool {
+//!         level != Level::Trace
+//!     }
+//!
+//!     fn log(&self, record: &Record<'_>) {
+//!         match record.message {
+//!             Some(msg) => println!("<7>{:?} {}#{} {}", msg.direction, msg.interface, msg.object_id, msg.name),
+//!             None => println!("<{}>{}", record.level.priority(), record.text),
+//!         }
+//!     }
+//! }
+//!
+//! scratchway::logger::set_logger(Journal);
+//! ```
+//!
+//! With the `log` or `tracing` feature [`LogBridge`] and [`TracingBridge`] forward records to
+//! those crates.
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::debug::Side;
+use crate::trace::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+    /// Messages sent and received, what libwayland prints with `WAYLAND_DEBUG`.
+    Wayland,
+}
+
+impl Level {
+    /// The syslog priority of the level, as journald expects it in a `<N>` prefix.
+    pub const fn priority(self) -> u8 {
+        match self {
+            Self::Error => 3,
+            Self::Warning => 4,
+            Self::Info => 6,
+            Self::Debug | Self::Trace | Self::Wayland => 7,
+        }
+    }
+}
+
+/// One thing to log.
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    pub level:   Level,
+    /// The whole line, for messages `interface@id.name(args)` like `WAYLAND_DEBUG` prints it.
+    pub text:    fmt::Arguments<'a>,
+    /// Set for [`Level::Wayland`] records.
+    pub message: Option<&'a Message<'a>>,
+}
+
+/// A message that was sent or received.
+#[derive(Debug, Clone, Copy)]
+pub struct Message<'a> {
+    pub direction: Direction,
+    /// Events for objects that were already destroyed, they're dropped without being handled.
+    pub discarded: bool,
+    pub object_id: u32,
+    pub interface: &'static str,
+    pub opcode:    u16,
+    pub name:      &'static str,
+    /// The arguments as libwayland prints them, e.g. `1, "wl_seat", 7`.
+    pub args:      &'a str,
+}
+
+pub trait Logger: Send + Sync {
+    /// Whether records of `level` are wanted, checked before anything is formatted.
+    fn enabled(&self, level: Level) -> bool;
+
+    fn log(&self, record: &Record<'_>);
+}
+
+static LOGGER: RwLock<Option<Arc<dyn Logger>>> = RwLock::new(None);
+
+/// Replaces the logger of the whole process, connections with their own keep it.
+///
+/// Unlike the default [`StderrLogger`] an installed logger gets the messages of every
+/// connection it enables [`Level::Wayland`] for, whatever `WAYLAND_DEBUG` says.
+pub fn set_logger(logger: impl Logger + 'static) {
+    *LOGGER.write().unwrap() = Some(Arc::new(logger));
+}
+
+/// The logger [`set_logger`] installed.
+fn installed() -> Option<Arc<dyn Logger>> {
+    LOGGER.read().unwrap().clone()
+}
+
+/// The logger of a single connection, set with
+/// [`Connection::set_logger`](crate::connection::Connection::set_logger).
+#[derive(Default)]
+pub(crate) struct LoggerSlot(RwLock<Option<Arc<dyn Logger>>>);
+
+impl LoggerSlot {
+    pub(crate) fn set(&self, logger: Arc<dyn Logger>) {
+        *self.0.write().unwrap() = Some(logger);
+    }
+
+    /// The connection's logger, or the one installed for the process.
+    pub(crate) fn get(&self) -> Option<Arc<dyn Logger>> {
+        self.0.read().unwrap().clone().or_else(installed)
+    }
+}
+
+impl fmt::Debug for LoggerSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let set = self.0.read().unwrap().is_some();
+        f.debug_tuple("LoggerSlot").field(&set).finish()
+    }
+}
+
+/// Hands a line to the installed logger, what [`log!`](crate::log) expands to.
+pub fn log(level: Level, text: fmt::Arguments<'_>) {
+    let record = Record {
+        level,
+        text,
+        message: None,
+    };
+    match &*LOGGER.read().unwrap() {
+        Some(logger) if logger.enabled(level) => logger.log(&record),
+        Some(_) => {}
+        None if StderrLogger.enabled(level) => StderrLogger.log(&record),
+        None => {}
+    }
+}
+
+/// Coloured lines on stderr, [`Level::Trace`] only with `SCR_TRACE=1` and [`Level::Wayland`]
+/// only when `WAYLAND_DEBUG` is set. Messages are printed exactly like libwayland does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrLogger;
+
+impl Logger for StderrLogger {
+    fn enabled(&self, level: Level) -> bool {
+        match level {
+            Level::Trace => *crate::connection::TRACE,
+            Level::Wayland => Side::Client.enabled() || Side::Server.enabled(),
+            _ => true,
+        }
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let Some(message) = record.message else {
+            let (color, name) = match record.level {
+                Level::Error => (31, "ERROR"),
+                Level::Warning => (33, "WARNING"),
+                Level::Info => (32, "INFO"),
+                Level::Debug => (34, "DEBUG"),
+                Level::Trace => (36, "TRACE"),
+                Level::Wayland => (35, "WAYLAND-DEBUG"),
+            };
+            eprintln!("[\x1b[{color}m{name}\x1b[0m]: {}", record.text);
+            return;
+        };
+        // libwayland keeps the microseconds in an unsigned int, wrapping every ~71 minutes
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros() as u32;
+        eprintln!(
+            "[{:7}.{:03}] {}{}{}",
+            time / 1000,
+            time % 1000,
+            if message.discarded { "discarded " } else { "" },
+            if message.direction == Direction::Sent {
+                " -> "
+            } else {
+                ""
+            },
+            record.text
+        );
+    }
+}
+
+/// Drops everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopLogger;
+
+impl Logger for NoopLogger {
+    fn enabled(&self, _: Level) -> bool {
+        false
+    }
+
+    fn log(&self, _: &Record<'_>) {}
+}
+
+/// Forwards records to the `log` crate, messages with the `scratchway::wayland` target at
+/// trace level.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogBridge;
+
+#[cfg(feature = "log")]
+impl LogBridge {
+    fn target(level: Level) -> (&'static str, ::log::Level) {
+        match level {
+            Level::Error => ("scratchway", ::log::Level::Error),
+            Level::Warning => ("scratchway", ::log::Level::Warn),
+            Level::Info => ("scratchway", ::log::Level::Info),
+            Level::Debug => ("scratchway", ::log::Level::Debug),
+            Level::Trace => ("scratchway", ::log::Level::Trace),
+            Level::Wayland => ("scratchway::wayland", ::log::Level::Trace),
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl Logger for LogBridge {
+    fn enabled(&self, level: Level) -> bool {
+        let (target, level) = Self::target(level);
+        ::log::log_enabled!(target: target, level)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let (target, level) = Self::target(record.level);
+        ::log::log!(target: target, level, "{}", record.text);
+    }
+}
+
+/// Forwards records to `tracing` as events, messages at trace level with the target
+/// `scratchway::wayland` and their parts as fields.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingBridge;
+
+#[cfg(feature = "tracing")]
+impl Logger for TracingBridge {
+    fn enabled(&self, level: Level) -> bool {
+        match level {
+            Level::Error => tracing::enabled!(target: "scratchway", tracing::Level::ERROR),
+            Level::Warning => tracing::enabled!(target: "scratchway", tracing::Level::WARN),
+            Level::Info => tracing::enabled!(target: "scratchway", tracing::Level::INFO),
+            Level::Debug => tracing::enabled!(target: "scratchway", tracing::Level::DEBUG),
+            Level::Trace => tracing::enabled!(target: "scratchway", tracing::Level::TRACE),
+            Level::Wayland => {
+                tracing::enabled!(target: "scratchway::wayland", tracing::Level::TRACE)
+            }
+        }
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let text = record.text;
+        match (record.level, record.message) {
+            (Level::Error, _) => tracing::error!(target: "scratchway", "{text}"),
+            (Level::Warning, _) => tracing::warn!(target: "scratchway", "{text}"),
+            (Level::Info, _) => tracing::info!(target: "scratchway", "{text}"),
+            (Level::Debug, _) => tracing::debug!(target: "scratchway", "{text}"),
+            (Level::Trace, _) => tracing::trace!(target: "scratchway", "{text}"),
+            (Level::Wayland, None) => tracing::trace!(target: "scratchway::wayland", "{text}"),
+            (Level::Wayland, Some(msg)) => tracing::trace!(
+                target: "scratchway::wayland",
+                direction = ?msg.direction,
+                discarded = msg.discarded,
+                object_id = msg.object_id,
+                interface = msg.interface,
+                opcode = msg.opcode,
+                name = msg.name,
+                args = msg.args,
+                "{text}"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Keeps what it's given as `level: text` lines.
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<String>>);
+
+    impl Logger for Collect {
+        fn enabled(&self, level: Level) -> bool {
+            level != Level::Trace
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            let line = match record.message {
+                Some(msg) => format!(
+                    "{:?} {:?} {}#{} {}",
+                    record.level, msg.direction, msg.interface, msg.object_id, msg.name
+                ),
+                None => format!("{:?} {}", record.level, record.text),
+            };
+            self.0.lock().unwrap().push(line);
+        }
+    }
+
+    #[test]
+    fn records_reach_the_logger() {
+        let logger = Arc::new(Collect::default());
+        let objects = crate::connection::ObjectMap::new();
+        objects.insert(
+            crate::connection::ObjectInfo {
+                id:        1,
+                interface: "wl_display",
+                version:   1,
+            },
+            <crate::wayland::wl_display::WlDisplay as crate::connection::Object>::DESC,
+        );
+        let header = crate::events::Header::new(1, 0, 12);
+        crate::debug::log(
+            &*logger,
+            &objects,
+            Side::Client,
+            Direction::Sent,
+            false,
+            header,
+            &3u32.to_ne_bytes(),
+            &[],
+        );
+        assert_eq!(
+            *logger.0.lock().unwrap(),
+            ["Wayland Sent wl_display#1 sync"]
+        );
+        assert!(!logger.enabled(Level::Trace));
+        assert!(!NoopLogger.enabled(Level::Error));
+    }
+}
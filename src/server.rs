@@ -0,0 +1,292 @@
+//! The compositor side of the protocol.
+//!
+//! Resources are the server's view of the objects a client created, they're generated with
+//! `scr_scanner_macros::generate_server!` and receive requests and send events, the mirror
+//! image of the client's proxies.
+//!
+//! A [`Display`] listens on a [`ListeningSocket`], accepts [`Client`]s and hands their
+//! requests to a [`ServerState`]:
+//!
+//! ```no_run
+//! use scratchway::events::WlEvent;
+//! use scratchway::server::{Client, Display, ServerState};
+//!
+//! struct Compositor;
+//!
+//! impl ServerState for Compositor {
+//!     fn handle_request(&mut self, client: &Client, request: WlEvent<'_>) {
+//!         println!("{:?}", request.header);
+//!     }
+//! }
+//!
+//! let mut display = Display::listen().unwrap();
+//! loop {
+//!     display.dispatch(&mut Compositor, None).unwrap();
+//! }
+//! ```
+
+use crate::connection::{ObjectInfo, Reader, WaylandBuffer, Writer};
+use crate::events::WlEvent;
+
+mod client;
+mod display;
+mod socket;
+pub mod wayland;
+
+pub use client::{Client, ClientId};
+pub use display::{Display, ServerState};
+pub use socket::ListeningSocket;
+
+/// Objects created by the server, e.g. `wl_data_offer`, get ids starting here.
+pub const SERVER_ID_START: u32 = 0xff00_0000;
+
+pub trait Resource {
+    const INTERFACE: &'static str;
+    /// The number of fds carried by each request, indexed by opcode.
+    const REQUEST_FDS: &'static [u8];
+    /// Names and signatures of the interface's messages.
+    const DESC: &'static crate::interface::Interface;
+    type Request<'a>;
+    fn from_id(id: u32) -> Self;
+
+    fn id(&self) -> u32;
+
+    fn interface(&self) -> &'static str;
+
+    /// `None` for unknown opcodes and requests that don't match their description.
+    fn parse_request<'a>(
+        &self, reader: &WaylandBuffer<Reader>, request: WlEvent<'a>,
+    ) -> Option<Self::Request<'a>>;
+}
+
+/// Routes the requests of a resource to the methods of its generated `{Interface}Handler`
+/// trait, the server side counterpart of [`connection::Dispatch`](crate::connection::Dispatch).
+pub trait Dispatch<H: ?Sized>: Resource {
+    fn dispatch(&self, handler: &mut H, client: &Client, request: WlEvent<'_>);
+}
+
+impl<T> WaylandBuffer<T> {
+    /// Creates a resource for `id` and tracks it in the client's object map.
+    pub fn new_resource<R: Resource>(&self, id: u32, version: u32) -> R {
+        self.objects.insert(
+            ObjectInfo {
+                id,
+                interface: R::INTERFACE,
+                version,
+            },
+            R::DESC,
+        );
+        R::from_id(id)
+    }
+}
+
+impl WaylandBuffer<Writer> {
+    /// An id for an object the server creates.
+    pub fn new_server_id(&self) -> u32 {
+        self.objects.next_server_id()
+    }
+
+    /// Forgets `resource` after a destructor event was sent for it.
+    pub fn destroy_resource<R: Resource>(&self, resource: &R) {
+        self.destroy_id(resource.id());
+    }
+
+    /// Forgets `id` and, if the client allocated it, tells the client it can be reused.
+    pub(crate) fn destroy_id(&self, id: u32) {
+        if self.objects.remove(id).is_some() && id < SERVER_ID_START {
+            wayland::wl_display::Event::DeleteId {
+                id,
+            }
+            .encode(1, self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::sync::Arc;
+
+    use super::wayland::*;
+    use super::*;
+    use crate::connection::ObjectMap;
+    use crate::events::{EventIter, Message};
+
+    #[test]
+    fn event_round_trip() {
+        let objects = Arc::new(ObjectMap::new());
+        let writer = WaylandBuffer::<Writer>::new(-1, objects.clone());
+        let reader = WaylandBuffer::<Reader>::new(-1, objects);
+        wl_keyboard::Event::Enter {
+            serial:  11,
+            surface: Resource::from_id(4),
+            keys:    &[30, 31, 32],
+        }
+        .encode(6, &writer);
+        let data = writer.data.read().unwrap().as_slice().to_vec();
+        let msg = EventIter::new(&data).next().unwrap();
+        assert_eq!(
+            (msg.header.id, msg.header.opcode, msg.header.size),
+            (6, 1, 32)
+        );
+        match wl_keyboard::Event::decode(&reader, msg) {
+            Some(wl_keyboard::Event::Enter {
+                serial,
+                surface,
+                keys,
+            }) => {
+                assert_eq!((serial, surface.id(), keys), (11, 4, &[30, 31, 32][..]));
+            }
+            other => panic!("decoded {other:?}"),
+        }
+    }
+
+    #[test]
+    fn server_ids() {
+        let writer = WaylandBuffer::<Writer>::new(-1, Arc::new(ObjectMap::new()));
+        assert_eq!(writer.new_server_id(), SERVER_ID_START);
+        assert_eq!(writer.new_server_id(), SERVER_ID_START + 1);
+    }
+
+    struct Server {
+        synced: u32,
+    }
+
+    impl ServerState for Server {
+        fn handle_request(&mut self, client: &Client, request: WlEvent<'_>) {
+            client.dispatch::<wl_display::WlDisplay, _>(self, request);
+        }
+    }
+
+    impl wl_display::WlDisplayHandler for Server {
+        fn sync(
+            &mut self, client: &Client, _: &wl_display::WlDisplay,
+            callback: wl_callback::WlCallback,
+        ) {
+            self.synced += 1;
+            callback.done(client.writer(), 42);
+        }
+    }
+
+    /// A display with one client, and the client's end of the socket.
+    fn connected() -> (Display, UnixStream) {
+        let (server, client) = UnixStream::pair().unwrap();
+        let mut display = Display::new();
+        display.insert_client(server);
+        (display, client)
+    }
+
+    fn received(socket: &mut UnixStream) -> Vec<(u32, u16, Vec<u8>)> {
+        let mut buf = [0; 512];
+        let len = socket.read(&mut buf).unwrap();
+        EventIter::new(&buf[..len])
+            .map(|msg| (msg.header.id, msg.header.opcode, msg.data.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn sync_is_answered_and_callback_released() {
+        let (mut display, mut socket) = connected();
+        let mut msg = Message::<12>::new(1, 0);
+        msg.write_u32(3).build();
+        socket.write_all(msg.data()).unwrap();
+
+        let mut server = Server {
+            synced: 0
+        };
+        display.dispatch(&mut server, None).unwrap();
+        assert_eq!(server.synced, 1);
+        let messages = received(&mut socket);
+        // wl_callback.done(42) and wl_display.delete_id(3)
+        assert_eq!(
+            messages,
+            [
+                (3, 0, 42u32.to_ne_bytes().to_vec()),
+                (1, 1, 3u32.to_ne_bytes().to_vec())
+            ]
+        );
+        let client = display.clients().next().unwrap();
+        assert_eq!(client.object_info(3), None);
+    }
+
+    #[test]
+    fn unknown_object_is_a_protocol_error() {
+        let (mut display, mut socket) = connected();
+        let mut msg = Message::<8>::new(9, 0);
+        msg.build();
+        socket.write_all(msg.data()).unwrap();
+
+        display
+            .dispatch(
+                &mut Server {
+                    synced: 0
+                },
+                None,
+            )
+            .unwrap();
+        let messages = received(&mut socket);
+        assert_eq!((messages[0].0, messages[0].1), (1, 0));
+        assert_eq!(
+            messages[0].2[..8],
+            [1u32.to_ne_bytes(), 0u32.to_ne_bytes()].concat()
+        );
+        assert_eq!(display.clients().count(), 0);
+    }
+
+    #[test]
+    fn truncated_request_is_a_protocol_error() {
+        let (mut display, mut socket) = connected();
+        // wl_display.sync without its callback
+        let mut msg = Message::<8>::new(1, 0);
+        msg.build();
+        socket.write_all(msg.data()).unwrap();
+
+        display
+            .dispatch(
+                &mut Server {
+                    synced: 0
+                },
+                None,
+            )
+            .unwrap();
+        let messages = received(&mut socket);
+        assert_eq!((messages[0].0, messages[0].1), (1, 0));
+        assert_eq!(
+            messages[0].2[..8],
+            [1u32.to_ne_bytes(), 1u32.to_ne_bytes()].concat()
+        );
+        assert_eq!(display.clients().count(), 0);
+    }
+
+    #[test]
+    fn hung_up_clients_are_dropped() {
+        let (mut display, socket) = connected();
+        drop(socket);
+        display
+            .dispatch(
+                &mut Server {
+                    synced: 0
+                },
+                None,
+            )
+            .unwrap();
+        assert_eq!(display.clients().count(), 0);
+    }
+
+    #[test]
+    fn socket_is_locked_and_cleaned_up() {
+        let name = format!("scratchway-test-{}", std::process::id());
+        let socket = ListeningSocket::bind(&name).unwrap();
+        let err = ListeningSocket::bind(&name).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
+
+        let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").unwrap_or("/tmp/".into());
+        let path = std::path::Path::new(&runtime_dir).join(&name);
+        let _client = UnixStream::connect(&path).unwrap();
+        assert!(socket.accept().unwrap().is_some());
+        assert!(socket.accept().unwrap().is_none());
+        drop(socket);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::io;
+use std::os::fd::{AsRawFd, RawFd};
+use std::os::unix::net::UnixStream;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use super::wayland::wl_display;
+use super::{Dispatch, Resource, ServerState};
+use crate::connection::{ObjectInfo, ObjectMap, Reader, WaylandBuffer, Writer};
+use crate::debug::Side;
+use crate::events::{EventIter, WlEvent};
+use crate::{log, validate};
+
+static CLIENT_IDS: AtomicU32 = AtomicU32::new(1);
+
+/// Identifies a client of a [`Display`](super::Display) for as long as it's connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClientId(u32);
+
+impl fmt::Display for ClientId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "client#{}", self.0)
+    }
+}
+
+/// A connected client and the resources it created.
+#[derive(Debug)]
+pub struct Client {
+    id:      ClientId,
+    socket:  UnixStream,
+    reader:  WaylandBuffer<Reader>,
+    writer:  WaylandBuffer<Writer>,
+    objects: Arc<ObjectMap>,
+    // Set once a protocol error was posted, the client gets disconnected
+    error:   AtomicBool,
+}
+
+impl Client {
+    pub fn new(socket: UnixStream) -> Self {
+        let objects = Arc::new(ObjectMap::new());
+        objects.insert(
+            ObjectInfo {
+                id:        1,
+                interface: wl_display::WlDisplay::INTERFACE,
+                version:   1,
+            },
+            wl_display::WlDisplay::DESC,
+        );
+        Self {
+            id: ClientId(CLIENT_IDS.fetch_add(1, Ordering::Relaxed)),
+            reader: WaylandBuffer::<Reader>::new(socket.as_raw_fd(), objects.clone())
+                .side(Side::Server),
+            writer: WaylandBuffer::<Writer>::new(socket.as_raw_fd(), objects.clone())
+                .side(Side::Server),
+            objects,
+            socket,
+            error: AtomicBool::new(false),
+        }
+    }
+
+    pub fn id(&self) -> ClientId {
+        self.id
+    }
+
+    pub fn display(&self) -> wl_display::WlDisplay {
+        Resource::from_id(1)
+    }
+
+    #[inline(always)]
+    pub fn writer(&self) -> &WaylandBuffer<Writer> {
+        &self.writer
+    }
+
+    #[inline(always)]
+    pub fn reader(&self) -> &WaylandBuffer<Reader> {
+        &self.reader
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        self.writer.send()
+    }
+
+    pub fn object_info(&self, id: u32) -> Option<ObjectInfo> {
+        self.objects.get(id)
+    }
+
+    /// Sends `wl_display.error` for `object_id`, the client is disconnected once it's flushed.
+    pub fn post_error(&self, object_id: u32, code: u32, message: &str) {
+        log!(
+            WARNING,
+            "{}: error {} on object {}: {}",
+            self.id,
+            code,
+            object_id,
+            message
+        );
+        wl_display::Event::Error {
+            object_id,
+            code,
+            message,
+        }
+        .encode(1, &self.writer);
+        self.error.store(true, Ordering::Relaxed);
+    }
+
+    /// Answers a request that doesn't match the description of its resource with an
+    /// `invalid_method` error, what the generated [`Dispatch`] implementations do with
+    /// requests they can't parse.
+    pub fn malformed(&self, request: WlEvent<'_>) {
+        let id = request.header.id;
+        let interface = self
+            .objects
+            .get(id)
+            .map_or("unknown", |info| info.interface);
+        self.post_error(
+            id,
+            wl_display::Error::InvalidMethod.into(),
+            &format!(
+                "malformed request {}, object {interface}#{id}",
+                request.header.opcode
+            ),
+        );
+    }
+
+    /// Whether a protocol error was posted.
+    pub fn has_error(&self) -> bool {
+        self.error.load(Ordering::Relaxed)
+    }
+
+    /// Reads what the client sent and hands each request to `state`.
+    ///
+    /// Requests for unknown objects or with unknown opcodes are answered with a protocol
+    /// error instead. Resources destroyed by a destructor request are forgotten, and their
+    /// ids released with `wl_display.delete_id`, after `state` handled the request.
+    ///
+    /// Fails with [`io::ErrorKind::UnexpectedEof`] once the client hung up.
+    pub fn dispatch_requests<S: ServerState + ?Sized>(&self, state: &mut S) -> io::Result<()> {
+        let read = self.reader.recv()?;
+        if read == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "client disconnected",
+            ));
+        }
+        let data = self.reader.data.read().unwrap();
+        for request in EventIter::new(&data[..self.reader.messages_end()]) {
+            if self.has_error() {
+                break;
+            }
+            let id = request.header.id;
+            let opcode = request.header.opcode;
+            let Some(desc) = self.objects.desc(id) else {
+                self.post_error(
+                    1,
+                    wl_display::Error::InvalidObject.into(),
+                    &format!("invalid object {id}"),
+                );
+                break;
+            };
+            let Some(message) = desc.request(opcode) else {
+                self.post_error(
+                    id,
+                    wl_display::Error::InvalidMethod.into(),
+                    &format!("invalid method {opcode}, object {}#{id}", desc.name),
+                );
+                break;
+            };
+            let fds = self.reader.fds.read().unwrap().len();
+            if let Some(problem) = validate::check_received(message, request.data, fds) {
+                self.post_error(
+                    id,
+                    wl_display::Error::InvalidMethod.into(),
+                    &format!(
+                        "invalid arguments for {}#{id}.{}: {problem}",
+                        desc.name, message.name
+                    ),
+                );
+                break;
+            }
+            self.reader.received(&request, false);
+            state.handle_request(self, request);
+            if message.destructor {
+                self.writer.destroy_id(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Hands `request` to the generated handler trait of `R` if it was sent to an `R`,
+    /// otherwise gives it back so it can be tried against another interface, like
+    /// [`Connection::dispatch`](crate::connection::Connection::dispatch).
+    pub fn dispatch<'a, R: Dispatch<H>, H: ?Sized>(
+        &self, handler: &mut H, request: WlEvent<'a>,
+    ) -> Option<WlEvent<'a>> {
+        match self.objects.get(request.header.id) {
+            Some(info) if info.interface == R::INTERFACE => {
+                R::from_id(request.header.id).dispatch(handler, self, request);
+                None
+            }
+            _ => Some(request),
+        }
+    }
+}
+
+impl AsRawFd for Client {
+    fn as_raw_fd(&self) -> RawFd {
+        self.socket.as_raw_fd()
+    }
+}
@@ -0,0 +1,141 @@
+use std::ffi::OsStr;
+use std::io;
+use std::os::fd::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+use super::{Client, ClientId, ListeningSocket};
+use crate::events::WlEvent;
+use crate::log;
+use crate::utils::syscall;
+
+/// What a compositor implements to get the requests of its clients.
+pub trait ServerState {
+    fn handle_request(&mut self, client: &Client, request: WlEvent<'_>);
+
+    fn client_connected(&mut self, _client: &Client) {}
+
+    /// Called before `client` is dropped, its resources are gone with it.
+    fn client_disconnected(&mut self, _client: &Client) {}
+}
+
+/// The clients of a compositor and, optionally, the socket new ones connect through.
+#[derive(Debug, Default)]
+pub struct Display {
+    socket:  Option<ListeningSocket>,
+    clients: Vec<Client>,
+}
+
+impl Display {
+    /// A display without a socket, clients can only be added with [`Display::insert_client`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A display listening on the first free `wayland-N`.
+    pub fn listen() -> io::Result<Self> {
+        Ok(Self::with_socket(ListeningSocket::bind_auto()?))
+    }
+
+    pub fn with_socket(socket: ListeningSocket) -> Self {
+        Self {
+            socket:  Some(socket),
+            clients: Vec::new(),
+        }
+    }
+
+    /// What clients set `WAYLAND_DISPLAY` to.
+    pub fn socket_name(&self) -> Option<&OsStr> {
+        self.socket.as_ref().map(ListeningSocket::name)
+    }
+
+    /// Adds an already connected client, e.g. one end of a `socketpair`.
+    pub fn insert_client(&mut self, socket: UnixStream) -> ClientId {
+        let client = Client::new(socket);
+        let id = client.id();
+        self.clients.push(client);
+        id
+    }
+
+    pub fn client(&self, id: ClientId) -> Option<&Client> {
+        self.clients.iter().find(|client| client.id() == id)
+    }
+
+    pub fn clients(&self) -> impl Iterator<Item = &Client> {
+        self.clients.iter()
+    }
+
+    /// Waits up to `timeout`, forever if `None`, for new clients or requests and handles
+    /// whatever arrived, then flushes every client.
+    ///
+    /// Clients that hung up, failed or got a protocol error are disconnected.
+    pub fn dispatch<S: ServerState>(
+        &mut self, state: &mut S, timeout: Option<Duration>,
+    ) -> io::Result<()> {
+        let mut fds: Vec<libc::pollfd> = self
+            .socket
+            .iter()
+            .map(|socket| socket.as_raw_fd())
+            .chain(self.clients.iter().map(|client| client.as_raw_fd()))
+            .map(|fd| libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            })
+            .collect();
+        let timeout = timeout.map_or(-1, |t| t.as_millis().min(i32::MAX as u128) as i32);
+        unsafe {
+            syscall!(libc::poll(
+                fds.as_mut_ptr(),
+                fds.len() as libc::nfds_t,
+                timeout
+            ))?;
+        }
+
+        let (socket_fd, client_fds) = match self.socket {
+            Some(_) => (fds.first(), &fds[1..]),
+            None => (None, &fds[..]),
+        };
+        let mut gone = Vec::new();
+        for (client, pollfd) in self.clients.iter().zip(client_fds) {
+            if pollfd.revents == 0 {
+                continue;
+            }
+            if let Err(err) = client.dispatch_requests(state) {
+                if err.kind() != io::ErrorKind::UnexpectedEof {
+                    log!(WARNING, "{}: {}", client.id(), err);
+                }
+                gone.push(client.id());
+            }
+        }
+
+        if let (Some(socket), Some(pollfd)) = (&self.socket, socket_fd)
+            && pollfd.revents != 0
+        {
+            while let Some(stream) = socket.accept()? {
+                let client = Client::new(stream);
+                log!(TRACE, "{} connected", client.id());
+                state.client_connected(&client);
+                self.clients.push(client);
+            }
+        }
+
+        for client in &self.clients {
+            if let Err(err) = client.flush() {
+                log!(WARNING, "{}: {}", client.id(), err);
+                gone.push(client.id());
+            } else if client.has_error() {
+                gone.push(client.id());
+            }
+        }
+        self.clients.retain(|client| {
+            if !gone.contains(&client.id()) {
+                return true;
+            }
+            log!(TRACE, "{} disconnected", client.id());
+            state.client_disconnected(client);
+            false
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::ffi::{OsStr, OsString};
+use std::fs::File;
+use std::io;
+use std::os::fd::{AsRawFd, RawFd};
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+
+use crate::log;
+use crate::utils::syscall;
+
+/// The highest `wayland-N` [`ListeningSocket::bind_auto`] tries, same as libwayland.
+const MAX_DISPLAYS: u32 = 32;
+
+/// A `$XDG_RUNTIME_DIR/wayland-N` socket clients connect to.
+///
+/// The socket is guarded by `wayland-N.lock`, held for as long as the socket lives, so a
+/// leftover socket of a compositor that crashed can be told apart from one that's in use.
+/// Both files are removed on drop.
+#[derive(Debug)]
+pub struct ListeningSocket {
+    listener:  UnixListener,
+    name:      OsString,
+    path:      PathBuf,
+    lock_path: PathBuf,
+    _lock:     File,
+}
+
+impl ListeningSocket {
+    /// Listens on `$XDG_RUNTIME_DIR/{name}`, fails with [`io::ErrorKind::AddrInUse`] if
+    /// another compositor holds it.
+    pub fn bind(name: impl AsRef<OsStr>) -> io::Result<Self> {
+        let name = name.as_ref().to_os_string();
+        let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").unwrap_or("/tmp/".into());
+        let path = PathBuf::from(runtime_dir).join(&name);
+        let mut lock_path = path.clone().into_os_string();
+        lock_path.push(".lock");
+        let lock_path = PathBuf::from(lock_path);
+
+        let lock = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .mode(0o660)
+            .open(&lock_path)?;
+        if unsafe { syscall!(libc::flock(lock.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB)) }
+            .is_err()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("{} is used by another compositor", path.display()),
+            ));
+        }
+        // We hold the lock, whatever socket is there was left behind
+        match std::fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        let listener = UnixListener::bind(&path)?;
+        listener.set_nonblocking(true)?;
+        log!(TRACE, "listening on wayland socket at {:?}", path);
+        Ok(Self {
+            listener,
+            name,
+            path,
+            lock_path,
+            _lock: lock,
+        })
+    }
+
+    /// Listens on the first free `wayland-N`.
+    pub fn bind_auto() -> io::Result<Self> {
+        for n in 0..=MAX_DISPLAYS {
+            match Self::bind(format!("wayland-{n}")) {
+                Err(err) if err.kind() == io::ErrorKind::AddrInUse => continue,
+                result => return result,
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            "every wayland socket name is taken",
+        ))
+    }
+
+    /// What clients set `WAYLAND_DISPLAY` to, e.g. `wayland-1`.
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// The next pending connection, `None` if there's none.
+    pub fn accept(&self) -> io::Result<Option<UnixStream>> {
+        match self.listener.accept() {
+            Ok((stream, _)) => {
+                stream.set_nonblocking(false)?;
+                Ok(Some(stream))
+            }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl AsRawFd for ListeningSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.listener.as_raw_fd()
+    }
+}
+
+impl Drop for ListeningSocket {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+        let _ = std::fs::remove_file(&self.lock_path);
+    }
+}
@@ -0,0 +1,8 @@
+#![allow(unused)]
+
+use crate::connection::{Reader, WaylandBuffer, Writer};
+use crate::events::*;
+use crate::interface;
+use crate::server::{Client, Dispatch, Resource};
+
+scr_scanner_macros::generate_server!("./protocols/wayland.xml");
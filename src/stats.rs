@@ -0,0 +1,326 @@
+//! Counters of what a connection sent and received, to find chatty code paths and check that
+//! requests are batched.
+//!
+//! [`Connection::stats`](crate::connection::Connection::stats) takes a [`Stats`] snapshot,
+//! [`Connection::reset_stats`](crate::connection::Connection::reset_stats) starts over:
+//!
+//! ```ignore
+//! conn.reset_stats();
+//! draw_frame(&conn);
+//! let stats = conn.stats();
+//! let damage = stats.message("wl_surface", "damage_buffer").map_or(0, |msg| msg.count);
+//! println!("{damage} damage_buffer in {} flushes", stats.flushes);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::connection::ObjectMap;
+use crate::debug::Side;
+use crate::events::Header;
+use crate::trace::Direction;
+
+/// A snapshot of the counters of a connection.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    pub sent:                 Traffic,
+    pub received:             Traffic,
+    /// Writes to the socket, every one sends all the requests queued since the last.
+    pub flushes:              u64,
+    /// Reads from the socket.
+    pub reads:                u64,
+    /// Messages a read ended in the middle of, kept and completed by a later read.
+    pub reassembled_messages: u64,
+    /// Time spent handling events in `dispatch_events` and `roundtrip`, without waiting for them.
+    pub dispatch_time:        Duration,
+    /// Every message that was sent or received at least once, ordered by interface, then
+    /// requests before events, then opcode. Messages of objects the connection doesn't know
+    /// about are only in the totals.
+    pub messages:             Vec<MessageStats>,
+}
+
+/// What went one way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Traffic {
+    /// Bytes that went through the socket.
+    pub bytes:    u64,
+    pub messages: u64,
+    pub fds:      u64,
+}
+
+/// How often one message was sent or received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageStats {
+    pub direction: Direction,
+    pub interface: &'static str,
+    pub opcode:    u16,
+    pub name:      &'static str,
+    pub count:     u64,
+    /// The size of all of them, headers included.
+    pub bytes:     u64,
+}
+
+impl Stats {
+    /// The counts of the request or event `name` of `interface`.
+    pub fn message(&self, interface: &str, name: &str) -> Option<&MessageStats> {
+        self.messages
+            .iter()
+            .find(|msg| msg.interface == interface && msg.name == name)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            sent,
+            received,
+            ..
+        } = self;
+        writeln!(
+            f,
+            "sent {} messages, {} bytes and {} fds in {} flushes",
+            sent.messages, sent.bytes, sent.fds, self.flushes
+        )?;
+        write!(
+            f,
+            "received {} messages, {} bytes and {} fds in {} reads, {} messages reassembled, {:?} \
+             dispatching",
+            received.messages,
+            received.bytes,
+            received.fds,
+            self.reads,
+            self.reassembled_messages,
+            self.dispatch_time
+        )?;
+        for msg in &self.messages {
+            let arrow = match msg.direction {
+                Direction::Sent => "->",
+                Direction::Received => "<-",
+            };
+            write!(
+                f,
+                "\n  {arrow} {}.{}: {} ({} bytes)",
+                msg.interface, msg.name, msg.count, msg.bytes
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The counters of one [`WaylandBuffer`](crate::connection::WaylandBuffer), what a reader
+/// counts is received and what a writer counts sent.
+#[derive(Debug, Default)]
+pub(crate) struct Counters {
+    bytes:          AtomicU64,
+    fds:            AtomicU64,
+    messages:       AtomicU64,
+    // Flushes or reads
+    transfers:      AtomicU64,
+    reassembled:    AtomicU64,
+    dispatch_nanos: AtomicU64,
+    // By interface and opcode
+    by_message:     Mutex<HashMap<(&'static str, u16), Count>>,
+}
+
+#[derive(Debug)]
+struct Count {
+    name:  &'static str,
+    count: u64,
+    bytes: u64,
+}
+
+impl Counters {
+    /// A flush or read of `bytes` carrying `fds`.
+    pub(crate) fn transfer(&self, bytes: usize, fds: usize) {
+        self.transfers.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.fds.fetch_add(fds as u64, Ordering::Relaxed);
+    }
+
+    /// Counts the message `header` introduces, `sent` by `side`.
+    pub(crate) fn message(&self, objects: &ObjectMap, side: Side, sent: bool, header: Header) {
+        self.messages.fetch_add(1, Ordering::Relaxed);
+        let Some(interface) = objects.desc(header.id) else {
+            return;
+        };
+        let Some(desc) = side.message(interface, sent, header.opcode) else {
+            return;
+        };
+        let mut by_message = self.by_message.lock().unwrap();
+        let entry = by_message
+            .entry((interface.name, header.opcode))
+            .or_insert(Count {
+                name:  desc.name,
+                count: 0,
+                bytes: 0,
+            });
+        entry.count += 1;
+        entry.bytes += header.size as u64;
+    }
+
+    /// A read completed the message the last one ended in the middle of.
+    pub(crate) fn reassembled(&self) {
+        self.reassembled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn dispatched(&self, time: Duration) {
+        self.dispatch_nanos
+            .fetch_add(time.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn traffic(&self) -> Traffic {
+        Traffic {
+            bytes:    self.bytes.load(Ordering::Relaxed),
+            messages: self.messages.load(Ordering::Relaxed),
+            fds:      self.fds.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn transfers(&self) -> u64 {
+        self.transfers.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn reassembled_messages(&self) -> u64 {
+        self.reassembled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn dispatch_time(&self) -> Duration {
+        Duration::from_nanos(self.dispatch_nanos.load(Ordering::Relaxed))
+    }
+
+    /// The per message counts, going in `direction`.
+    pub(crate) fn messages(&self, direction: Direction) -> impl Iterator<Item = MessageStats> {
+        let by_message = self.by_message.lock().unwrap();
+        let messages: Vec<_> = by_message
+            .iter()
+            .map(|(&(interface, opcode), count)| MessageStats {
+                direction,
+                interface,
+                opcode,
+                name: count.name,
+                count: count.count,
+                bytes: count.bytes,
+            })
+            .collect();
+        messages.into_iter()
+    }
+
+    pub(crate) fn reset(&self) {
+        for counter in [
+            &self.bytes,
+            &self.fds,
+            &self.messages,
+            &self.transfers,
+            &self.reassembled,
+            &self.dispatch_nanos,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.by_message.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    use super::*;
+    use crate::connection::{Connection, State};
+    use crate::events::WlEvent;
+    use crate::wayland::*;
+
+    struct Ignore;
+
+    impl State for Ignore {
+        fn handle_event(&mut self, _: &Connection, _: WlEvent<'_>) {}
+    }
+
+    #[test]
+    fn traffic_is_counted_per_message() {
+        let (socket, mut compositor) = UnixStream::pair().unwrap();
+        let conn = Connection::from_fd(socket.into());
+        let registry = conn.display().get_registry(conn.writer());
+        let wl_compositor: wl_compositor::WlCompositor = registry.bind(conn.writer(), 1, 4);
+        let surface = wl_compositor.create_surface(conn.writer());
+        for _ in 0..3 {
+            surface.damage_buffer(conn.writer(), 0, 0, 1, 1);
+        }
+        surface.commit(conn.writer());
+        conn.flush().unwrap();
+        let mut sent = [0; 1024];
+        let len = compositor.read(&mut sent).unwrap();
+
+        let stats = conn.stats();
+        assert_eq!(
+            stats.sent,
+            Traffic {
+                bytes:    len as u64,
+                messages: 7,
+                fds:      0,
+            }
+        );
+        assert_eq!(stats.flushes, 1);
+        let damage = stats.message("wl_surface", "damage_buffer").unwrap();
+        assert_eq!(
+            (damage.direction, damage.count, damage.bytes),
+            (Direction::Sent, 3, 72)
+        );
+        let names: Vec<_> = stats.messages.iter().map(|msg| msg.name).collect();
+        assert_eq!(
+            names,
+            [
+                "create_surface",
+                "get_registry",
+                "bind",
+                "commit",
+                "damage_buffer"
+            ]
+        );
+
+        // wl_registry.global(1, "wl_seat", 7) and wl_registry.global(2, "wl_shm", 1), cut off
+        // after the header of the second one
+        let mut events = Vec::new();
+        for word in [2, 28 << 16, 1, 8] {
+            events.extend_from_slice(&u32::to_ne_bytes(word));
+        }
+        events.extend_from_slice(b"wl_seat\0");
+        events.extend_from_slice(&7u32.to_ne_bytes());
+        for word in [2, 28 << 16, 2, 7] {
+            events.extend_from_slice(&u32::to_ne_bytes(word));
+        }
+        events.extend_from_slice(b"wl_shm\0\0");
+        events.extend_from_slice(&1u32.to_ne_bytes());
+        let (first, rest) = events.split_at(36);
+        compositor.write_all(first).unwrap();
+        conn.dispatch_events(&mut Ignore).unwrap();
+
+        let stats = conn.stats();
+        assert_eq!(stats.received.messages, 1);
+        assert_eq!((stats.reads, stats.reassembled_messages), (1, 0));
+
+        compositor.write_all(rest).unwrap();
+        conn.dispatch_events(&mut Ignore).unwrap();
+
+        let stats = conn.stats();
+        assert_eq!(stats.received.messages, 2);
+        assert_eq!(stats.received.bytes, events.len() as u64);
+        assert_eq!((stats.reads, stats.reassembled_messages), (2, 1));
+        let global = stats.message("wl_registry", "global").unwrap();
+        assert_eq!(
+            (global.direction, global.count, global.bytes),
+            (Direction::Received, 2, 56)
+        );
+
+        conn.reset_stats();
+        let stats = conn.stats();
+        assert_eq!(
+            (stats.sent, stats.received),
+            (Traffic::default(), Traffic::default())
+        );
+        assert!(stats.messages.is_empty());
+    }
+}
@@ -0,0 +1,634 @@
+//! A scripted fake compositor for testing client code without a real one.
+//!
+//! [`MockServer::new`] connects a [`Connection`] to a [`MockServer`] over a `socketpair`. The
+//! server records every request it gets, advertises its globals to registries, answers
+//! `wl_display.sync` and sends whatever events the test asks for:
+//!
+//! ```ignore
+//! let (conn, mut server) = MockServer::new();
+//! server.global(&wl_seat::INTERFACE_DESC, 7);
+//! conn.display().get_registry(conn.writer());
+//! testing::roundtrip(&conn, &mut server, &mut app)?;
+//!
+//! let keyboard = server.object("wl_keyboard").unwrap();
+//! server.send(keyboard, "enter", [Arg::Uint(1), Arg::Object(surface), Arg::Array(vec![])]);
+//! testing::roundtrip(&conn, &mut server, &mut app)?;
+//! ```
+//!
+//! Everything is encoded from the [`Interface`] descriptions, so objects of any protocol
+//! the client was generated for work.
+
+use std::io;
+use std::os::fd::{AsRawFd, IntoRawFd, OwnedFd, RawFd};
+use std::os::unix::net::UnixStream;
+
+use crate::connection::{Connection, ObjectInfo, State};
+use crate::events::{Message, WlEvent};
+use crate::interface::{ArgDesc, ArgType, Interface, MessageDesc};
+use crate::server::{Client, ServerState};
+use crate::utils::syscall;
+
+/// A message argument, as received in a request or to send in an event.
+///
+/// A new_id without an interface is three arguments, the interface name, the version and
+/// the id, as on the wire.
+#[derive(Debug)]
+pub enum Arg {
+    Int(i32),
+    Uint(u32),
+    Fixed(f32),
+    String(String),
+    /// The null of nullable strings.
+    NullString,
+    /// 0 for null.
+    Object(u32),
+    NewId(u32),
+    /// The bytes on the wire, whatever the element type.
+    Array(Vec<u8>),
+    Fd(OwnedFd),
+}
+
+impl PartialEq for Arg {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Uint(a), Self::Uint(b)) => a == b,
+            (Self::Fixed(a), Self::Fixed(b)) => a == b,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::NullString, Self::NullString) => true,
+            (Self::Object(a), Self::Object(b)) => a == b,
+            (Self::NewId(a), Self::NewId(b)) => a == b,
+            (Self::Array(a), Self::Array(b)) => a == b,
+            (Self::Fd(a), Self::Fd(b)) => a.as_raw_fd() == b.as_raw_fd(),
+            _ => false,
+        }
+    }
+}
+
+/// A request the server received.
+#[derive(Debug)]
+pub struct Request {
+    pub object: ObjectInfo,
+    pub name:   &'static str,
+    pub opcode: u16,
+    pub args:   Vec<Arg>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Global {
+    pub name:      u32,
+    pub interface: &'static Interface,
+    pub version:   u32,
+}
+
+/// The fake compositor end of a [`Connection`].
+#[derive(Debug)]
+pub struct MockServer {
+    client: Client,
+    state:  Recorder,
+}
+
+#[derive(Debug, Default)]
+struct Recorder {
+    globals:    Vec<Global>,
+    registries: Vec<u32>,
+    requests:   Vec<Request>,
+    serial:     u32,
+}
+
+impl MockServer {
+    /// A connection and the server on its other end.
+    pub fn new() -> (Connection, MockServer) {
+        let (client, server) = UnixStream::pair().expect("couldn't create a socketpair");
+        let conn = Connection::from_fd(client.into());
+        let server = MockServer {
+            client: Client::new(server),
+            state:  Recorder::default(),
+        };
+        (conn, server)
+    }
+
+    /// The server's view of the client, to post errors or send events through the types
+    /// of [`server::wayland`](crate::server::wayland).
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Advertises a global to every registry, present and future, and returns its name.
+    pub fn global(&mut self, interface: &'static Interface, version: u32) -> u32 {
+        let global = Global {
+            name: self.state.globals.last().map_or(1, |g| g.name + 1),
+            interface,
+            version,
+        };
+        self.state.globals.push(global);
+        for registry in self.state.registries.clone() {
+            self.state.announce(&self.client, registry, global);
+        }
+        global.name
+    }
+
+    /// Withdraws the global `name` from every registry.
+    pub fn remove_global(&mut self, name: u32) {
+        self.state.globals.retain(|g| g.name != name);
+        for registry in self.state.registries.clone() {
+            send(
+                &self.client,
+                registry,
+                "global_remove",
+                vec![Arg::Uint(name)],
+            );
+        }
+    }
+
+    pub fn globals(&self) -> &[Global] {
+        &self.state.globals
+    }
+
+    /// Queues `event` on `object`, sent with the next [`MockServer::dispatch`] or
+    /// [`MockServer::flush`].
+    ///
+    /// New ids in the event are tracked like the client's, ids for them come from
+    /// [`MockServer::new_id`].
+    ///
+    /// # Panics
+    ///
+    /// If `object` doesn't exist, has no such event or `args` don't fit it.
+    pub fn send(&mut self, object: u32, event: &str, args: impl IntoIterator<Item = Arg>) {
+        send(&self.client, object, event, args.into_iter().collect());
+    }
+
+    /// An id for an object created by the server.
+    pub fn new_id(&self) -> u32 {
+        self.client.writer().new_server_id()
+    }
+
+    /// A new serial for input and configure events.
+    pub fn serial(&mut self) -> u32 {
+        self.state.serial += 1;
+        self.state.serial
+    }
+
+    /// The newest object of `interface`.
+    pub fn object(&self, interface: &str) -> Option<u32> {
+        self.client
+            .reader()
+            .objects
+            .all()
+            .iter()
+            .rev()
+            .find(|info| info.interface == interface)
+            .map(|info| info.id)
+    }
+
+    /// Every request received so far.
+    pub fn requests(&self) -> &[Request] {
+        &self.state.requests
+    }
+
+    /// Takes the requests received so far, so later assertions only see newer ones.
+    pub fn take_requests(&mut self) -> Vec<Request> {
+        std::mem::take(&mut self.state.requests)
+    }
+
+    /// Handles whatever the client sent without waiting for more, then flushes.
+    pub fn dispatch(&mut self) -> io::Result<()> {
+        if readable(self.client.as_raw_fd())? {
+            self.client.dispatch_requests(&mut self.state)?;
+        }
+        self.flush()
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        self.client.flush()
+    }
+}
+
+/// Lets the server answer everything `conn` sent and hands the answers to `state`.
+pub fn roundtrip<S: State>(
+    conn: &Connection, server: &mut MockServer, state: &mut S,
+) -> io::Result<()> {
+    conn.flush()?;
+    server.dispatch()?;
+    if readable(conn.display_fd())? {
+        conn.dispatch_events(state)?;
+    }
+    Ok(())
+}
+
+fn readable(fd: RawFd) -> io::Result<bool> {
+    let mut pollfd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let ready = unsafe { syscall!(libc::poll(&raw mut pollfd, 1, 0))? };
+    Ok(ready > 0)
+}
+
+impl Recorder {
+    fn announce(&self, client: &Client, registry: u32, global: Global) {
+        let args = vec![
+            Arg::Uint(global.name),
+            Arg::String(global.interface.name.to_string()),
+            Arg::Uint(global.version),
+        ];
+        send(client, registry, "global", args);
+    }
+
+    /// The arguments of `request`, `None` if it doesn't match `message`. New objects are
+    /// tracked like the generated code would.
+    fn args(
+        &self, client: &Client, object: ObjectInfo, message: &MessageDesc, request: WlEvent<'_>,
+    ) -> Option<Vec<Arg>> {
+        let objects = &client.reader().objects;
+        let parser = request.parser();
+        let mut args = Vec::new();
+        for arg in message.args {
+            match arg.ty {
+                ArgType::Int => args.push(Arg::Int(parser.get_i32()?)),
+                ArgType::Uint => args.push(Arg::Uint(parser.get_u32()?)),
+                ArgType::Fixed => args.push(Arg::Fixed(parser.get_fixed()?)),
+                ArgType::String => args.push(match parser.get_nullable_string()? {
+                    Some(string) => Arg::String(string.to_string()),
+                    None if arg.nullable => Arg::NullString,
+                    None => return None,
+                }),
+                ArgType::Object => args.push(Arg::Object(parser.get_u32()?)),
+                ArgType::NewId => {
+                    let (interface, version) = match arg.interface {
+                        Some(interface) => (Some(interface), object.version),
+                        None => {
+                            let name = parser.get_string()?;
+                            let version = parser.get_u32()?;
+                            args.push(Arg::String(name.to_string()));
+                            args.push(Arg::Uint(version));
+                            let bound = self.globals.iter().find(|g| g.interface.name == name);
+                            (bound.map(|g| g.interface), version)
+                        }
+                    };
+                    let id = parser.get_u32()?;
+                    if let Some(interface) = interface {
+                        let info = ObjectInfo {
+                            id,
+                            interface: interface.name,
+                            version,
+                        };
+                        objects.insert(info, interface);
+                    }
+                    args.push(Arg::NewId(id));
+                }
+                ArgType::Array => args.push(Arg::Array(parser.get_bytes()?.to_vec())),
+                ArgType::Fd => args.push(Arg::Fd(client.reader().get_fd()?)),
+            }
+        }
+        Some(args)
+    }
+}
+
+impl ServerState for Recorder {
+    fn handle_request(&mut self, client: &Client, request: WlEvent<'_>) {
+        let objects = &client.reader().objects;
+        // Checked by `Client::dispatch_requests`
+        let object = objects.get(request.header.id).unwrap();
+        let desc = objects.desc(object.id).unwrap();
+        let message = desc.request(request.header.opcode).unwrap();
+
+        let Some(args) = self.args(client, object, message, request) else {
+            client.malformed(request);
+            return;
+        };
+
+        if object.interface == "wl_display" {
+            match (message.name, &args[..]) {
+                ("sync", [Arg::NewId(callback)]) => {
+                    send(client, *callback, "done", vec![Arg::Uint(self.serial)]);
+                }
+                ("get_registry", [Arg::NewId(registry)]) => {
+                    self.registries.push(*registry);
+                    for global in &self.globals {
+                        self.announce(client, *registry, *global);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.requests.push(Request {
+            object,
+            name: message.name,
+            opcode: request.header.opcode,
+            args,
+        });
+    }
+}
+
+/// Encodes `event` from `object`'s description, tracking new objects and releasing the
+/// object if the event is a destructor.
+fn send(client: &Client, object: u32, event: &str, args: Vec<Arg>) {
+    let objects = &client.writer().objects;
+    let info = objects
+        .get(object)
+        .unwrap_or_else(|| panic!("no object {object}"));
+    let desc = objects.desc(object).unwrap();
+    let (opcode, message) = desc
+        .events
+        .iter()
+        .enumerate()
+        .find(|(_, message)| message.name == event)
+        .unwrap_or_else(|| panic!("{} has no event {event}", info.interface));
+    let mismatch = |arg: &ArgDesc, value: Option<Arg>| -> ! {
+        panic!(
+            "`{}` of {}.{event} can't be {value:?}",
+            arg.name, info.interface
+        )
+    };
+
+    let mut msg = Message::<64>::new(object, opcode as u16);
+    let mut args = args.into_iter();
+    for arg in message.args {
+        match (arg.ty, arg.interface, args.next()) {
+            (ArgType::Int, _, Some(Arg::Int(value))) => _ = msg.write_i32(value),
+            (ArgType::Uint, _, Some(Arg::Uint(value))) => _ = msg.write_u32(value),
+            (ArgType::Fixed, _, Some(Arg::Fixed(value))) => _ = msg.write_fixed(value),
+            (ArgType::String, _, Some(Arg::String(value))) => _ = msg.write_string(value),
+            (ArgType::String, _, Some(Arg::NullString)) if arg.nullable => {
+                _ = msg.write_null_string()
+            }
+            (ArgType::Object, _, Some(Arg::Object(id))) => _ = msg.write_u32(id),
+            (ArgType::Array, _, Some(Arg::Array(bytes))) => _ = msg.write_bytes(&bytes),
+            (ArgType::Fd, _, Some(Arg::Fd(fd))) => client.writer().add_fd(fd.into_raw_fd()),
+            (ArgType::NewId, Some(interface), Some(Arg::NewId(id))) => {
+                let created = ObjectInfo {
+                    id,
+                    interface: interface.name,
+                    version: info.version,
+                };
+                objects.insert(created, interface);
+                msg.write_u32(id);
+            }
+            // Preceded by the interface name and version, like on the wire
+            (ArgType::NewId, None, Some(Arg::String(name))) => {
+                let (Some(Arg::Uint(version)), Some(Arg::NewId(id))) = (args.next(), args.next())
+                else {
+                    panic!(
+                        "`{}` of {}.{event} needs a version and an id",
+                        arg.name, info.interface
+                    );
+                };
+                let interface = crate::wayland::INTERFACES
+                    .iter()
+                    .copied()
+                    .find(|desc| desc.name == name)
+                    .or_else(|| objects.desc_named(&name));
+                if let Some(interface) = interface {
+                    let created = ObjectInfo {
+                        id,
+                        interface: interface.name,
+                        version,
+                    };
+                    objects.insert(created, interface);
+                }
+                msg.write_string(name).write_u32(version).write_u32(id);
+            }
+            (_, _, value) => mismatch(arg, value),
+        }
+    }
+    if let Some(extra) = args.next() {
+        panic!(
+            "{}.{event} takes {} arguments, got {extra:?} too",
+            info.interface,
+            message.args.len()
+        );
+    }
+    msg.build();
+    client.writer().write_request(msg.data());
+    if message.destructor {
+        client.writer().destroy_id(object);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek, Write};
+
+    use super::*;
+    use crate::connection::Object;
+    use crate::wayland::*;
+
+    #[derive(Default)]
+    struct App {
+        globals: Vec<(u32, String, u32)>,
+        removed: Vec<u32>,
+        synced:  bool,
+        keymap:  Option<String>,
+        keys:    Vec<u32>,
+        motion:  Option<(f32, f32)>,
+    }
+
+    impl State for App {
+        fn handle_event(&mut self, conn: &Connection, event: WlEvent<'_>) {
+            let Some(event) = conn.dispatch::<wl_display::WlDisplay, _>(self, event) else {
+                return;
+            };
+            let Some(event) = conn.dispatch::<wl_registry::WlRegistry, _>(self, event) else {
+                return;
+            };
+            let Some(event) = conn.dispatch::<wl_callback::WlCallback, _>(self, event) else {
+                return;
+            };
+            let Some(event) = conn.dispatch::<wl_keyboard::WlKeyboard, _>(self, event) else {
+                return;
+            };
+            let Some(event) = conn.dispatch::<wl_pointer::WlPointer, _>(self, event) else {
+                return;
+            };
+            panic!("unexpected event {:?}", event.header);
+        }
+    }
+
+    impl wl_display::WlDisplayHandler for App {}
+
+    impl wl_registry::WlRegistryHandler for App {
+        fn global(
+            &mut self, _: &Connection, _: &wl_registry::WlRegistry, name: u32, interface: &str,
+            version: u32,
+        ) {
+            self.globals.push((name, interface.to_string(), version));
+        }
+
+        fn global_remove(&mut self, _: &Connection, _: &wl_registry::WlRegistry, name: u32) {
+            self.removed.push(name);
+        }
+    }
+
+    impl wl_callback::WlCallbackHandler for App {
+        fn done(&mut self, _: &Connection, _: &wl_callback::WlCallback, _: u32) {
+            self.synced = true;
+        }
+    }
+
+    impl wl_keyboard::WlKeyboardHandler for App {
+        fn keymap(
+            &mut self, _: &Connection, _: &wl_keyboard::WlKeyboard,
+            _: crate::events::WEnum<wl_keyboard::KeymapFormat>, fd: OwnedFd, size: u32,
+        ) {
+            let mut keymap = String::new();
+            std::fs::File::from(fd).read_to_string(&mut keymap).unwrap();
+            assert_eq!(keymap.len(), size as usize);
+            self.keymap = Some(keymap);
+        }
+
+        fn enter(
+            &mut self, _: &Connection, _: &wl_keyboard::WlKeyboard, _: u32,
+            _: wl_surface::WlSurface, keys: &[u32],
+        ) {
+            self.keys = keys.to_vec();
+        }
+    }
+
+    impl wl_pointer::WlPointerHandler for App {
+        fn motion(&mut self, _: &Connection, _: &wl_pointer::WlPointer, _: u32, x: f32, y: f32) {
+            self.motion = Some((x, y));
+        }
+    }
+
+    #[test]
+    fn globals_are_advertised_and_bound() {
+        let (conn, mut server) = MockServer::new();
+        let mut app = App::default();
+        server.global(&wl_compositor::INTERFACE_DESC, 6);
+        let registry = conn.display().get_registry(conn.writer());
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+        assert_eq!(app.globals, [(1, "wl_compositor".to_string(), 6)]);
+
+        let seat = server.global(&wl_seat::INTERFACE_DESC, 7);
+        let compositor: wl_compositor::WlCompositor = registry.bind(conn.writer(), 1, 6);
+        let surface = compositor.create_surface(conn.writer());
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+        assert_eq!(app.globals[1], (seat, "wl_seat".to_string(), 7));
+        assert_eq!(server.object("wl_surface"), Some(surface.id()));
+
+        let requests = server.take_requests();
+        let names: Vec<_> = requests
+            .iter()
+            .map(|r| (r.object.interface, r.name))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("wl_display", "get_registry"),
+                ("wl_registry", "bind"),
+                ("wl_compositor", "create_surface")
+            ]
+        );
+        assert_eq!(
+            requests[1].args,
+            [
+                Arg::Uint(1),
+                Arg::String("wl_compositor".into()),
+                Arg::Uint(6),
+                Arg::NewId(compositor.id())
+            ]
+        );
+
+        server.remove_global(seat);
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+        assert_eq!(app.removed, [seat]);
+    }
+
+    #[test]
+    fn sync_is_answered() {
+        let (conn, mut server) = MockServer::new();
+        let mut app = App::default();
+        let callback = conn.display().sync(conn.writer());
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+        assert!(app.synced);
+        assert_eq!(server.object("wl_callback"), None);
+        // Released with `wl_display.delete_id`
+        assert_eq!(conn.object_info(callback.id()), None);
+    }
+
+    #[test]
+    fn events_carry_fds() {
+        let (conn, mut server) = MockServer::new();
+        let mut app = App::default();
+        server.global(&wl_seat::INTERFACE_DESC, 7);
+        let registry = conn.display().get_registry(conn.writer());
+        let seat: wl_seat::WlSeat = registry.bind(conn.writer(), 1, 7);
+        let keyboard = seat.get_keyboard(conn.writer());
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+
+        let keymap = "xkb_keymap {};";
+        let mut file = tempfile();
+        file.write_all(keymap.as_bytes()).unwrap();
+        file.rewind().unwrap();
+        server.send(
+            keyboard.id(),
+            "keymap",
+            [
+                Arg::Uint(1),
+                Arg::Fd(file.into()),
+                Arg::Uint(keymap.len() as u32),
+            ],
+        );
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+        assert_eq!(app.keymap.as_deref(), Some(keymap));
+    }
+
+    #[test]
+    fn events_are_encoded_like_the_generated_code() {
+        let (conn, mut server) = MockServer::new();
+        let mut app = App::default();
+        server.global(&wl_compositor::INTERFACE_DESC, 6);
+        server.global(&wl_seat::INTERFACE_DESC, 7);
+        let registry = conn.display().get_registry(conn.writer());
+        let compositor: wl_compositor::WlCompositor = registry.bind(conn.writer(), 1, 6);
+        let seat: wl_seat::WlSeat = registry.bind(conn.writer(), 2, 7);
+        let surface = compositor.create_surface(conn.writer());
+        let keyboard = seat.get_keyboard(conn.writer());
+        let pointer = seat.get_pointer(conn.writer());
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+
+        let keys = [30u32, 31]
+            .iter()
+            .flat_map(|key| key.to_ne_bytes())
+            .collect();
+        server.send(
+            keyboard.id(),
+            "enter",
+            [Arg::Uint(1), Arg::Object(surface.id()), Arg::Array(keys)],
+        );
+        // Rounded to the nearest 1/256 rather than truncated
+        server.send(
+            pointer.id(),
+            "motion",
+            [Arg::Uint(2), Arg::Fixed(0.999), Arg::Fixed(-1.5)],
+        );
+        roundtrip(&conn, &mut server, &mut app).unwrap();
+        assert_eq!(app.keys, [30, 31]);
+        assert_eq!(app.motion, Some((1.0, -1.5)));
+    }
+
+    #[test]
+    #[should_panic = "`surface_x` of wl_pointer.motion can't be Some(Uint(1))"]
+    fn mismatched_arguments_panic() {
+        let (conn, mut server) = MockServer::new();
+        server.global(&wl_seat::INTERFACE_DESC, 7);
+        let registry = conn.display().get_registry(conn.writer());
+        let seat: wl_seat::WlSeat = registry.bind(conn.writer(), 1, 7);
+        let pointer = seat.get_pointer(conn.writer());
+        roundtrip(&conn, &mut server, &mut App::default()).unwrap();
+        server.send(
+            pointer.id(),
+            "motion",
+            [Arg::Uint(2), Arg::Uint(1), Arg::Uint(1)],
+        );
+    }
+
+    fn tempfile() -> std::fs::File {
+        let fd = unsafe { libc::memfd_create(c"keymap".as_ptr(), libc::MFD_CLOEXEC) };
+        assert!(fd >= 0);
+        unsafe { <std::fs::File as std::os::fd::FromRawFd>::from_raw_fd(fd) }
+    }
+}
@@ -0,0 +1,451 @@
+//! Recording the wire traffic of a connection and feeding it back to a client.
+//!
+//! A trace is a JSON-lines file with one message per line:
+//!
+//! ```text
+//! {"time_us":1520,"dir":"sent","fds":0,"data":"0100000001000c0002000000"}
+//! ```
+//!
+//! `time_us` counts from when tracing started and `data` is the message as it was on the wire,
+//! header included. File descriptors can't be recorded, `fds` is how many came along with it:
+//! they're passed per read, not per message, so all the fds of a read are counted on its first
+//! message.
+//!
+//! Tracing is turned on with [`Connection::trace_to`](crate::connection::Connection::trace_to)
+//! or by pointing `SCR_TRACE_FILE` at a file, traces of every connection made are appended to it.
+//! [`Replayer`] plays the received half of a trace back to a client, so a bug seen on some
+//! compositor can be turned into a test.
+
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::os::fd::{AsRawFd, IntoRawFd, OwnedFd};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::connection::{Connection, ObjectMap, WaylandBuffer, Writer};
+use crate::events::Header;
+use crate::log;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Sent => "sent",
+            Self::Received => "recv",
+        }
+    }
+}
+
+/// One message of a trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub time:      Duration,
+    pub direction: Direction,
+    pub fds:       u32,
+    pub data:      Vec<u8>,
+}
+
+impl Record {
+    pub fn header(&self) -> Option<Header> {
+        (self.data.len() >= Header::HEADER_SIZE)
+            .then(|| Header::from_slice(&self.data[..Header::HEADER_SIZE]))
+    }
+
+    fn to_json(&self) -> String {
+        let mut data = String::with_capacity(self.data.len() * 2);
+        for byte in &self.data {
+            write!(data, "{byte:02x}").unwrap();
+        }
+        format!(
+            "{{\"time_us\":{},\"dir\":\"{}\",\"fds\":{},\"data\":\"{}\"}}",
+            self.time.as_micros(),
+            self.direction.as_str(),
+            self.fds,
+            data
+        )
+    }
+
+    /// Parses a line written by [`Tracer`], other JSON isn't understood.
+    pub fn from_json(line: &str) -> Result<Self, String> {
+        let field = |name: &str| -> Result<&str, String> {
+            let key = format!("\"{name}\":");
+            let start = line.find(&key).ok_or(format!("missing `{name}`"))? + key.len();
+            let rest = &line[start..];
+            let end = rest
+                .find([',', '}'])
+                .ok_or(format!("unterminated `{name}`"))?;
+            Ok(rest[..end].trim().trim_matches('"'))
+        };
+        let number = |name: &str| -> Result<u64, String> {
+            field(name)?
+                .parse()
+                .map_err(|_| format!("`{name}` isn't a number"))
+        };
+
+        let direction = match field("dir")? {
+            "sent" => Direction::Sent,
+            "recv" => Direction::Received,
+            other => return Err(format!("unknown direction `{other}`")),
+        };
+        let hex = field("data")?;
+        if hex.len() % 2 != 0 {
+            return Err("odd number of hex digits in `data`".into());
+        }
+        let data = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "`data` isn't hex".to_string())?;
+        Ok(Self {
+            time: Duration::from_micros(number("time_us")?),
+            direction,
+            fds: number("fds")? as u32,
+            data,
+        })
+    }
+}
+
+/// Reads every record of a trace file.
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Record>> {
+    let file = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+    for (n, line) in file.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = Record::from_json(&line).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {err}", n + 1))
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Writes the messages of a connection to a trace, shared by its reader and writer.
+pub struct Tracer {
+    start: Instant,
+    out:   Mutex<Box<dyn Write + Send>>,
+}
+
+impl std::fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tracer")
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Tracer {
+    pub fn new(out: impl Write + Send + 'static) -> Self {
+        Self {
+            start: Instant::now(),
+            out:   Mutex::new(Box::new(out)),
+        }
+    }
+
+    /// Appends to the file at `path`, creating it if needed.
+    pub fn to_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(BufWriter::new(file)))
+    }
+
+    /// Records every message in `data`, which starts at a message boundary.
+    pub(crate) fn record(&self, direction: Direction, data: &[u8], fds: usize) {
+        let time = self.start.elapsed();
+        let mut out = self.out.lock().unwrap();
+        let mut fds = fds as u32;
+        let mut rest = data;
+        while rest.len() >= Header::HEADER_SIZE {
+            let size = (Header::from_slice(&rest[..Header::HEADER_SIZE]).size as usize)
+                .clamp(Header::HEADER_SIZE, rest.len());
+            let record = Record {
+                time,
+                direction,
+                fds,
+                data: rest[..size].to_vec(),
+            };
+            fds = 0;
+            rest = &rest[size..];
+            if let Err(err) = writeln!(out, "{}", record.to_json()) {
+                log!(WARNING, "Couldn't write the trace: {}", err);
+                return;
+            }
+        }
+        let _ = out.flush();
+    }
+}
+
+/// Plays the received messages of a trace back to a client, waiting for the client to send
+/// what it sent while recording before going on.
+///
+/// The client has to make the same requests in the same order, the first one that differs
+/// from the trace ends the replay with an error naming its record. Fds are replaced with empty
+/// memfds.
+///
+/// ```no_run
+/// # use scratchway::{prelude::*, trace::{self, Replayer}};
+/// # struct App;
+/// # impl State for App { fn handle_event(&mut self, _: &Connection, _: WlEvent<'_>) {} }
+/// let records = trace::load("bug-report.trace").unwrap();
+/// let (conn, replayer) = Replayer::spawn(records);
+/// let registry = conn.display().get_registry(conn.writer());
+/// conn.roundtrip(&mut App).unwrap();
+/// drop(conn);
+/// replayer.join().unwrap().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Replayer;
+
+impl Replayer {
+    /// Replays `records` from a thread, it ends once the trace is over or the client is gone.
+    pub fn spawn(records: Vec<Record>) -> (Connection, JoinHandle<io::Result<()>>) {
+        let (server, client) = UnixStream::pair().expect("socketpair failed");
+        let handle = std::thread::spawn(move || Self::run(server, &records));
+        (Connection::from_fd(client.into()), handle)
+    }
+
+    fn run(mut socket: UnixStream, records: &[Record]) -> io::Result<()> {
+        let writer = WaylandBuffer::<Writer>::new(socket.as_raw_fd(), Arc::new(ObjectMap::new()));
+        for (index, record) in records.iter().enumerate() {
+            match record.direction {
+                Direction::Sent => {
+                    writer.send()?;
+                    // Fds that came along are closed with the ancillary data
+                    let request = read_request(&mut socket)?;
+                    if let Some(mismatch) = compare(&request, record) {
+                        let err = format!("record {index} doesn't match the request: {mismatch}");
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+                    }
+                }
+                Direction::Received => {
+                    for _ in 0..record.fds {
+                        writer.add_fd(placeholder_fd()?.into_raw_fd());
+                    }
+                    writer.write_request(&record.data);
+                    // Fds go out with the data they came with
+                    if record.fds > 0 {
+                        writer.send()?;
+                    }
+                }
+            }
+        }
+        writer.send()
+    }
+}
+
+/// The next message the client sent, read by its header.
+fn read_request(socket: &mut UnixStream) -> io::Result<Vec<u8>> {
+    let mut request = vec![0; Header::HEADER_SIZE];
+    socket.read_exact(&mut request)?;
+    let size = Header::from_slice(&request).size as usize;
+    if size < Header::HEADER_SIZE {
+        let err = format!("the client sent a {size} byte message");
+        return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+    }
+    request.resize(size, 0);
+    socket.read_exact(&mut request[Header::HEADER_SIZE..])?;
+    Ok(request)
+}
+
+/// How `request` differs from the `recorded` one, `None` if it's the same.
+fn compare(request: &[u8], recorded: &Record) -> Option<String> {
+    let Some(expected) = recorded.header() else {
+        return Some("the recorded message has no header".to_string());
+    };
+    let header = Header::from_slice(&request[..Header::HEADER_SIZE]);
+    if (header.id, header.opcode, header.size) != (expected.id, expected.opcode, expected.size) {
+        return Some(format!(
+            "got {}#{} of {} bytes, expected {}#{} of {} bytes",
+            header.id, header.opcode, header.size, expected.id, expected.opcode, expected.size
+        ));
+    }
+    let offset = request
+        .iter()
+        .zip(&recorded.data)
+        .position(|(a, b)| a != b)?;
+    Some(format!(
+        "{}#{} differs from byte {offset} on",
+        header.id, header.opcode
+    ))
+}
+
+fn placeholder_fd() -> io::Result<OwnedFd> {
+    let fd = unsafe { libc::memfd_create(c"scr-trace-placeholder".as_ptr(), libc::MFD_CLOEXEC) };
+    if fd == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(unsafe { <OwnedFd as std::os::fd::FromRawFd>::from_raw_fd(fd) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::State;
+    use crate::events::WlEvent;
+    use crate::testing::{MockServer, roundtrip};
+    use crate::wayland::*;
+
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct App {
+        globals: Vec<(u32, String, u32)>,
+        synced:  bool,
+    }
+
+    impl State for App {
+        fn handle_event(&mut self, conn: &Connection, event: WlEvent<'_>) {
+            let Some(event) = conn.dispatch::<wl_display::WlDisplay, _>(self, event) else {
+                return;
+            };
+            let Some(event) = conn.dispatch::<wl_registry::WlRegistry, _>(self, event) else {
+                return;
+            };
+            let Some(event) = conn.dispatch::<wl_callback::WlCallback, _>(self, event) else {
+                return;
+            };
+            panic!("unexpected event {:?}", event.header);
+        }
+    }
+
+    impl wl_display::WlDisplayHandler for App {}
+
+    impl wl_registry::WlRegistryHandler for App {
+        fn global(
+            &mut self, _: &Connection, _: &wl_registry::WlRegistry, name: u32, interface: &str,
+            version: u32,
+        ) {
+            self.globals.push((name, interface.to_string(), version));
+        }
+    }
+
+    impl wl_callback::WlCallbackHandler for App {
+        fn done(&mut self, _: &Connection, _: &wl_callback::WlCallback, _: u32) {
+            self.synced = true;
+        }
+    }
+
+    #[test]
+    fn records_round_trip_through_json() {
+        let record = Record {
+            time:      Duration::from_micros(1520),
+            direction: Direction::Sent,
+            fds:       1,
+            data:      vec![1, 0, 0, 0, 0, 0, 8, 0],
+        };
+        let json = record.to_json();
+        assert_eq!(
+            json,
+            r#"{"time_us":1520,"dir":"sent","fds":1,"data":"0100000000000800"}"#
+        );
+        assert_eq!(Record::from_json(&json), Ok(record));
+        assert_eq!(
+            Record::from_json(r#"{"time_us":1,"dir":"up"}"#),
+            Err("unknown direction `up`".into())
+        );
+    }
+
+    #[test]
+    fn fds_are_counted_on_the_first_message_of_a_read() {
+        let out = Shared::default();
+        let tracer = Tracer::new(out.clone());
+        let mut data = Vec::new();
+        data.extend_from_slice(&[3, 0, 0, 0, 0, 0, 8, 0]);
+        data.extend_from_slice(&[4, 0, 0, 0, 1, 0, 12, 0, 7, 0, 0, 0]);
+        tracer.record(Direction::Received, &data, 2);
+
+        let text = String::from_utf8(out.0.lock().unwrap().clone()).unwrap();
+        let records: Vec<_> = text
+            .lines()
+            .map(|line| Record::from_json(line).unwrap())
+            .collect();
+        let fds: Vec<_> = records
+            .iter()
+            .map(|r| (r.header().unwrap().id, r.fds))
+            .collect();
+        assert_eq!(fds, [(3, 2), (4, 0)]);
+    }
+
+    #[test]
+    fn replayed_traces_give_the_same_events() {
+        let (conn, mut server) = MockServer::new();
+        let out = Shared::default();
+        conn.set_tracer(Tracer::new(out.clone()));
+        server.global(&wl_compositor::INTERFACE_DESC, 6);
+        server.global(&wl_seat::INTERFACE_DESC, 7);
+        let mut recorded = App::default();
+        conn.display().get_registry(conn.writer());
+        conn.display().sync(conn.writer());
+        roundtrip(&conn, &mut server, &mut recorded).unwrap();
+        assert!(recorded.synced);
+        drop(conn);
+
+        let text = String::from_utf8(out.0.lock().unwrap().clone()).unwrap();
+        let records: Vec<_> = text
+            .lines()
+            .map(|line| Record::from_json(line).unwrap())
+            .collect();
+        let directions: Vec<_> = records.iter().map(|r| r.direction).collect();
+        // get_registry and sync, two globals, done and delete_id
+        use Direction::*;
+        assert_eq!(
+            directions,
+            [Sent, Sent, Received, Received, Received, Received]
+        );
+
+        let (conn, replayer) = Replayer::spawn(records);
+        let mut replayed = App::default();
+        conn.display().get_registry(conn.writer());
+        conn.display().sync(conn.writer());
+        while !replayed.synced {
+            conn.dispatch_events(&mut replayed).unwrap();
+        }
+        assert_eq!(replayed.globals, recorded.globals);
+        drop(conn);
+        replayer.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn diverging_requests_end_the_replay() {
+        // wl_display.get_registry(2), the client syncs instead
+        let record = Record {
+            time:      Duration::ZERO,
+            direction: Direction::Sent,
+            fds:       0,
+            data:      [1u32, 12 << 16 | 1, 2]
+                .iter()
+                .flat_map(|word| word.to_ne_bytes())
+                .collect(),
+        };
+        let (conn, replayer) = Replayer::spawn(vec![record]);
+        conn.display().sync(conn.writer());
+        conn.flush().unwrap();
+        let err = replayer.join().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "record 0 doesn't match the request: got 1#0 of 12 bytes, expected 1#1 of 12 bytes"
+        );
+    }
+}
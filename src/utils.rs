@@ -80,6 +80,20 @@ impl<T, const S: usize> Bucket<T, S> {
         unsafe { Some(core::ptr::read(self.as_ptr().add(self.len))) }
     }
 
+    /// Removes the first item, shifting the rest down.
+    pub fn take_first(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let item = core::ptr::read(ptr);
+            core::ptr::copy(ptr.add(1), ptr, self.len);
+            Some(item)
+        }
+    }
+
     #[inline]
     pub const fn empty(&self) -> bool {
         self.len == 0
@@ -168,6 +182,8 @@ macro_rules! impl_index {
     };
 }
 
+impl_index!(std::ops::Range<usize>, [T]);
+impl_index!(std::ops::RangeFrom<usize>, [T]);
 impl_index!(usize, T);
 impl_index!(std::ops::RangeTo<usize>, [T]);
 impl_index!(std::ops::RangeInclusive<usize>, [T]);
@@ -187,29 +203,26 @@ macro_rules! syscall {
 }
 pub(crate) use syscall;
 
+/// Hands a line to the [`Logger`](crate::logger::Logger) installed, see [`crate::logger`].
 #[macro_export]
 macro_rules! log {
     (INFO, $($arg:tt)*) => {{
-        eprintln!("[\x1b[32mINFO\x1b[0m]: {}", format_args!($($arg)*));
+        $crate::logger::log($crate::logger::Level::Info, format_args!($($arg)*));
     }};
     (ERR, $($arg:tt)*) => {{
-        eprintln!("[\x1b[31mERROR\x1b[0m]: {}", format_args!($($arg)*));
+        $crate::logger::log($crate::logger::Level::Error, format_args!($($arg)*));
     }};
     (DEBUG, $($arg:tt)*) => {{
-        eprintln!("[\x1b[34mDEBUG\x1b[0m]: {}", format_args!($($arg)*));
+        $crate::logger::log($crate::logger::Level::Debug, format_args!($($arg)*));
     }};
     (TRACE, $($arg:tt)*) => {{
-        if *$crate::connection::TRACE {
-            eprintln!("[\x1b[36mTRACE\x1b[0m]: {}", format_args!($($arg)*));
-        }
+        $crate::logger::log($crate::logger::Level::Trace, format_args!($($arg)*));
     }};
     (WARNING, $($arg:tt)*) => {{
-        eprintln!("[\x1b[33mWARNING\x1b[0m]: {}", format_args!($($arg)*));
+        $crate::logger::log($crate::logger::Level::Warning, format_args!($($arg)*));
     }};
     (WAYLAND, $($arg:tt)*) => {{
-        if *$crate::connection::DEBUG {
-            eprintln!("[\x1b[35mWAYLAND-DEBUG\x1b[0m]: {}", format_args!($($arg)*));
-        }
+        $crate::logger::log($crate::logger::Level::Wayland, format_args!($($arg)*));
     }};
     () => {};
 }
@@ -223,7 +236,7 @@ mod bucket_tests {
         const SIZE: usize = 100;
         {
             let mut bucket = Bucket::<u8, SIZE>::new();
-            bucket.extend_from_slice(&[0; 100]);
+            bucket.extend_from_slice([0; 100]);
             assert!(bucket.can_fit(0)); // duh
             assert!(!bucket.can_fit(10));
             assert!(!bucket.can_fit(2));
@@ -231,7 +244,7 @@ mod bucket_tests {
         }
         {
             let mut bucket = Bucket::<u8, SIZE>::new();
-            bucket.extend_from_slice(&[0; 99]);
+            bucket.extend_from_slice([0; 99]);
             assert!(bucket.can_fit(1));
             assert!(!bucket.can_fit(2));
         }
@@ -240,22 +253,22 @@ mod bucket_tests {
     #[test]
     fn bucket_len() {
         let mut bucket = Bucket::<u8, 50>::new();
-        bucket.extend_from_slice(&[0; 45]);
+        bucket.extend_from_slice([0; 45]);
         assert_eq!(bucket.len(), 45);
     }
 
     #[test]
     fn bucket_pop() {
         let mut bucket = Bucket::<u8, 20>::new();
-        bucket.extend_from_slice(&[0; 10]);
-        while let Some(_) = bucket.pop() {}
+        bucket.extend_from_slice([0; 10]);
+        while bucket.pop().is_some() {}
         assert_eq!(bucket.len(), 0);
     }
 
     #[test]
     fn bucket_clear() {
         let mut bucket = Bucket::<u8, 40>::new();
-        bucket.extend_from_slice(&[0; 10]);
+        bucket.extend_from_slice([0; 10]);
         assert_eq!(bucket.len(), 10);
         bucket.clear();
         assert_eq!(bucket.len(), 0);
@@ -273,6 +286,20 @@ mod bucket_tests {
         assert_eq!(bucket.pop(), Some(3));
     }
 
+    #[test]
+    fn bucket_take_first() {
+        let mut bucket = Bucket::<String, 10>::new();
+        bucket.push("one".into());
+        bucket.push("two".into());
+        bucket.push("three".into());
+        assert_eq!(bucket.take_first(), Some("one".into()));
+        assert_eq!(bucket.len(), 2);
+        assert_eq!(bucket[..], ["two".to_string(), "three".into()]);
+        assert_eq!(bucket.take_first(), Some("two".into()));
+        assert_eq!(bucket.take_first(), Some("three".into()));
+        assert_eq!(bucket.take_first(), None);
+    }
+
     #[test]
     fn bucket_index() {
         const SIZE: usize = 45;
@@ -320,8 +347,8 @@ mod bucket_tests {
         bucket.push(40);
         unsafe {
             let mut output = [0u8; size_of::<u32>() * 2];
-            &output[..4].copy_from_slice(&u32::to_ne_bytes(10));
-            &output[4..].copy_from_slice(&u32::to_ne_bytes(40));
+            output[..4].copy_from_slice(&u32::to_ne_bytes(10));
+            output[4..].copy_from_slice(&u32::to_ne_bytes(40));
             assert_eq!(bucket.as_bytes(), output);
         }
     }
@@ -0,0 +1,436 @@
+//! Checks of outgoing requests against the interface descriptions, so mistakes show up with a
+//! backtrace where they're made instead of as a `wl_display.error` from the compositor later.
+//!
+//! On in debug builds, `SCR_VALIDATE=1` turns it on in release builds and `SCR_VALIDATE=0`
+//! off in debug builds. Like [`crate::debug`] only the raw bytes and the [`Interface`]
+//! descriptions are looked at, invalid requests are reported and still sent.
+
+use std::backtrace::Backtrace;
+use std::sync::LazyLock;
+
+use crate::connection::ObjectMap;
+use crate::debug::Side;
+use crate::events::EventDataParser;
+use crate::events::Header;
+use crate::interface::{ArgDesc, ArgType, EnumDesc, Interface, MessageDesc};
+use crate::log;
+
+pub(crate) static ENABLED: LazyLock<bool> =
+    LazyLock::new(|| match std::env::var("SCR_VALIDATE").as_deref() {
+        Ok("1") => true,
+        Ok("0") => false,
+        _ => cfg!(debug_assertions),
+    });
+
+/// Logs what's wrong with the message `header` introduces, with a backtrace to the call that
+/// sent it. `fds` is the number of fds added for it.
+pub(crate) fn report(objects: &ObjectMap, side: Side, header: Header, body: &[u8], fds: usize) {
+    let violations = check(objects, side, header, body, fds);
+    if violations.is_empty() {
+        return;
+    }
+    let name = objects
+        .desc(header.id)
+        .and_then(|interface| {
+            let desc = side.message(interface, true, header.opcode)?;
+            Some(format!("{}@{}.{}", interface.name, header.id, desc.name))
+        })
+        .unwrap_or_else(|| format!("{}#{}", header.id, header.opcode));
+    let kind = match side {
+        Side::Client => "request",
+        Side::Server => "event",
+    };
+    log!(
+        ERR,
+        "Invalid {kind} {name}:\n  {}\n{}",
+        violations.join("\n  "),
+        Backtrace::force_capture()
+    );
+}
+
+/// Everything wrong with a message sent by `side`, empty if it's valid.
+pub(crate) fn check(
+    objects: &ObjectMap, side: Side, header: Header, body: &[u8], fds: usize,
+) -> Vec<String> {
+    let mut violations = Vec::new();
+    let Some(info) = objects.get(header.id) else {
+        violations.push(format!("object {} doesn't exist", header.id));
+        return violations;
+    };
+    if objects.zombie_event_fds(header.id).is_some() {
+        violations.push(format!("{}@{} was destroyed", info.interface, header.id));
+    }
+    let interface = objects.desc(header.id).unwrap();
+    let Some(desc) = side.message(interface, true, header.opcode) else {
+        let count = match side {
+            Side::Client => interface.requests.len(),
+            Side::Server => interface.events.len(),
+        };
+        violations.push(format!(
+            "opcode {} is out of range, {} has {count}",
+            header.opcode, interface.name
+        ));
+        return violations;
+    };
+    if desc.since > info.version {
+        violations.push(format!(
+            "{} was added in version {}, the object is version {}",
+            desc.name, desc.since, info.version
+        ));
+    }
+    let size = header.size as usize;
+    if size != Header::HEADER_SIZE + body.len() {
+        violations.push(format!(
+            "the header gives {size} bytes, the message has {}",
+            Header::HEADER_SIZE + body.len()
+        ));
+    }
+
+    let args = EventDataParser::new(body);
+    let mut expected_fds = 0;
+    for arg in desc.args {
+        let checked = match arg.ty {
+            ArgType::Int | ArgType::Uint => args.get_u32().map(|value| {
+                if let Some(name) = arg.enumeration
+                    && let Some(en) = enumeration(objects, interface, name)
+                {
+                    violations.extend(check_enum(arg, en, value));
+                }
+            }),
+            ArgType::Fixed => args.get_u32().map(drop),
+            ArgType::String => args.get_nullable_string().map(|string| {
+                if string.is_none() && !arg.nullable {
+                    violations.push(format!("`{}` is null but isn't nullable", arg.name));
+                }
+            }),
+            ArgType::Array => args.get_bytes().map(drop),
+            ArgType::Object => args.get_u32().map(|id| {
+                if id == 0 {
+                    if !arg.nullable {
+                        violations.push(format!("`{}` is null but isn't nullable", arg.name));
+                    }
+                } else {
+                    violations.extend(check_object(
+                        objects,
+                        arg,
+                        id,
+                        arg.interface.map(|i| i.name),
+                    ));
+                }
+            }),
+            ArgType::NewId => {
+                // Untyped ids are preceded by the interface name and version
+                let expected = match arg.interface {
+                    Some(interface) => Some(Some(interface.name)),
+                    None => args
+                        .get_nullable_string()
+                        .filter(|_| args.get_u32().is_some()),
+                };
+                expected
+                    .zip(args.get_u32())
+                    .map(|(expected, id)| match expected {
+                        None => violations.push(format!("the interface of `{}` is null", arg.name)),
+                        Some(_) if id == 0 => {
+                            violations.push(format!("`{}` is null but isn't nullable", arg.name))
+                        }
+                        Some(expected) => {
+                            violations.extend(check_object(objects, arg, id, Some(expected)))
+                        }
+                    })
+            }
+            ArgType::Fd => {
+                expected_fds += 1;
+                Some(())
+            }
+        };
+        if checked.is_none() {
+            violations.push(format!("`{}` is cut off or malformed", arg.name));
+            return violations;
+        }
+    }
+    if args.remaining() > 0 {
+        violations.push(format!(
+            "{} bytes are left after the arguments",
+            args.remaining()
+        ));
+    }
+    if fds != expected_fds {
+        violations.push(format!(
+            "{fds} fds were added, {} takes {expected_fds}",
+            desc.name
+        ));
+    }
+    violations
+}
+
+/// What's wrong with the arguments of a received message, `None` if they fit `desc`. Unlike
+/// [`check`] the objects they refer to aren't looked at, only that every argument is there in
+/// full, nothing is left over, nothing non-nullable is null and at most `fds` fds are needed.
+pub(crate) fn check_received(desc: &MessageDesc, body: &[u8], fds: usize) -> Option<String> {
+    let args = EventDataParser::new(body);
+    let mut expected_fds = 0;
+    for arg in desc.args {
+        let null = match arg.ty {
+            ArgType::Int | ArgType::Uint | ArgType::Fixed => args.get_u32().map(|_| false),
+            ArgType::String => args.get_nullable_string().map(|string| string.is_none()),
+            ArgType::Array => args.get_bytes().map(|_| false),
+            ArgType::Object => args.get_u32().map(|id| id == 0),
+            ArgType::NewId if arg.interface.is_none() => args
+                .get_nullable_string()
+                .filter(|_| args.get_u32().is_some())
+                .zip(args.get_u32())
+                .map(|(interface, _)| interface.is_none()),
+            // Ids are checked when the object is created
+            ArgType::NewId => args.get_u32().map(|_| false),
+            ArgType::Fd => {
+                expected_fds += 1;
+                Some(false)
+            }
+        };
+        match null {
+            None => return Some(format!("`{}` is cut off or malformed", arg.name)),
+            Some(true) if !arg.nullable => {
+                return Some(format!("`{}` is null but isn't nullable", arg.name));
+            }
+            Some(_) => {}
+        }
+    }
+    if args.remaining() > 0 {
+        return Some(format!(
+            "{} bytes are left after the arguments",
+            args.remaining()
+        ));
+    }
+    (expected_fds > fds).then(|| format!("{fds} fds arrived, {} takes {expected_fds}", desc.name))
+}
+
+/// Checks that the object `arg` refers to is alive and of the `expected` interface.
+fn check_object(
+    objects: &ObjectMap, arg: &ArgDesc, id: u32, expected: Option<&str>,
+) -> Option<String> {
+    let Some(info) = objects.get(id) else {
+        return Some(format!(
+            "`{}` is object {id}, which doesn't exist",
+            arg.name
+        ));
+    };
+    if objects.zombie_event_fds(id).is_some() {
+        return Some(format!(
+            "`{}` is {}@{id}, which was destroyed",
+            arg.name, info.interface
+        ));
+    }
+    match expected {
+        Some(expected) if expected != info.interface => Some(format!(
+            "`{}` is {}@{id}, expected a {expected}",
+            arg.name, info.interface
+        )),
+        _ => None,
+    }
+}
+
+fn check_enum(arg: &ArgDesc, en: &EnumDesc, value: u32) -> Option<String> {
+    if en.bitfield {
+        let known = en.entries.iter().fold(0, |all, entry| all | entry.value);
+        (value & !known != 0).then(|| {
+            format!(
+                "`{}` sets bits {:#x} that aren't in {}",
+                arg.name,
+                value & !known,
+                en.name
+            )
+        })
+    } else {
+        (!en.entries.iter().any(|entry| entry.value == value)).then(|| {
+            format!(
+                "`{}` is {value}, which isn't a value of {}",
+                arg.name, en.name
+            )
+        })
+    }
+}
+
+/// The enum an argument of `interface` names, either `name` of the same interface or
+/// `interface.name` of another one. Only interfaces of the core protocol and of live objects
+/// can be found.
+fn enumeration(
+    objects: &ObjectMap, interface: &'static Interface, name: &str,
+) -> Option<&'static EnumDesc> {
+    let Some((other, name)) = name.split_once('.') else {
+        return interface.enumeration(name);
+    };
+    let other = if other == interface.name {
+        interface
+    } else {
+        crate::wayland::INTERFACES
+            .iter()
+            .copied()
+            .find(|desc| desc.name == other)
+            .or_else(|| objects.desc_named(other))?
+    };
+    other.enumeration(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::{Object, ObjectInfo};
+    use crate::wayland::*;
+
+    fn objects() -> ObjectMap {
+        let objects = ObjectMap::new();
+        for (id, desc) in [
+            (1, wl_display::WlDisplay::DESC),
+            (2, wl_registry::WlRegistry::DESC),
+            (3, wl_compositor::WlCompositor::DESC),
+            (5, wl_surface::WlSurface::DESC),
+            (6, wl_shm::WlShm::DESC),
+            (8, wl_buffer::WlBuffer::DESC),
+            (9, wl_seat::WlSeat::DESC),
+        ] {
+            let info = ObjectInfo {
+                id,
+                interface: desc.name,
+                version: 4,
+            };
+            objects.insert(info, desc);
+        }
+        objects
+    }
+
+    fn check_message(
+        objects: &ObjectMap, id: u32, opcode: u16, args: &[u32], fds: usize,
+    ) -> Vec<String> {
+        let body: Vec<u8> = args.iter().flat_map(|arg| arg.to_ne_bytes()).collect();
+        let header = Header::new(id, opcode, (Header::HEADER_SIZE + body.len()) as u16);
+        check(objects, Side::Client, header, &body, fds)
+    }
+
+    #[test]
+    fn valid_requests_pass() {
+        let objects = objects();
+        // wl_surface.attach(wl_buffer@8, 0, 0) and attach(nil, 0, 0)
+        assert!(check_message(&objects, 5, 1, &[8, 0, 0], 0).is_empty());
+        assert!(check_message(&objects, 5, 1, &[0, 0, 0], 0).is_empty());
+        // wl_surface.set_buffer_transform(flipped_90)
+        assert!(check_message(&objects, 5, 7, &[5], 0).is_empty());
+        // wl_shm.create_pool(new id wl_shm_pool@10, fd, 4096)
+        objects.insert(
+            ObjectInfo {
+                id:        10,
+                interface: "wl_shm_pool",
+                version:   1,
+            },
+            wl_shm_pool::WlShmPool::DESC,
+        );
+        assert!(check_message(&objects, 6, 0, &[10, 4096], 1).is_empty());
+    }
+
+    #[test]
+    fn violations_are_found() {
+        let objects = objects();
+        assert_eq!(
+            check_message(&objects, 42, 0, &[], 0),
+            ["object 42 doesn't exist"]
+        );
+        assert_eq!(
+            check_message(&objects, 5, 99, &[], 0),
+            ["opcode 99 is out of range, wl_surface has 11"]
+        );
+        assert_eq!(
+            check_message(&objects, 5, 1, &[9, 0, 0], 0),
+            ["`buffer` is wl_seat@9, expected a wl_buffer"]
+        );
+        assert_eq!(
+            check_message(&objects, 5, 1, &[77, 0, 0], 0),
+            ["`buffer` is object 77, which doesn't exist"]
+        );
+        assert_eq!(
+            check_message(&objects, 5, 7, &[12], 0),
+            ["`transform` is 12, which isn't a value of transform"]
+        );
+        // wl_surface.offset is version 5, wl_surface.damage_buffer version 4
+        assert_eq!(
+            check_message(&objects, 5, 10, &[0, 0], 0),
+            ["offset was added in version 5, the object is version 4"]
+        );
+        assert!(check_message(&objects, 5, 9, &[0, 0, 1, 1], 0).is_empty());
+        assert_eq!(
+            check_message(&objects, 5, 1, &[8, 0], 0),
+            ["`y` is cut off or malformed"]
+        );
+        assert_eq!(
+            check_message(&objects, 6, 0, &[0, 4096], 0),
+            [
+                "`id` is null but isn't nullable",
+                "0 fds were added, create_pool takes 1"
+            ]
+        );
+        objects.mark_zombie(8, &[]);
+        assert_eq!(
+            check_message(&objects, 5, 1, &[8, 0, 0], 0),
+            ["`buffer` is wl_buffer@8, which was destroyed"]
+        );
+    }
+
+    #[test]
+    fn received_arguments_are_checked() {
+        let check = |desc: &MessageDesc, args: &[u32], fds| {
+            let body: Vec<u8> = args.iter().flat_map(|arg| arg.to_ne_bytes()).collect();
+            check_received(desc, &body, fds)
+        };
+        let requests = wl_surface::WlSurface::DESC.requests;
+        // wl_surface.attach(wl_buffer@8, 0, 0) and attach(nil, 0, 0)
+        assert_eq!(check(&requests[1], &[8, 0, 0], 0), None);
+        assert_eq!(check(&requests[1], &[0, 0, 0], 0), None);
+        assert_eq!(
+            check(&requests[1], &[8, 0], 0).as_deref(),
+            Some("`y` is cut off or malformed")
+        );
+        assert_eq!(
+            check(&requests[1], &[8, 0, 0, 0], 0).as_deref(),
+            Some("4 bytes are left after the arguments")
+        );
+        // wl_subcompositor.get_subsurface(new id, nil, wl_surface@5)
+        let get_subsurface = &wl_subcompositor::WlSubcompositor::DESC.requests[1];
+        assert_eq!(
+            check(get_subsurface, &[10, 0, 5], 0).as_deref(),
+            Some("`surface` is null but isn't nullable")
+        );
+        // wl_shm.create_pool(new id, fd, 4096)
+        let create_pool = &wl_shm::WlShm::DESC.requests[0];
+        assert_eq!(check(create_pool, &[10, 4096], 1), None);
+        assert_eq!(
+            check(create_pool, &[10, 4096], 0).as_deref(),
+            Some("0 fds arrived, create_pool takes 1")
+        );
+        // wl_registry.bind(1, "wl_seat", 7, new id) with a string running past the end and a
+        // null interface
+        let bind = &wl_registry::WlRegistry::DESC.requests[0];
+        let wl_seat = u32::from_ne_bytes(*b"wl_s");
+        assert_eq!(
+            check(bind, &[1, 8, wl_seat], 0).as_deref(),
+            Some("`id` is cut off or malformed")
+        );
+        assert_eq!(
+            check(bind, &[1, 0, 7, 10], 0).as_deref(),
+            Some("`id` is null but isn't nullable")
+        );
+        let wl_seat = [u32::from_ne_bytes(*b"wl_s"), u32::from_ne_bytes(*b"eat\0")];
+        assert_eq!(check(bind, &[1, 8, wl_seat[0], wl_seat[1], 7, 10], 0), None);
+        // Strings have to be nul terminated UTF-8, like the generated decoders want them
+        let unterminated = [u32::from_ne_bytes(*b"wl_s"), u32::from_ne_bytes(*b"eat!")];
+        assert_eq!(
+            check(bind, &[1, 8, unterminated[0], unterminated[1], 7, 10], 0).as_deref(),
+            Some("`id` is cut off or malformed")
+        );
+        let invalid = [
+            u32::from_ne_bytes(*b"wl_s"),
+            u32::from_ne_bytes(*b"ea\xff\0"),
+        ];
+        assert_eq!(
+            check(bind, &[1, 8, invalid[0], invalid[1], 7, 10], 0).as_deref(),
+            Some("`id` is cut off or malformed")
+        );
+    }
+}